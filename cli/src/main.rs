@@ -0,0 +1,164 @@
+//! `cargo ts-rs`, exporting and checking the TypeScript bindings of a crate without running its
+//! tests.
+//!
+//! The bindings are exported by a binary of the crate, `export-bindings` by default, which calls
+//! `ts_rs::export_registered`. Options given on the command line are passed to it using the
+//! environment variables read by `ts_rs::Config::load`, so they take precedence over the
+//! crate's `ts-rs.toml`.
+
+use std::{
+    error::Error,
+    ffi::OsString,
+    path::{Path, PathBuf},
+    process::{self, ExitCode},
+};
+
+use clap::{Args, Parser, Subcommand};
+use ts_rs::Config;
+
+#[derive(Parser)]
+#[command(name = "cargo", bin_name = "cargo")]
+enum Cargo {
+    TsRs(TsRs),
+}
+
+/// Exports and checks the TypeScript bindings generated by ts-rs
+#[derive(Args)]
+#[command(version)]
+struct TsRs {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Exports the bindings of all types annotated with `#[ts(export)]`
+    Export(Exporter),
+    /// Checks that the exported bindings are up to date, failing if they differ or if files are
+    /// no longer generated
+    Check(Exporter),
+    /// Removes all bindings generated by ts-rs from the output directory
+    Clean(Target),
+}
+
+#[derive(Args)]
+struct Target {
+    /// Path to the `Cargo.toml` of the crate
+    #[arg(long, value_name = "PATH")]
+    manifest_path: Option<PathBuf>,
+    /// Directory into which the bindings are exported
+    #[arg(long, value_name = "DIR")]
+    out_dir: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct Exporter {
+    #[command(flatten)]
+    target: Target,
+    /// Binary of the crate which exports the bindings using `ts_rs::export_registered`
+    #[arg(long, value_name = "NAME", default_value = "export-bindings")]
+    bin: String,
+    /// TypeScript type used for large integers, e.g. `number`
+    #[arg(long, value_name = "TYPE")]
+    large_int: Option<String>,
+    /// File extension used in `import` statements
+    #[arg(long, value_name = "EXT", value_parser = ["js", "ts"])]
+    import_extension: Option<String>,
+}
+
+fn main() -> ExitCode {
+    let Cargo::TsRs(args) = Cargo::parse();
+    let result = match args.command {
+        Command::Export(exporter) => run(&exporter, false),
+        Command::Check(exporter) => run(&exporter, true),
+        Command::Clean(target) => clean(&target),
+    };
+
+    result.unwrap_or_else(|e| {
+        eprintln!("error: {e}");
+        ExitCode::FAILURE
+    })
+}
+
+/// Builds and runs the exporter of the crate, exporting or checking its bindings
+fn run(exporter: &Exporter, check: bool) -> Result<ExitCode, Box<dyn Error>> {
+    let manifest = manifest_path(&exporter.target)?;
+    let dir = manifest.parent().unwrap_or(Path::new("."));
+
+    let mut command = process::Command::new(cargo());
+    command
+        .current_dir(dir)
+        .arg("run")
+        .arg("--manifest-path")
+        .arg(&manifest)
+        .args(["--bin", &exporter.bin]);
+
+    if let Some(out_dir) = &exporter.target.out_dir {
+        command.env("TS_RS_EXPORT_DIR", std::env::current_dir()?.join(out_dir));
+    }
+    if let Some(large_int) = &exporter.large_int {
+        command.env("TS_RS_LARGE_INT", large_int);
+    }
+    if let Some(extension) = &exporter.import_extension {
+        command.env("TS_RS_IMPORT_EXTENSION", extension);
+    }
+    if check {
+        command.env("TS_RS_CHECK", "1");
+    }
+
+    let status = command.status()?;
+    if status.success() {
+        return Ok(ExitCode::SUCCESS);
+    }
+
+    if check {
+        eprintln!(
+            "error: checking the bindings failed, run `cargo ts-rs export` if they are out of date, \
+             and `cargo ts-rs clean` before if files are no longer generated"
+        );
+    }
+    let code = status.code().and_then(|code| u8::try_from(code).ok());
+    Ok(code
+        .filter(|code| *code != 0)
+        .map_or(ExitCode::FAILURE, ExitCode::from))
+}
+
+/// Removes all files generated by ts-rs from the output directory of the crate
+fn clean(target: &Target) -> Result<ExitCode, Box<dyn Error>> {
+    let manifest = manifest_path(target)?;
+    let dir = manifest.parent().unwrap_or(Path::new("."));
+
+    // relative paths are resolved like they are when the tests of the crate are run
+    let out_dir = match &target.out_dir {
+        Some(out_dir) => std::env::current_dir()?.join(out_dir),
+        None => dir.join(Config::load_from(dir)?.out_dir()),
+    };
+
+    // every generated file is stale, since nothing was exported by this process
+    let removed = ts_rs::remove_stale_files(&Config::new().with_out_dir(&out_dir))?;
+    eprintln!("Removed {} files from {}", removed.len(), out_dir.display());
+
+    Ok(ExitCode::SUCCESS)
+}
+
+/// Returns the `Cargo.toml` of the crate, which is found like cargo does if it is not given
+fn manifest_path(target: &Target) -> Result<PathBuf, Box<dyn Error>> {
+    if let Some(path) = &target.manifest_path {
+        return Ok(std::env::current_dir()?.join(path));
+    }
+
+    let output = process::Command::new(cargo())
+        .args(["locate-project", "--message-format", "plain"])
+        .stderr(process::Stdio::inherit())
+        .output()?;
+    if !output.status.success() {
+        return Err("could not find `Cargo.toml` in the current directory or its parents".into());
+    }
+
+    Ok(PathBuf::from(String::from_utf8(output.stdout)?.trim_end()))
+}
+
+/// Returns the cargo executable running this subcommand
+fn cargo() -> OsString {
+    std::env::var_os("CARGO").unwrap_or_else(|| "cargo".into())
+}
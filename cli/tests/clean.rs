@@ -0,0 +1,76 @@
+use std::{fs, path::PathBuf, process::Command};
+
+const NOTE: &str = "// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.\n";
+
+fn cargo_ts_rs() -> Command {
+    let mut command = Command::new(env!("CARGO_BIN_EXE_cargo-ts-rs"));
+    command.arg("ts-rs");
+    command
+}
+
+fn project(name: &str) -> PathBuf {
+    let dir = PathBuf::from(env!("CARGO_TARGET_TMPDIR")).join(name);
+    let _ = fs::remove_dir_all(&dir);
+    fs::create_dir_all(dir.join("generated/nested")).unwrap();
+    fs::write(
+        dir.join("Cargo.toml"),
+        "[package]\nname = \"app\"\n\n[package.metadata.ts-rs]\nout-dir = \"generated\"\n",
+    )
+    .unwrap();
+    dir
+}
+
+#[test]
+fn clean() {
+    let dir = project("clean");
+    let out_dir = dir.join("generated");
+    fs::write(
+        out_dir.join("User.ts"),
+        format!("{NOTE}export type User = {{}};\n"),
+    )
+    .unwrap();
+    fs::write(
+        out_dir.join("nested/Role.ts"),
+        format!("{NOTE}export type Role = \"A\";\n"),
+    )
+    .unwrap();
+    fs::write(out_dir.join("handwritten.ts"), "export const a = 1;\n").unwrap();
+
+    let status = cargo_ts_rs()
+        .arg("clean")
+        .arg("--manifest-path")
+        .arg(dir.join("Cargo.toml"))
+        .status()
+        .unwrap();
+
+    assert!(status.success());
+    assert!(!out_dir.join("User.ts").exists());
+    assert!(!out_dir.join("nested/Role.ts").exists());
+    assert!(out_dir.join("handwritten.ts").exists());
+}
+
+#[test]
+fn invalid_config() {
+    let dir = project("invalid_config");
+    fs::write(dir.join("ts-rs.toml"), "out_dir = \"generated\"\n").unwrap();
+
+    let output = cargo_ts_rs()
+        .arg("clean")
+        .arg("--manifest-path")
+        .arg(dir.join("Cargo.toml"))
+        .output()
+        .unwrap();
+
+    assert!(!output.status.success());
+    let stderr = String::from_utf8(output.stderr).unwrap();
+    assert!(stderr.contains("unknown key `out_dir`"), "{stderr}");
+}
+
+#[test]
+fn invalid_arguments() {
+    let status = cargo_ts_rs()
+        .args(["export", "--import-extension", "mjs"])
+        .status()
+        .unwrap();
+    assert_eq!(status.code(), Some(2));
+}
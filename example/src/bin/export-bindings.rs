@@ -0,0 +1,7 @@
+//! Exports the bindings of all types annotated with `#[ts(export)]`, used by `cargo ts-rs`
+
+use example as _;
+
+fn main() -> Result<(), ts_rs::ExportError> {
+    ts_rs::export_registered(&ts_rs::Config::load()?)
+}
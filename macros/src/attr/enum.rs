@@ -8,7 +8,10 @@ use super::{
     parse_assign_expr, parse_assign_from_str, parse_bound, parse_repr, Attr, ContainerAttr, Serde,
 };
 use crate::{
-    attr::{parse_assign_inflection, parse_assign_str, parse_concrete, Inflection},
+    attr::{
+        parse_assign_inflection, parse_assign_str, parse_concrete, parse_optional_assign_str,
+        Inflection,
+    },
     optional::{parse_optional, Optional},
     utils::{extract_docs, parse_attrs},
 };
@@ -23,6 +26,8 @@ pub struct EnumAttr {
     pub rename: Option<Expr>,
     pub export_to: Option<Expr>,
     pub export: bool,
+    // `#[ts(export_values)]`, optionally naming the constant, e.g. `#[ts(export_values = "ALL")]`
+    pub export_values: Option<Option<String>>,
     pub docs: Vec<Expr>,
     pub concrete: HashMap<Ident, Type>,
     pub bound: Option<Vec<WherePredicate>>,
@@ -31,6 +36,9 @@ pub struct EnumAttr {
     pub content: Option<String>,
     pub repr: Option<Repr>,
     pub optional_fields: Optional,
+    // serde-specific
+    pub type_into: Option<Type>,
+    pub type_from: Option<Type>,
 }
 
 #[derive(Copy, Clone)]
@@ -95,6 +103,7 @@ impl Attr for EnumAttr {
             untagged: self.untagged || other.untagged,
             content: self.content.or(other.content),
             export: self.export || other.export,
+            export_values: self.export_values.or(other.export_values),
             export_to: self.export_to.or(other.export_to),
             docs: other.docs,
             concrete: self.concrete.into_iter().chain(other.concrete).collect(),
@@ -105,6 +114,8 @@ impl Attr for EnumAttr {
             },
             repr: self.repr.or(other.repr),
             optional_fields: self.optional_fields.or(other.optional_fields),
+            type_into: self.type_into.or(other.type_into),
+            type_from: self.type_from.or(other.type_from),
         }
     }
 
@@ -208,6 +219,26 @@ impl Attr for EnumAttr {
             }
         }
 
+        if self.export_values.is_some() {
+            if self.type_override.is_some() || self.type_as.is_some() {
+                syn_err_spanned!(item; "`export_values` is not compatible with `type` or `as`");
+            }
+
+            if self.type_into.is_some() && self.repr.is_none() {
+                syn_err_spanned!(item; "`export_values` is not compatible with `into`");
+            }
+
+            if self.untagged || self.tag.is_some() {
+                syn_err_spanned!(item; "`export_values` can only be used on externally tagged enums");
+            }
+
+            for variant in item.variants.iter() {
+                if !matches!(variant.fields, Fields::Unit) {
+                    syn_err_spanned!(variant; "All variants of an enum marked as `#[ts(export_values)]` must be unit variants");
+                }
+            }
+        }
+
         if self.untagged && self.repr.is_some() {
             syn_err_spanned!(item; "`untagged` is not compatible with `repr`");
         }
@@ -270,6 +301,7 @@ impl_parse! {
         "rename_all_fields" => out.rename_all_fields = Some(parse_assign_inflection(input)?),
         "export_to" => out.export_to = Some(parse_assign_expr(input)?),
         "export" => out.export = true,
+        "export_values" => out.export_values = Some(parse_optional_assign_str(input)?),
         "tag" => out.tag = Some(parse_assign_str(input)?),
         "content" => out.content = Some(parse_assign_str(input)?),
         "untagged" => out.untagged = true,
@@ -289,6 +321,8 @@ impl_parse! {
         "content" => out.0.content = Some(parse_assign_str(input)?),
         "untagged" => out.0.untagged = true,
         "bound" => out.0.bound = Some(parse_bound(input)?),
+        "into" => out.0.type_into = Some(parse_assign_from_str(input)?),
+        "from" | "try_from" => out.0.type_from = Some(parse_assign_from_str(input)?),
 
         // parse #[serde(crate = "...")] to not emit a warning
         "crate" => {
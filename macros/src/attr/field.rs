@@ -4,7 +4,9 @@ use syn::{
     TypeReference, TypeSlice, TypeTuple,
 };
 
-use super::{parse_assign_from_str, parse_assign_str, parse_optional_assign_str, Attr, Serde};
+use super::{
+    parse_assign_from_str, parse_assign_str, parse_optional_assign_str, parse_rename, Attr, Serde,
+};
 use crate::{
     optional::{parse_optional, Optional},
     utils::{extract_docs, parse_attrs},
@@ -19,6 +21,7 @@ pub struct FieldAttr {
     pub skip: bool,
     pub optional: Optional,
     pub flatten: bool,
+    pub readonly: bool,
     pub docs: Vec<Expr>,
 
     // serde-specific
@@ -26,6 +29,11 @@ pub struct FieldAttr {
     // whether the field might be omitted during serialization by skip_serializing{_if}
     pub maybe_omitted: bool,
     pub has_default: bool,
+    // names set by `#[serde(rename(serialize = "..", deserialize = ".."))]`
+    pub rename_serialize: Option<String>,
+    pub rename_deserialize: Option<String>,
+    pub skip_serializing: bool,
+    pub skip_deserializing: bool,
 }
 
 impl FieldAttr {
@@ -37,6 +45,11 @@ impl FieldAttr {
             result = result.merge(serde_attr.0);
         }
 
+        // a field which is neither serialized nor deserialized is skipped entirely
+        if result.skip_serializing && result.skip_deserializing {
+            result.skip = true;
+        }
+
         result.docs = extract_docs(attrs);
 
         Ok(result)
@@ -50,12 +63,25 @@ impl FieldAttr {
             original_type.clone()
         }
     }
+
+    /// Returns the names of the field used for serialization and deserialization, falling back
+    /// to `default` if the field is not renamed.
+    pub fn names(&self, default: String) -> (String, String) {
+        let name = self.rename.clone().unwrap_or(default);
+        (
+            self.rename_serialize.clone().unwrap_or_else(|| name.clone()),
+            self.rename_deserialize.clone().unwrap_or(name),
+        )
+    }
 }
 
 impl Attr for FieldAttr {
     type Item = Field;
 
     fn merge(self, other: Self) -> Self {
+        // `#[ts(rename)]` takes precedence over `#[serde(rename(serialize = "..", ..))]`
+        let renamed = self.rename.is_some();
+
         Self {
             type_as: self.type_as.or(other.type_as),
             type_override: self.type_override.or(other.type_override),
@@ -64,10 +90,21 @@ impl Attr for FieldAttr {
             skip: self.skip || other.skip,
             optional: self.optional.or(other.optional),
             flatten: self.flatten || other.flatten,
+            readonly: self.readonly || other.readonly,
 
             using_serde_with: self.using_serde_with || other.using_serde_with,
             maybe_omitted: self.maybe_omitted || other.maybe_omitted,
             has_default: self.has_default || other.has_default,
+            skip_serializing: self.skip_serializing || other.skip_serializing,
+            skip_deserializing: self.skip_deserializing || other.skip_deserializing,
+            rename_serialize: match renamed {
+                true => self.rename_serialize,
+                false => self.rename_serialize.or(other.rename_serialize),
+            },
+            rename_deserialize: match renamed {
+                true => self.rename_deserialize,
+                false => self.rename_deserialize.or(other.rename_deserialize),
+            },
 
             // We can't emit TSDoc for a flattened field
             // and we cant make this invalid in assert_validity because
@@ -116,7 +153,10 @@ impl Attr for FieldAttr {
                 );
             }
 
-            if self.rename.is_some() {
+            if self.rename.is_some()
+                || self.rename_serialize.is_some()
+                || self.rename_deserialize.is_some()
+            {
                 syn_err_spanned!(
                     field;
                     "`rename` is not compatible with `flatten`"
@@ -136,6 +176,13 @@ impl Attr for FieldAttr {
                     "`optional` is not compatible with `flatten`"
                 );
             }
+
+            if self.readonly {
+                syn_err_spanned!(
+                    field;
+                    "`readonly` is not compatible with `flatten`"
+                );
+            }
         }
 
         if field.ident.is_none() {
@@ -152,6 +199,13 @@ impl Attr for FieldAttr {
                     "`rename` cannot be used with tuple struct fields"
                 );
             }
+
+            if self.readonly {
+                syn_err_spanned!(
+                    field;
+                    "`readonly` cannot be used with tuple struct fields"
+                );
+            }
         }
 
         Ok(())
@@ -167,12 +221,22 @@ impl_parse! {
         "skip" => out.skip = true,
         "optional" => out.optional = parse_optional(input)?,
         "flatten" => out.flatten = true,
+        "readonly" => out.readonly = true,
     }
 }
 
 impl_parse! {
     Serde<FieldAttr>(input, out) {
-        "rename" => out.0.rename = Some(parse_assign_str(input)?),
+        "rename" => {
+            let (rename, serialize, deserialize) = parse_rename(input)?;
+            out.0.rename = rename;
+            out.0.rename_serialize = serialize;
+            out.0.rename_deserialize = deserialize;
+        },
+        // aliases are only accepted during deserialization, so they are not part of the bindings
+        "alias" => {
+            parse_assign_str(input)?;
+        },
         "skip" => out.0.skip = true,
         "skip_serializing_if" => {
             let _ = parse_assign_str(input)?;
@@ -180,7 +244,9 @@ impl_parse! {
         },
         "skip_serializing" => {
             out.0.maybe_omitted = true;
+            out.0.skip_serializing = true;
         },
+        "skip_deserializing" => out.0.skip_deserializing = true,
         "flatten" => out.0.flatten = true,
         // parse #[serde(default)] to make the TS field optional if `skip_serializing(_if)` is also present.
         "default" => {
@@ -0,0 +1,75 @@
+use syn::{parse_quote, Expr, FnArg, GenericParam, Ident, ItemFn, Pat, Path, Result};
+
+use super::{
+    parse_assign_expr, parse_assign_from_str, parse_assign_inflection, Attr, ContainerAttr,
+    Inflection,
+};
+
+/// Attributes of `#[ts_rs::function(..)]`
+#[derive(Default)]
+pub struct FunctionAttr {
+    crate_rename: Option<Path>,
+    pub rename: Option<Expr>,
+    pub rename_all: Option<Inflection>,
+    pub export_to: Option<Expr>,
+    pub export: bool,
+    pub declare: bool,
+}
+
+impl Attr for FunctionAttr {
+    type Item = ItemFn;
+
+    fn merge(self, other: Self) -> Self {
+        Self {
+            crate_rename: self.crate_rename.or(other.crate_rename),
+            rename: self.rename.or(other.rename),
+            rename_all: self.rename_all.or(other.rename_all),
+            export_to: self.export_to.or(other.export_to),
+            export: self.export || other.export,
+            declare: self.declare || other.declare,
+        }
+    }
+
+    fn assert_validity(&self, item: &Self::Item) -> Result<()> {
+        let generics = &item.sig.generics.params;
+        if generics
+            .iter()
+            .any(|p| !matches!(p, GenericParam::Lifetime(_)))
+        {
+            syn_err_spanned!(generics; "generic functions are not supported");
+        }
+
+        for input in &item.sig.inputs {
+            match input {
+                FnArg::Receiver(receiver) => {
+                    syn_err_spanned!(receiver; "methods are not supported")
+                }
+                FnArg::Typed(arg) if !matches!(&*arg.pat, Pat::Ident(_)) => {
+                    syn_err_spanned!(&arg.pat; "arguments must be identifiers")
+                }
+                FnArg::Typed(_) => (),
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl ContainerAttr for FunctionAttr {
+    fn crate_rename(&self) -> Path {
+        self.crate_rename
+            .clone()
+            .unwrap_or_else(|| parse_quote!(::ts_rs))
+    }
+}
+
+impl_parse! {
+    FunctionAttr(input, out) {
+        "crate" => out.crate_rename = Some(parse_assign_from_str(input)?),
+        "rename" => out.rename = Some(parse_assign_expr(input)?),
+        "rename_all" => out.rename_all = Some(parse_assign_inflection(input)?),
+        "export_to" => out.export_to = Some(parse_assign_expr(input)?),
+        "export" => out.export = true,
+        "declare" => out.declare = true,
+    }
+}
@@ -1,10 +1,12 @@
 use std::collections::HashMap;
 
 pub use field::*;
+pub use function::*;
 use proc_macro2::TokenTree;
 use quote::quote;
 pub use r#enum::*;
 pub use r#struct::*;
+pub use service::*;
 use syn::{
     parenthesized,
     parse::{Parse, ParseStream},
@@ -14,6 +16,8 @@ use syn::{
 pub use variant::*;
 mod r#enum;
 mod field;
+mod function;
+mod service;
 mod r#struct;
 mod variant;
 
@@ -141,6 +145,35 @@ fn parse_assign_str(input: ParseStream) -> Result<String> {
     }
 }
 
+/// Parses the value of serde's `rename` attribute, which is either `rename = ".."` or
+/// `rename(serialize = "..", deserialize = "..")`.
+/// Returns the name for both directions, followed by the names for serialization and
+/// deserialization.
+fn parse_rename(input: ParseStream) -> Result<(Option<String>, Option<String>, Option<String>)> {
+    if input.peek(Token![=]) {
+        return Ok((Some(parse_assign_str(input)?), None, None));
+    }
+
+    let content;
+    parenthesized!(content in input);
+
+    let (mut serialize, mut deserialize) = (None, None);
+    while !content.is_empty() {
+        let key = content.parse::<Ident>()?;
+        match &*key.to_string() {
+            "serialize" => serialize = Some(parse_assign_str(&content)?),
+            "deserialize" => deserialize = Some(parse_assign_str(&content)?),
+            _ => syn_err!(key.span(); "expected `serialize` or `deserialize`"),
+        }
+
+        if !content.is_empty() {
+            content.parse::<Token![,]>()?;
+        }
+    }
+
+    Ok((None, serialize, deserialize))
+}
+
 fn parse_optional_assign_str(input: ParseStream) -> Result<Option<String>> {
     if input.peek(Token![=]) {
         Some(parse_assign_str(input))
@@ -0,0 +1,115 @@
+use syn::{
+    parse_quote, Expr, FnArg, GenericParam, Ident, ItemTrait, Pat, Path, Result, TraitItemFn,
+};
+
+use super::{
+    parse_assign_expr, parse_assign_from_str, parse_assign_inflection, parse_assign_str,
+    parse_optional_assign_str, Attr, ContainerAttr, Inflection,
+};
+
+/// Attributes of `#[ts_rs::service(..)]`
+#[derive(Default)]
+pub struct ServiceAttr {
+    crate_rename: Option<Path>,
+    pub rename: Option<Expr>,
+    pub rename_all: Option<Inflection>,
+    pub export_to: Option<Expr>,
+    pub export: bool,
+    // `#[ts_rs::service(map)]`, optionally naming the map, e.g. `#[ts_rs::service(map = "Api")]`
+    pub map: Option<Option<String>>,
+}
+
+/// Attributes of the methods of a trait annotated with `#[ts_rs::service]`
+#[derive(Default)]
+pub struct MethodAttr {
+    pub rename: Option<String>,
+    pub skip: bool,
+}
+
+impl Attr for ServiceAttr {
+    type Item = ItemTrait;
+
+    fn merge(self, other: Self) -> Self {
+        Self {
+            crate_rename: self.crate_rename.or(other.crate_rename),
+            rename: self.rename.or(other.rename),
+            rename_all: self.rename_all.or(other.rename_all),
+            export_to: self.export_to.or(other.export_to),
+            export: self.export || other.export,
+            map: self.map.or(other.map),
+        }
+    }
+
+    fn assert_validity(&self, item: &Self::Item) -> Result<()> {
+        if !item.generics.params.is_empty() {
+            syn_err_spanned!(&item.generics; "generic traits are not supported");
+        }
+
+        Ok(())
+    }
+}
+
+impl ContainerAttr for ServiceAttr {
+    fn crate_rename(&self) -> Path {
+        self.crate_rename
+            .clone()
+            .unwrap_or_else(|| parse_quote!(::ts_rs))
+    }
+}
+
+impl Attr for MethodAttr {
+    type Item = TraitItemFn;
+
+    fn merge(self, other: Self) -> Self {
+        Self {
+            rename: self.rename.or(other.rename),
+            skip: self.skip || other.skip,
+        }
+    }
+
+    fn assert_validity(&self, item: &Self::Item) -> Result<()> {
+        if self.skip {
+            return Ok(());
+        }
+
+        let generics = &item.sig.generics.params;
+        if generics
+            .iter()
+            .any(|p| !matches!(p, GenericParam::Lifetime(_)))
+        {
+            syn_err_spanned!(generics; "generic methods are not supported");
+        }
+
+        if !matches!(item.sig.inputs.first(), Some(FnArg::Receiver(_))) {
+            syn_err_spanned!(&item.sig; "methods without a receiver are not supported. Use `#[ts(skip)]` to skip them");
+        }
+
+        for input in &item.sig.inputs {
+            if let FnArg::Typed(arg) = input {
+                if !matches!(&*arg.pat, Pat::Ident(_)) {
+                    syn_err_spanned!(&arg.pat; "arguments must be identifiers");
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl_parse! {
+    ServiceAttr(input, out) {
+        "crate" => out.crate_rename = Some(parse_assign_from_str(input)?),
+        "rename" => out.rename = Some(parse_assign_expr(input)?),
+        "rename_all" => out.rename_all = Some(parse_assign_inflection(input)?),
+        "export_to" => out.export_to = Some(parse_assign_expr(input)?),
+        "export" => out.export = true,
+        "map" => out.map = Some(parse_optional_assign_str(input)?),
+    }
+}
+
+impl_parse! {
+    MethodAttr(input, out) {
+        "rename" => out.rename = Some(parse_assign_str(input)?),
+        "skip" => out.skip = true,
+    }
+}
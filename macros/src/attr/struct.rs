@@ -7,7 +7,10 @@ use super::{
     Attr, ContainerAttr, Serde, Tagged,
 };
 use crate::{
-    attr::{parse_assign_str, parse_optional_assign_str, EnumAttr, Inflection, VariantAttr},
+    attr::{
+        parse_assign_str, parse_optional_assign_str, EnumAttr, FunctionAttr, Inflection,
+        VariantAttr,
+    },
     optional::{parse_optional, Optional},
     utils::{extract_docs, parse_attrs},
 };
@@ -26,6 +29,14 @@ pub struct StructAttr {
     pub concrete: HashMap<Ident, Type>,
     pub bound: Option<Vec<WherePredicate>>,
     pub optional_fields: Optional,
+    pub transparent: bool,
+    pub interface: bool,
+    pub brand: bool,
+    pub readonly: bool,
+    // serde-specific
+    pub type_into: Option<Type>,
+    pub type_from: Option<Type>,
+    pub has_default: bool,
 }
 
 impl StructAttr {
@@ -73,6 +84,15 @@ impl StructAttr {
             ..Self::default()
         }
     }
+
+    /// The attributes of the object containing the arguments of a function
+    pub fn from_function(function_attr: &FunctionAttr) -> Self {
+        Self {
+            crate_rename: Some(function_attr.crate_rename()),
+            rename_all: function_attr.rename_all,
+            ..Self::default()
+        }
+    }
 }
 
 impl Attr for StructAttr {
@@ -96,6 +116,13 @@ impl Attr for StructAttr {
                 (None, None) => None,
             },
             optional_fields: self.optional_fields.or(other.optional_fields),
+            transparent: self.transparent || other.transparent,
+            interface: self.interface || other.interface,
+            brand: self.brand || other.brand,
+            readonly: self.readonly || other.readonly,
+            type_into: self.type_into.or(other.type_into),
+            type_from: self.type_from.or(other.type_from),
+            has_default: self.has_default || other.has_default,
         }
     }
 
@@ -132,6 +159,74 @@ impl Attr for StructAttr {
             }
         }
 
+        if self.transparent {
+            if self.type_override.is_some() {
+                syn_err!("`transparent` is not compatible with `type`");
+            }
+
+            if self.type_as.is_some() {
+                syn_err!("`transparent` is not compatible with `as`");
+            }
+
+            if self.tag.is_some() {
+                syn_err!("`transparent` is not compatible with `tag`");
+            }
+
+            if matches!(item, Fields::Unit) {
+                syn_err!("`transparent` cannot be used with unit structs");
+            }
+        }
+
+        if self.interface {
+            if self.type_override.is_some() {
+                syn_err!("`interface` is not compatible with `type`");
+            }
+
+            if self.type_as.is_some() {
+                syn_err!("`interface` is not compatible with `as`");
+            }
+
+            if self.transparent {
+                syn_err!("`interface` is not compatible with `transparent`");
+            }
+
+            if !matches!(item, Fields::Named(f) if !f.named.is_empty()) {
+                syn_err!("`interface` can only be used on structs with named fields");
+            }
+        }
+
+        if self.brand {
+            if self.type_override.is_some() {
+                syn_err!("`brand` is not compatible with `type`");
+            }
+
+            if self.type_as.is_some() {
+                syn_err!("`brand` is not compatible with `as`");
+            }
+
+            let newtype = matches!(item, Fields::Unnamed(f) if f.unnamed.len() == 1);
+            if !newtype && !self.transparent {
+                syn_err!("`brand` can only be used on newtypes and `transparent` structs");
+            }
+        }
+
+        if self.readonly {
+            if self.type_override.is_some() {
+                syn_err!("`readonly` is not compatible with `type`");
+            }
+
+            if self.type_as.is_some() {
+                syn_err!("`readonly` is not compatible with `as`");
+            }
+
+            let newtype = matches!(item, Fields::Unnamed(f) if f.unnamed.len() == 1);
+            if self.transparent || newtype || matches!(item, Fields::Unit) {
+                syn_err!(
+                    "`readonly` can only be used on structs with named fields and tuple structs"
+                );
+            }
+        }
+
         if !matches!(item, Fields::Named(_)) && self.tag.is_some() {
             syn_err!("`tag` cannot be used with unit or tuple structs");
         }
@@ -161,6 +256,10 @@ impl_parse! {
         "concrete" => out.concrete = parse_concrete(input)?,
         "bound" => out.bound = Some(parse_bound(input)?),
         "optional_fields" => out.optional_fields = parse_optional(input)?,
+        "transparent" => out.transparent = true,
+        "interface" => out.interface = true,
+        "brand" => out.brand = true,
+        "readonly" => out.readonly = true,
     }
 }
 
@@ -170,10 +269,16 @@ impl_parse! {
         "rename_all" => out.0.rename_all = Some(parse_assign_inflection(input)?),
         "tag" => out.0.tag = Some(parse_assign_str(input)?),
         "bound" => out.0.bound = Some(parse_bound(input)?),
-        // parse #[serde(default)] to not emit a warning
-        "deny_unknown_fields" | "default" => {
+        "transparent" => out.0.transparent = true,
+        "into" => out.0.type_into = Some(parse_assign_from_str(input)?),
+        "from" | "try_from" => out.0.type_from = Some(parse_assign_from_str(input)?),
+        // every field of a struct with `#[serde(default)]` has a default value
+        "default" => {
             parse_optional_assign_str(input)?;
+            out.0.has_default = true;
         },
+        // parse #[serde(deny_unknown_fields)] to not emit a warning
+        "deny_unknown_fields" => {},
         // parse #[serde(crate = "...")] to not emit a warning
         "crate" => {
             parse_assign_str(input)?;
@@ -1,13 +1,13 @@
 use syn::{Attribute, Expr, Fields, Ident, Result, Type, Variant};
 
-use super::{parse_assign_expr, Attr, Serde};
+use super::{parse_assign_expr, parse_rename, Attr, Serde};
 use crate::{
     attr::{
         parse_assign_from_str, parse_assign_inflection, parse_assign_str,
         parse_optional_assign_str, Inflection,
     },
     optional::{parse_optional, Optional},
-    utils::parse_attrs,
+    utils::{make_string_literal, parse_attrs},
 };
 
 #[derive(Default)]
@@ -20,6 +20,11 @@ pub struct VariantAttr {
     pub skip: bool,
     pub untagged: bool,
     pub optional_fields: Optional,
+    // serde-specific
+    pub rename_serialize: Option<String>,
+    pub rename_deserialize: Option<String>,
+    pub skip_serializing: bool,
+    pub skip_deserializing: bool,
 }
 
 impl VariantAttr {
@@ -29,6 +34,12 @@ impl VariantAttr {
             let serde_attr = crate::utils::parse_serde_attrs::<VariantAttr>(attrs);
             result = result.merge(serde_attr.0);
         }
+
+        // a variant which is neither serialized nor deserialized is skipped entirely
+        if result.skip_serializing && result.skip_deserializing {
+            result.skip = true;
+        }
+
         Ok(result)
     }
 }
@@ -37,6 +48,9 @@ impl Attr for VariantAttr {
     type Item = Variant;
 
     fn merge(self, other: Self) -> Self {
+        // `#[ts(rename)]` takes precedence over `#[serde(rename(serialize = "..", ..))]`
+        let renamed = self.rename.is_some();
+
         Self {
             type_as: self.type_as.or(other.type_as),
             type_override: self.type_override.or(other.type_override),
@@ -46,6 +60,16 @@ impl Attr for VariantAttr {
             skip: self.skip || other.skip,
             untagged: self.untagged || other.untagged,
             optional_fields: self.optional_fields.or(other.optional_fields),
+            rename_serialize: match renamed {
+                true => self.rename_serialize,
+                false => self.rename_serialize.or(other.rename_serialize),
+            },
+            rename_deserialize: match renamed {
+                true => self.rename_deserialize,
+                false => self.rename_deserialize.or(other.rename_deserialize),
+            },
+            skip_serializing: self.skip_serializing || other.skip_serializing,
+            skip_deserializing: self.skip_deserializing || other.skip_deserializing,
         }
     }
 
@@ -108,9 +132,21 @@ impl_parse! {
 
 impl_parse! {
     Serde<VariantAttr>(input, out) {
-        "rename" => out.0.rename = Some(parse_assign_expr(input)?),
+        "rename" => {
+            let span = input.span();
+            let (rename, serialize, deserialize) = parse_rename(input)?;
+            out.0.rename = rename.map(|rename| make_string_literal(&rename, span));
+            out.0.rename_serialize = serialize;
+            out.0.rename_deserialize = deserialize;
+        },
+        // aliases are only accepted during deserialization, so they are not part of the bindings
+        "alias" => {
+            parse_assign_str(input)?;
+        },
         "rename_all" => out.0.rename_all = Some(parse_assign_inflection(input)?),
         "skip" => out.0.skip = true,
+        "skip_serializing" => out.0.skip_serializing = true,
+        "skip_deserializing" => out.0.skip_deserializing = true,
         "untagged" => out.0.untagged = true,
         // parse #[serde(borrow)] or `#[serde(borrow = "..")]` to not emit a warning
         "borrow" => {
@@ -2,7 +2,7 @@ use std::{collections::HashSet, rc::Rc};
 
 use proc_macro2::TokenStream;
 use quote::{quote, ToTokens};
-use syn::{Path, Type};
+use syn::{Expr, Path, Type};
 
 pub struct Dependencies {
     crate_rename: Rc<Path>,
@@ -25,6 +25,12 @@ enum Dependency {
         ty: Rc<Type>,
     },
     Type(Rc<Type>),
+    // A dependency which is only visited if `condition` evaluates to `true`.
+    // The condition may refer to the `TypeVisitor` as `v`.
+    Conditional {
+        condition: Rc<Expr>,
+        dependency: Box<Dependency>,
+    },
 }
 
 impl Dependencies {
@@ -64,6 +70,20 @@ impl Dependencies {
         self.types.extend(other.types);
     }
 
+    /// Adds all dependencies from `other`, but only visits them if `condition` evaluates to `true`.
+    /// The condition may refer to the `TypeVisitor` as `v`.
+    pub fn append_if(&mut self, condition: Expr, other: Dependencies) {
+        let condition = Rc::new(condition);
+        self.dependencies
+            .extend(other.dependencies.into_iter().map(|dependency| {
+                Dependency::Conditional {
+                    condition: condition.clone(),
+                    dependency: Box::new(dependency),
+                }
+            }));
+        self.types.extend(other.types);
+    }
+
     fn push_type(&mut self, ty: &Type) -> Rc<Type> {
         // this can be replaces with `get_or_insert_owned` once #60896 is stabilized
         match self.types.get(ty) {
@@ -97,6 +117,10 @@ impl ToTokens for Dependency {
                 quote![<#ty as #crate_rename::TS>::visit_generics(v)]
             }
             Dependency::Type(ty) => quote![v.visit::<#ty>()],
+            Dependency::Conditional {
+                condition,
+                dependency,
+            } => quote![if #condition { #dependency }],
         });
     }
 }
@@ -0,0 +1,40 @@
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{parse_quote, Expr, Path};
+
+/// Returns an expression which evaluates to `true` if an item annotated with
+/// `#[serde(skip_serializing)]` and/or `#[serde(skip_deserializing)]` is not part of the bindings
+/// generated for `direction`, an expression evaluating to a `Direction`.
+///
+/// If the item is part of the bindings in every direction, `None` is returned.
+pub fn skipped(
+    crate_rename: &Path,
+    direction: TokenStream,
+    skip_serializing: bool,
+    skip_deserializing: bool,
+) -> Option<Expr> {
+    let skipped_in = match (skip_serializing, skip_deserializing) {
+        (false, false) => return None,
+        (true, true) => return Some(parse_quote!(true)),
+        (true, false) => quote!(Serialize),
+        (false, true) => quote!(Deserialize),
+    };
+
+    Some(parse_quote!(#direction == #crate_rename::Direction::#skipped_in))
+}
+
+/// Returns an expression which evaluates to `serialize` or `deserialize`, depending on the
+/// direction for which bindings are generated.
+/// In `Direction::Both`, the name used for serialization is used.
+pub fn name(crate_rename: &Path, serialize: Expr, deserialize: Expr) -> Expr {
+    if serialize == deserialize {
+        return serialize;
+    }
+
+    parse_quote! {
+        match cfg.direction() {
+            #crate_rename::Direction::Deserialize => #deserialize,
+            _ => #serialize,
+        }
+    }
+}
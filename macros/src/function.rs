@@ -0,0 +1,222 @@
+use std::collections::HashMap;
+
+use proc_macro2::TokenStream;
+use quote::quote;
+use syn::{
+    ext::IdentExt, parse_quote, punctuated::Punctuated, Field, FieldMutability, Fields,
+    FieldsNamed, FnArg, Generics, ItemFn, Pat, QSelf, Result, ReturnType, Type, TypeArray,
+    TypeGroup, TypeImplTrait, TypeParamBound, TypeParen, TypePath, TypeReference, TypeSlice,
+    TypeTuple, Visibility,
+};
+
+use crate::{
+    attr::{Attr, ContainerAttr, FunctionAttr, Inflection, StructAttr},
+    deps::Dependencies,
+    types,
+    utils::{extract_docs, make_string_literal},
+    DerivedTS,
+};
+
+/// Generates the bindings of a function annotated with `#[ts_rs::function]`.
+///
+/// The function itself is kept as it is. `TS` is implemented for a struct with the same name,
+/// which does not collide with the function since it only lives in the type namespace.
+pub(crate) fn function(attr: FunctionAttr, mut item: ItemFn) -> Result<TokenStream> {
+    attr.assert_validity(&item)?;
+
+    let crate_rename = attr.crate_rename();
+    let ident = item.sig.ident.clone();
+    let ts_name = attr.rename.clone().unwrap_or_else(|| {
+        let name = Inflection::Camel.apply(&ident.unraw().to_string());
+        make_string_literal(&name, ident.span())
+    });
+
+    // the arguments are passed as an object, which is formatted like a struct
+    let mut args = Punctuated::new();
+    for input in &mut item.sig.inputs {
+        let FnArg::Typed(arg) = input else {
+            unreachable!("methods are rejected by `FunctionAttr::assert_validity`")
+        };
+        let Pat::Ident(pat) = &*arg.pat else {
+            unreachable!("patterns are rejected by `FunctionAttr::assert_validity`")
+        };
+
+        // `#[ts(..)]` is not a valid attribute on its own, so it is removed from the function
+        let (ts_attrs, attrs) = std::mem::take(&mut arg.attrs)
+            .into_iter()
+            .partition(|attr| attr.path().is_ident("ts"));
+        arg.attrs = attrs;
+
+        let mut ty = (*arg.ty).clone();
+        static_lifetimes(&mut ty);
+        args.push(Field {
+            attrs: ts_attrs,
+            vis: Visibility::Inherited,
+            mutability: FieldMutability::None,
+            ident: Some(pat.ident.clone()),
+            colon_token: Some(arg.colon_token),
+            ty,
+        });
+    }
+
+    let (params, mut dependencies) = match args.is_empty() {
+        true => (quote!(vec![]), Dependencies::new(crate_rename.clone())),
+        false => {
+            let fields = Fields::Named(FieldsNamed {
+                brace_token: Default::default(),
+                named: args,
+            });
+            let derived =
+                types::type_def(&StructAttr::from_function(&attr), ts_name.clone(), &fields)?;
+            let inline = derived.inline;
+            let params = quote! {
+                vec![#crate_rename::ast::Param {
+                    name: "args".to_owned(),
+                    ty: #inline,
+                }]
+            };
+            (params, derived.dependencies)
+        }
+    };
+
+    let returned = match &item.sig.output {
+        ReturnType::Default => None,
+        ReturnType::Type(_, ty) => resolved(ty),
+    };
+    let returned = match returned {
+        None => quote!(#crate_rename::ast::TsType::Keyword(#crate_rename::ast::Keyword::Void)),
+        Some(mut ty) => {
+            static_lifetimes(&mut ty);
+            dependencies.push(&ty);
+            quote!(<#ty as #crate_rename::TS>::name_ast(cfg))
+        }
+    };
+
+    let decl_kind = attr
+        .declare
+        .then(|| quote!(#crate_rename::ast::DeclarationKind::Function));
+
+    let derived = DerivedTS {
+        crate_rename: crate_rename.clone(),
+        ts_name,
+        docs: extract_docs(&item.attrs),
+        inline: quote! {
+            #crate_rename::ast::TsType::Function {
+                params: #params,
+                returns: Box::new(#crate_rename::ast::TsType::Reference {
+                    name: "Promise".to_owned(),
+                    generics: vec![#returned],
+                }),
+            }
+        },
+        inline_flattened: None,
+        dependencies,
+        concrete: HashMap::new(),
+        bound: None,
+        ts_enum: None,
+        is_enum: quote!(false),
+        interface: None,
+        variant_values: None,
+        decl_kind,
+        export: attr.export,
+        export_to: attr.export_to,
+    };
+
+    let vis = &item.vis;
+    let ts_impl = derived.into_impl(ident.clone(), Generics::default());
+
+    Ok(quote! {
+        #item
+
+        #[doc(hidden)]
+        #[allow(non_camel_case_types)]
+        #vis struct #ident {}
+
+        #ts_impl
+    })
+}
+
+/// Returns the type the promise returned by a function resolves to, or `None` if it returns `()`.
+/// For functions returning `Result<T, E>`, this is `T`, since errors reject the promise instead.
+/// Functions returning `impl Future<Output = T>` are treated like `async` functions returning `T`.
+pub(crate) fn resolved(ty: &Type) -> Option<Type> {
+    use syn::{
+        AngleBracketedGenericArguments as GenericArgs, GenericArgument as G, PathArguments as P,
+    };
+
+    match ty {
+        Type::Tuple(TypeTuple { elems, .. }) if elems.is_empty() => None,
+        Type::Paren(TypeParen { elem, .. }) | Type::Group(TypeGroup { elem, .. }) => resolved(elem),
+        Type::Path(TypePath { qself: None, path }) => {
+            let last = path.segments.last().unwrap();
+            match &last.arguments {
+                P::AngleBracketed(GenericArgs { args, .. }) if last.ident == "Result" => {
+                    match args.first() {
+                        Some(G::Type(ok)) => resolved(ok),
+                        _ => Some(ty.clone()),
+                    }
+                }
+                _ => Some(ty.clone()),
+            }
+        }
+        Type::ImplTrait(TypeImplTrait { bounds, .. }) => {
+            let output = bounds.iter().find_map(|bound| {
+                let TypeParamBound::Trait(bound) = bound else {
+                    return None;
+                };
+                let last = bound.path.segments.last()?;
+                let P::AngleBracketed(GenericArgs { args, .. }) = &last.arguments else {
+                    return None;
+                };
+                args.iter().find_map(|arg| match arg {
+                    G::AssocType(assoc) if last.ident == "Future" && assoc.ident == "Output" => {
+                        Some(&assoc.ty)
+                    }
+                    _ => None,
+                })
+            });
+            match output {
+                Some(output) => resolved(output),
+                None => Some(ty.clone()),
+            }
+        }
+        _ => Some(ty.clone()),
+    }
+}
+
+/// Replaces all lifetimes within `ty` with `'static`, so the types of the arguments can be used
+/// outside of the function.
+pub(crate) fn static_lifetimes(ty: &mut Type) {
+    use syn::{
+        AngleBracketedGenericArguments as GenericArgs, GenericArgument as G, PathArguments as P,
+    };
+
+    match ty {
+        Type::Reference(TypeReference { lifetime, elem, .. }) => {
+            *lifetime = Some(parse_quote!('static));
+            static_lifetimes(elem);
+        }
+        Type::Array(TypeArray { elem, .. })
+        | Type::Group(TypeGroup { elem, .. })
+        | Type::Paren(TypeParen { elem, .. })
+        | Type::Slice(TypeSlice { elem, .. }) => static_lifetimes(elem),
+        Type::Tuple(TypeTuple { elems, .. }) => elems.iter_mut().for_each(static_lifetimes),
+        Type::Path(TypePath { qself, path }) => {
+            if let Some(QSelf { ty, .. }) = qself {
+                static_lifetimes(ty);
+            }
+            for segment in &mut path.segments {
+                if let P::AngleBracketed(GenericArgs { args, .. }) = &mut segment.arguments {
+                    for arg in args {
+                        match arg {
+                            G::Lifetime(lifetime) => *lifetime = parse_quote!('static),
+                            G::Type(ty) => static_lifetimes(ty),
+                            _ => (),
+                        }
+                    }
+                }
+            }
+        }
+        _ => (),
+    }
+}
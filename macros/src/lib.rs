@@ -17,25 +17,48 @@ use crate::{attr::Repr, deps::Dependencies, utils::format_generics};
 mod utils;
 mod attr;
 mod deps;
+mod direction;
+mod function;
 mod optional;
+mod service;
 mod types;
 
 struct DerivedTS {
     crate_rename: Path,
     ts_name: Expr,
     docs: Vec<Expr>,
+    // expression of type `TsType`. For a TypeScript enum, it is an expression of type
+    // `Vec<EnumMember>` containing its members instead.
     inline: TokenStream,
+    // expression of type `TsType`
     inline_flattened: Option<TokenStream>,
     dependencies: Dependencies,
     concrete: HashMap<Ident, Type>,
     bound: Option<Vec<WherePredicate>>,
     ts_enum: Option<Repr>,
     is_enum: TokenStream,
+    interface: Option<Interface>,
+    // expression of type `(String, Vec<String>)` containing the name and the elements of the
+    // array of variants declared for `#[ts(export_values)]`
+    variant_values: Option<TokenStream>,
+    // expression of type `DeclarationKind` replacing the `type` alias declaring the type, e.g. the
+    // `declare function` of a function
+    decl_kind: Option<TokenStream>,
 
     export: bool,
     export_to: Option<Expr>,
 }
 
+/// A struct which may be declared as an `interface`
+struct Interface {
+    // `#[ts(interface)]`, declaring the struct as an interface regardless of the `Config`
+    forced: bool,
+    // expression of type `Vec<Property>` containing the fields of the struct
+    fields: TokenStream,
+    // types of the flattened fields, which are extended if they are interfaces themselves
+    flattened: Vec<Type>,
+}
+
 impl DerivedTS {
     fn into_impl(mut self, rust_ty: Ident, generics: Generics) -> TokenStream {
         let export = self
@@ -91,6 +114,14 @@ impl DerivedTS {
         let decl = self.generate_decl_fn(&rust_ty, &generics);
         let dependencies = &self.dependencies;
         let generics_fn = self.generate_generics_fn(&generics);
+        let interface = self.generate_interface_fns();
+        let variant_values = self.variant_values.as_ref().map(|values| {
+            quote! {
+                fn variant_values(cfg: &#crate_rename::Config) -> Option<(String, Vec<String>)> {
+                    Some(#values)
+                }
+            }
+        });
 
         quote! {
             #[automatically_derived]
@@ -109,6 +140,8 @@ impl DerivedTS {
                 #decl
                 #inline
                 #generics_fn
+                #interface
+                #variant_values
                 #output_path_fn
 
                 fn visit_dependencies(v: &mut impl #crate_rename::TypeVisitor)
@@ -123,27 +156,6 @@ impl DerivedTS {
         }
     }
 
-    /// Returns an expression which evaluates to the TypeScript name of the type, including generic
-    /// parameters.
-    fn name_with_generics(&self, generics: &Generics) -> TokenStream {
-        let name = &self.ts_name;
-        let crate_rename = &self.crate_rename;
-        let mut generics_ts_names = generics
-            .type_params()
-            .filter(|ty| !self.concrete.contains_key(&ty.ident))
-            .map(|ty| &ty.ident)
-            .map(|generic| quote!(<#generic as #crate_rename::TS>::name(cfg)))
-            .peekable();
-
-        if generics_ts_names.peek().is_some() {
-            quote! {
-                format!("{}<{}>", #name, vec![#(#generics_ts_names),*].join(", "))
-            }
-        } else {
-            quote!((#name).to_string())
-        }
-    }
-
     /// Generate a dummy unit struct for every generic type parameter of this type.
     /// # Example:
     /// ```compile_fail
@@ -179,6 +191,12 @@ impl DerivedTS {
                     fn name(cfg: &#crate_rename::Config) -> String { stringify!(#generics).to_owned() }
                     fn inline(cfg: &#crate_rename::Config) -> String { panic!("{} cannot be inlined", #name) }
                     fn inline_flattened(cfg: &#crate_rename::Config) -> String { stringify!(#generics).to_owned() }
+                    fn name_ast(cfg: &#crate_rename::Config) -> #crate_rename::ast::TsType {
+                        #crate_rename::ast::TsType::reference(stringify!(#generics))
+                    }
+                    fn inline_flattened_ast(cfg: &#crate_rename::Config) -> #crate_rename::ast::TsType {
+                        #crate_rename::ast::TsType::reference(stringify!(#generics))
+                    }
                     fn decl(cfg: &#crate_rename::Config) -> String { panic!("{} cannot be declared", #name) }
                     fn decl_concrete(cfg: &#crate_rename::Config) -> String { panic!("{} cannot be declared", #name) }
                 }
@@ -198,14 +216,19 @@ impl DerivedTS {
                 None => quote! { #crate_rename::Dummy },
                 Some(ty) => quote! { #ty },
             });
-        let ty = quote!(<#rust_ty<#(#generic_params),*> as #crate_rename::TS>);
+        let concrete = quote!(#rust_ty<#(#generic_params),*>);
+        let ty = quote!(<#concrete as #crate_rename::TS>);
 
         quote! {
+            #crate_rename::__register!(#crate_rename::Registration::of::<#concrete>());
+
             #[cfg(test)]
             #[test]
-            fn #test_fn() {
-                let cfg = #crate_rename::Config::from_env();
-                #ty::export_all(&cfg).expect("could not export type");
+            fn #test_fn() -> ::std::result::Result<(), #crate_rename::__TestError> {
+                let cfg = #crate_rename::Config::load()
+                    .map_err(#crate_rename::__TestError::context("could not load the configuration"))?;
+                #ty::export_all(&cfg)
+                    .map_err(#crate_rename::__TestError::context("could not export type"))
             }
         }
     }
@@ -233,10 +256,21 @@ impl DerivedTS {
 
     fn generate_name_fn(&self, generics: &Generics) -> TokenStream {
         let crate_rename = &self.crate_rename;
-        let name = self.name_with_generics(generics);
+        let name = &self.ts_name;
+        let generics = generics
+            .type_params()
+            .filter(|ty| !self.concrete.contains_key(&ty.ident))
+            .map(|ty| &ty.ident);
         quote! {
             fn name(cfg: &#crate_rename::Config) -> String {
-                #name
+                <Self as #crate_rename::TS>::name_ast(cfg).to_string()
+            }
+
+            fn name_ast(cfg: &#crate_rename::Config) -> #crate_rename::ast::TsType {
+                #crate_rename::ast::TsType::Reference {
+                    name: (#name).to_string(),
+                    generics: vec![#(<#generics as #crate_rename::TS>::name_ast(cfg)),*],
+                }
             }
         }
     }
@@ -251,80 +285,162 @@ impl DerivedTS {
             }
         });
 
-        let inline = match self.ts_enum {
-            Some(Repr::Int) => quote! {
-                let variants = #inline.replace(|x: char| !x.is_numeric() && x != ',', "");
-                let mut variants = variants
-                    .split(',')
-                    .map(|x| x.parse().ok())
-                    .peekable();
-
-                if variants.peek().is_none() {
-                    return "never".into()
-                }
-
-                let mut buffer = String::new();
+        // a TypeScript enum is inlined as the union of the values of its members
+        let values = match self.ts_enum {
+            Some(Repr::Int) => Some(quote! {
                 let mut latest = None::<isize>;
-
-                for variant in variants {
-                    let value = variant.or(latest.map(|x| x + 1)).unwrap_or(0);
-                    buffer.push_str(&format!("{} | ", value));
-
-                    latest = Some(value)
-                }
-
-                buffer.trim_end_matches(['|', ' ']).into()
-            },
-            Some(Repr::Name) => quote! {
-                let variants = #inline;
-                let mut variants = variants
-                    .split(',')
-                    .map(|x| x.split_once(" = ").unwrap().1.to_string())
-                    .peekable();
-
-                if variants.peek().is_none() {
-                    return "never".into()
-                }
-
-                let mut buffer = String::new();
-                for variant in variants {
-                    buffer.push_str(&variant);
-                    buffer.push_str(" | ");
-                }
-
-                buffer.trim_end_matches(['|', ' ']).into()
+                members
+                    .into_iter()
+                    .map(|member| {
+                        let value = match member.value {
+                            Some(#crate_rename::ast::Literal::Number(value)) => value.parse().ok(),
+                            _ => None,
+                        };
+                        let value = value.or(latest.map(|x| x + 1)).unwrap_or(0);
+                        latest = Some(value);
+                        #crate_rename::ast::Literal::Number(value.to_string())
+                    })
+                    .collect::<Vec<_>>()
+            }),
+            Some(Repr::Name) => Some(quote! {
+                members
+                    .into_iter()
+                    .filter_map(|member| member.value)
+                    .collect::<Vec<_>>()
+            }),
+            None => None,
+        };
+        let inline = match values {
+            Some(values) => quote! {
+                let members: Vec<#crate_rename::ast::EnumMember> = #inline;
+                let values = { #values };
+                #crate_rename::ast::TsType::union(
+                    values.into_iter().map(#crate_rename::ast::TsType::Literal).collect()
+                )
             },
             None => quote!(#inline),
         };
 
         quote! {
             fn inline(cfg: &#crate_rename::Config) -> String {
+                <Self as #crate_rename::TS>::inline_ast(cfg).to_string()
+            }
+
+            fn inline_ast(cfg: &#crate_rename::Config) -> #crate_rename::ast::TsType {
                 #inline
             }
 
             fn inline_flattened(cfg: &#crate_rename::Config) -> String {
+                <Self as #crate_rename::TS>::inline_flattened_ast(cfg).to_string()
+            }
+
+            fn inline_flattened_ast(cfg: &#crate_rename::Config) -> #crate_rename::ast::TsType {
                 #inline_flattened
             }
         }
     }
 
-    /// Generates the `decl()` and `decl_concrete()` methods.
-    /// `decl_concrete()` is simple, and simply defers to `inline()`.
-    /// For `decl()`, however, we need to change out the generic parameters of the type, replacing
-    /// them with the dummy types generated by `generate_generic_types()`.
+    /// Generates the `is_interface()` and `interface_ast()` methods for structs which may be
+    /// declared as an interface.
+    fn generate_interface_fns(&self) -> Option<TokenStream> {
+        let Interface {
+            forced,
+            fields,
+            flattened,
+        } = self.interface.as_ref()?;
+        let crate_rename = &self.crate_rename;
+
+        Some(quote! {
+            fn is_interface(interfaces: bool) -> bool {
+                (#forced || interfaces)
+                    #(&& <#flattened as #crate_rename::TS>::is_interface(interfaces))*
+            }
+
+            fn interface_ast(cfg: &#crate_rename::Config) -> Option<#crate_rename::ast::TsType> {
+                if !<Self as #crate_rename::TS>::is_interface(cfg.interfaces()) {
+                    return None;
+                }
+
+                let fields = #crate_rename::ast::TsType::Object(#fields);
+                let extends = vec![#(<#flattened as #crate_rename::TS>::name_ast(cfg)),*];
+                Some(match extends.is_empty() {
+                    true => fields,
+                    false => #crate_rename::ast::TsType::Intersection(
+                        std::iter::once(fields).chain(extends).collect()
+                    ),
+                })
+            }
+        })
+    }
+
+    /// Generates the `decl_ast()`, `decl()` and `decl_concrete()` methods.
+    /// `decl()` prints the declaration returned by `decl_ast()`, and `decl_concrete()` simply
+    /// defers to `inline_ast()`.
+    /// For `decl_ast()`, however, we need to change out the generic parameters of the type,
+    /// replacing them with the dummy types generated by `generate_generic_types()`.
     fn generate_decl_fn(&mut self, rust_ty: &Ident, generics: &Generics) -> TokenStream {
         let crate_rename = &self.crate_rename;
         let name = &self.ts_name;
-
-        if self.ts_enum.is_some() {
-            let inline = &self.inline;
-            return quote! {
-                fn decl_concrete(cfg: &#crate_rename::Config) -> String {
-                    format!("enum {} {{ {} }}", #name, #inline)
+        let docs = &self.docs;
+        let docs = quote!(#crate_rename::docs_text(&[#(#docs),*]));
+
+        let kind = match (&self.decl_kind, self.ts_enum.is_some()) {
+            (Some(kind), _) => Some(kind.clone()),
+            (None, true) => {
+                let members = &self.inline;
+                Some(quote!(#crate_rename::ast::DeclarationKind::Enum(#members)))
+            }
+            (None, false) => None,
+        };
+        // an interface is declared using the type returned by `interface_ast()`
+        let declared = |ty: TokenStream| match &kind {
+            Some(kind) => quote! {
+                (<#ty as #crate_rename::TS>::inline_ast(cfg), #kind)
+            },
+            None => quote! {
+                match <#ty as #crate_rename::TS>::interface_ast(cfg) {
+                    Some(ty) => (ty, #crate_rename::ast::DeclarationKind::Interface),
+                    None => (
+                        <#ty as #crate_rename::TS>::inline_ast(cfg),
+                        #crate_rename::ast::DeclarationKind::Type,
+                    ),
                 }
+            },
+        };
+
+        let concrete = declared(quote!(Self));
+        let decl_fns = quote! {
+            fn decl_concrete(cfg: &#crate_rename::Config) -> String {
+                let (ty, kind) = #concrete;
+                let decl = #crate_rename::ast::Declaration {
+                    name: (#name).to_string(),
+                    generics: vec![],
+                    ty,
+                    docs: None,
+                    kind,
+                };
+                decl.to_string()
+            }
+
+            fn decl(cfg: &#crate_rename::Config) -> String {
+                <Self as #crate_rename::TS>::decl_ast(cfg).to_string()
+            }
+        };
 
-                fn decl(cfg: &#crate_rename::Config) -> String {
-                    format!("enum {} {{ {} }}", #name, #inline)
+        // TypeScript enums and functions are not generic
+        if kind.is_some() {
+            return quote! {
+                #decl_fns
+
+                fn decl_ast(cfg: &#crate_rename::Config) -> #crate_rename::ast::Declaration {
+                    let (ty, kind) = #concrete;
+                    #crate_rename::ast::Declaration {
+                        name: (#name).to_string(),
+                        generics: vec![],
+                        ty,
+                        docs: #docs,
+                        kind,
+                    }
                 }
             };
         }
@@ -353,15 +469,22 @@ impl DerivedTS {
             // use instead. This might be something to change in the future.
             G::Const(ConstParam { ident, .. }) => Some(quote!(#ident)),
         });
+        let generic_idents = generic_idents.collect::<Vec<_>>();
+        let declared = declared(quote!(#rust_ty<#(#generic_idents,)*>));
+
         quote! {
-            fn decl_concrete(cfg: &#crate_rename::Config) -> String {
-                format!("type {} = {};", #name, <Self as #crate_rename::TS>::inline(cfg))
-            }
-            fn decl(cfg: &#crate_rename::Config) -> String {
+            #decl_fns
+
+            fn decl_ast(cfg: &#crate_rename::Config) -> #crate_rename::ast::Declaration {
                 #generic_types
-                let inline = <#rust_ty<#(#generic_idents,)*> as #crate_rename::TS>::inline(cfg);
-                let generics = #ts_generics;
-                format!("type {}{generics} = {inline};", #name)
+                let (ty, kind) = #declared;
+                #crate_rename::ast::Declaration {
+                    name: (#name).to_string(),
+                    generics: #ts_generics,
+                    ty,
+                    docs: #docs,
+                    kind,
+                }
             }
         }
     }
@@ -514,6 +637,54 @@ pub fn typescript(input: proc_macro::TokenStream) -> proc_macro::TokenStream {
     .into()
 }
 
+/// Generates TypeScript bindings for a function.
+#[proc_macro_attribute]
+pub fn function(
+    attr: proc_macro::TokenStream,
+    input: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    match function_entry(attr, input) {
+        Err(err) => err.to_compile_error(),
+        Ok(result) => result,
+    }
+    .into()
+}
+
+fn function_entry(
+    attr: proc_macro::TokenStream,
+    input: proc_macro::TokenStream,
+) -> Result<TokenStream> {
+    let attr = match attr.is_empty() {
+        true => attr::FunctionAttr::default(),
+        false => syn::parse(attr)?,
+    };
+    function::function(attr, syn::parse(input)?)
+}
+
+/// Generates TypeScript bindings for a trait.
+#[proc_macro_attribute]
+pub fn service(
+    attr: proc_macro::TokenStream,
+    input: proc_macro::TokenStream,
+) -> proc_macro::TokenStream {
+    match service_entry(attr, input) {
+        Err(err) => err.to_compile_error(),
+        Ok(result) => result,
+    }
+    .into()
+}
+
+fn service_entry(
+    attr: proc_macro::TokenStream,
+    input: proc_macro::TokenStream,
+) -> Result<TokenStream> {
+    let attr = match attr.is_empty() {
+        true => attr::ServiceAttr::default(),
+        false => syn::parse(attr)?,
+    };
+    service::service(attr, syn::parse(input)?)
+}
+
 fn entry(input: proc_macro::TokenStream) -> Result<TokenStream> {
     let input = syn::parse::<Item>(input)?;
     let (ts, ident, generics) = match input {
@@ -103,10 +103,21 @@ pub fn apply(
         ),
         // no applicable `#[ts(optional)]` attributes
         _ => {
-            // field may be omitted during serialization and has a default value, so the field can be
-            // treated as `#[ts(optional = nullable)]`.
-            let is_optional = attr.maybe_omitted && attr.has_default;
-            (parse_quote!(#is_optional), field_ty.clone())
+            // If the field may be omitted during serialization, it is optional in serialized data.
+            // If it has a default value, it may be omitted when deserializing.
+            // Bindings used in both directions treat the field as `#[ts(optional = nullable)]` only
+            // if both is the case.
+            let is_optional = match (attr.maybe_omitted, attr.has_default) {
+                (false, false) => parse_quote!(false),
+                (true, true) => parse_quote!(true),
+                (true, false) => parse_quote! {
+                    cfg.direction() == #crate_rename::Direction::Serialize
+                },
+                (false, true) => parse_quote! {
+                    cfg.direction() == #crate_rename::Direction::Deserialize
+                },
+            };
+            (is_optional, field_ty.clone())
         }
     }
 }
@@ -0,0 +1,205 @@
+use std::collections::HashMap;
+
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{
+    ext::IdentExt, parse_quote, FnArg, Generics, ItemTrait, Pat, Result, ReturnType, TraitItem,
+};
+
+use crate::{
+    attr::{Attr, ContainerAttr, Inflection, MethodAttr, ServiceAttr},
+    deps::Dependencies,
+    function::{resolved, static_lifetimes},
+    utils::{extract_docs, make_string_literal, parse_attrs},
+    DerivedTS, Interface,
+};
+
+/// Generates the bindings of a trait annotated with `#[ts_rs::service]`.
+///
+/// The trait itself is kept as it is. `TS` is implemented for a struct named after the trait with
+/// a `Client` suffix, which is declared as an interface containing a method for every method of
+/// the trait. With `#[ts_rs::service(map)]`, `TS` is also implemented for a struct with a `Map`
+/// suffix, declaring a type which maps the name of every method to its request and response.
+pub(crate) fn service(attr: ServiceAttr, mut item: ItemTrait) -> Result<TokenStream> {
+    attr.assert_validity(&item)?;
+
+    let crate_rename = attr.crate_rename();
+    let ident = item.ident.unraw();
+    let ts_name = attr
+        .rename
+        .clone()
+        .unwrap_or_else(|| make_string_literal(&ident.to_string(), ident.span()));
+    let rename_all = attr.rename_all.unwrap_or(Inflection::Camel);
+
+    let mut dependencies = Dependencies::new(crate_rename.clone());
+    // expressions of type `Property`, e.g. `getUser(id: UserId): Promise<User>,`
+    let mut methods = Vec::new();
+    // expressions of type `Property`, e.g. `getUser: { req: { id: UserId, }, res: User, },`
+    let mut entries = Vec::new();
+
+    for trait_item in &mut item.items {
+        let TraitItem::Fn(method) = trait_item else {
+            continue;
+        };
+
+        // `#[ts(..)]` is not a valid attribute on its own, so it is removed from the trait
+        let method_attr = parse_attrs::<MethodAttr>(&method.attrs)?;
+        method.attrs.retain(|attr| !attr.path().is_ident("ts"));
+        method_attr.assert_validity(method)?;
+
+        if method_attr.skip {
+            continue;
+        }
+
+        let name = method_attr.rename.unwrap_or_else(|| {
+            let name = method.sig.ident.unraw().to_string();
+            rename_all.apply(&name)
+        });
+
+        // expressions of type `Param`, e.g. `id: UserId`
+        let mut params = Vec::new();
+        for input in &method.sig.inputs {
+            let FnArg::Typed(arg) = input else {
+                continue;
+            };
+            let Pat::Ident(pat) = &*arg.pat else {
+                unreachable!("patterns are rejected by `MethodAttr::assert_validity`")
+            };
+
+            let param = Inflection::Camel.apply(&pat.ident.unraw().to_string());
+            let mut ty = (*arg.ty).clone();
+            static_lifetimes(&mut ty);
+            dependencies.push(&ty);
+            params.push(quote! {
+                #crate_rename::ast::Param {
+                    name: #param.to_owned(),
+                    ty: <#ty as #crate_rename::TS>::name_ast(cfg),
+                }
+            });
+        }
+
+        let returned = match &method.sig.output {
+            ReturnType::Default => None,
+            ReturnType::Type(_, ty) => resolved(ty),
+        };
+        let returned = match returned {
+            None => quote!(#crate_rename::ast::TsType::Keyword(#crate_rename::ast::Keyword::Void)),
+            Some(mut ty) => {
+                static_lifetimes(&mut ty);
+                dependencies.push(&ty);
+                quote!(<#ty as #crate_rename::TS>::name_ast(cfg))
+            }
+        };
+
+        let property = |ty: TokenStream| {
+            quote! {
+                #crate_rename::ast::Property::new(#name, #ty)
+            }
+        };
+        methods.push(property(quote! {
+            #crate_rename::ast::TsType::Function {
+                params: vec![#(#params),*],
+                returns: Box::new(#crate_rename::ast::TsType::Reference {
+                    name: "Promise".to_owned(),
+                    generics: vec![#returned],
+                }),
+            }
+        }));
+        entries.push(property(quote! {{
+            let req = <Vec<#crate_rename::ast::Param>>::into_iter(vec![#(#params),*])
+                .map(|param| #crate_rename::ast::Property::new(param.name, param.ty))
+                .collect();
+            let entry = |name, ty| {
+                #crate_rename::ast::Property::with_style(
+                    name,
+                    ty,
+                    #crate_rename::ast::PropertyStyle::Entry,
+                )
+            };
+            #crate_rename::ast::TsType::Object(vec![
+                entry("req", #crate_rename::ast::TsType::Object(req)),
+                entry("res", #returned),
+            ])
+        }}));
+    }
+
+    let vis = &item.vis;
+    let client_ident = format_ident!("{}Client", ident);
+    let fields = quote!(vec![#(#methods),*]);
+    let client = DerivedTS {
+        crate_rename: crate_rename.clone(),
+        ts_name: ts_name.clone(),
+        docs: extract_docs(&item.attrs),
+        inline: quote!(#crate_rename::ast::TsType::Object(#fields)),
+        inline_flattened: None,
+        dependencies,
+        concrete: HashMap::new(),
+        bound: None,
+        ts_enum: None,
+        is_enum: quote!(false),
+        interface: Some(Interface {
+            forced: true,
+            fields,
+            flattened: vec![],
+        }),
+        variant_values: None,
+        decl_kind: None,
+        export: attr.export,
+        export_to: attr.export_to.clone(),
+    }
+    .into_impl(client_ident.clone(), Generics::default());
+
+    let map = attr.map.as_ref().map(|map_name| {
+        let map_ident = format_ident!("{}Map", ident);
+        let map_ts_name = match map_name {
+            Some(map_name) => make_string_literal(map_name, ident.span()),
+            None => parse_quote!(format!("{}Map", #ts_name)),
+        };
+
+        // the map is exported into the same file as the interface
+        let mut dependencies = Dependencies::new(crate_rename.clone());
+        dependencies.append_from(&parse_quote!(#client_ident));
+        let map = DerivedTS {
+            crate_rename: crate_rename.clone(),
+            ts_name: map_ts_name,
+            docs: vec![],
+            inline: quote!(#crate_rename::ast::TsType::Object(vec![#(#entries),*])),
+            inline_flattened: None,
+            dependencies,
+            concrete: HashMap::new(),
+            bound: None,
+            ts_enum: None,
+            is_enum: quote!(false),
+            interface: None,
+            variant_values: None,
+            decl_kind: None,
+            export: attr.export,
+            export_to: Some(parse_quote! {
+                <#client_ident as #crate_rename::TS>::output_path()
+                    .unwrap()
+                    .to_string_lossy()
+            }),
+        }
+        .into_impl(map_ident.clone(), Generics::default());
+
+        let doc =
+            format!("Map of the requests and responses of [`{ident}`], exported using TypeScript");
+        quote! {
+            #[doc = #doc]
+            #vis struct #map_ident {}
+
+            #map
+        }
+    });
+
+    let doc = format!("Client interface of [`{ident}`], exported using TypeScript");
+    Ok(quote! {
+        #item
+
+        #[doc = #doc]
+        #vis struct #client_ident {}
+
+        #client
+        #map
+    })
+}
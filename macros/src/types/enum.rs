@@ -1,12 +1,13 @@
 use proc_macro2::TokenStream;
-use quote::quote;
-use syn::{ext::IdentExt, Expr, Fields, ItemEnum, Variant};
+use quote::{quote, ToTokens};
+use syn::{ext::IdentExt, parse_quote, Expr, ExprLit, Fields, ItemEnum, Lit, Variant};
 
 use crate::{
-    attr::{Attr, EnumAttr, FieldAttr, Repr, StructAttr, Tagged, VariantAttr},
+    attr::{Attr, EnumAttr, FieldAttr, Inflection, Repr, StructAttr, Tagged, VariantAttr},
     deps::Dependencies,
+    direction,
     types::{self, type_as, type_override},
-    utils::make_string_literal,
+    utils::{make_string_literal, raw_name_to_ts_field},
     DerivedTS,
 };
 
@@ -15,8 +16,6 @@ pub(crate) fn r#enum_def(s: &ItemEnum) -> syn::Result<DerivedTS> {
 
     enum_attr.assert_validity(s)?;
 
-    let crate_rename = enum_attr.crate_rename();
-
     let name = match &enum_attr.rename {
         Some(existing) => existing.clone(),
         None => make_string_literal(&s.ident.unraw().to_string(), s.ident.span()),
@@ -30,33 +29,72 @@ pub(crate) fn r#enum_def(s: &ItemEnum) -> syn::Result<DerivedTS> {
         return type_as::type_as_enum(&enum_attr, name, attr_type_as);
     }
 
+    // `#[serde(into)]` and `#[serde(from)]` are ignored for enums exported as a TypeScript enum
+    let type_into = enum_attr
+        .type_into
+        .clone()
+        .filter(|_| enum_attr.repr.is_none());
+    let type_from = enum_attr
+        .type_from
+        .clone()
+        .filter(|_| enum_attr.repr.is_none());
+
+    let derived = variants_def(s, enum_attr, name)?;
+    let derived = match type_into {
+        Some(type_into) => type_as::serialize_as(derived, &type_into),
+        None => derived,
+    };
+    Ok(match type_from {
+        Some(type_from) => type_as::deserialize_as(derived, &type_from),
+        None => derived,
+    })
+}
+
+fn variants_def(s: &ItemEnum, enum_attr: EnumAttr, name: Expr) -> syn::Result<DerivedTS> {
+    let crate_rename = enum_attr.crate_rename();
+
+    let values_name = variant_values_name(s, &enum_attr);
+
     if s.variants.is_empty() {
-        return Ok(empty_enum(name, enum_attr));
+        return Ok(empty_enum(name, enum_attr, values_name));
     }
 
     let mut formatted_variants = Vec::new();
+    let mut values = Vec::new();
     let mut dependencies = Dependencies::new(crate_rename.clone());
 
     for variant in &s.variants {
         format_variant(
             &mut formatted_variants,
+            &mut values,
             &mut dependencies,
             &enum_attr,
+            &name,
             variant,
         )?;
     }
 
-    let separator = match enum_attr.repr {
-        Some(_) => ", ",
-        None => " | ",
+    // variants which are skipped in the current direction are formatted as `None`. The variants
+    // of a TypeScript enum are its members.
+    let variant = match enum_attr.repr {
+        Some(_) => quote!(#crate_rename::ast::EnumMember),
+        None => quote!(#crate_rename::ast::TsType),
+    };
+    let variants = quote! {
+        <Vec<Option<#variant>>>::into_iter(vec![#(#formatted_variants),*])
+            .flatten()
+            .collect::<Vec<_>>()
+    };
+
+    let inline = match enum_attr.repr {
+        Some(_) => variants,
+        None => quote!(#crate_rename::ast::TsType::union(#variants)),
     };
 
     Ok(DerivedTS {
         crate_rename,
-        inline: quote!([#(#formatted_variants),*].join(#separator)),
-        inline_flattened: enum_attr.repr.is_none().then_some(quote!(
-            format!("({})", [#(#formatted_variants),*].join(" | "))
-        )),
+        inline: inline.clone(),
+        inline_flattened: enum_attr.repr.is_none().then_some(inline),
         dependencies,
         docs: enum_attr.docs,
         export: enum_attr.export,
@@ -66,13 +104,43 @@ pub(crate) fn r#enum_def(s: &ItemEnum) -> syn::Result<DerivedTS> {
         bound: enum_attr.bound,
         ts_enum: enum_attr.repr,
         is_enum: quote!(true),
+        interface: None,
+        variant_values: values_name.map(|values_name| {
+            quote! {(
+                #values_name.to_owned(),
+                [#(#values),*]
+                    .into_iter()
+                    .filter(|value: &String| !value.is_empty())
+                    .collect(),
+            )}
+        }),
+        decl_kind: None,
     })
 }
 
+/// Returns the name of the constant declared for `#[ts(export_values)]`, which defaults to the
+/// name of the enum in SCREAMING_SNAKE_CASE followed by `_VALUES`, e.g. `COLOR_VALUES`.
+fn variant_values_name(s: &ItemEnum, enum_attr: &EnumAttr) -> Option<String> {
+    let name = match enum_attr.export_values.as_ref()? {
+        Some(name) => return Some(name.clone()),
+        None => match &enum_attr.rename {
+            Some(Expr::Lit(ExprLit {
+                lit: Lit::Str(rename),
+                ..
+            })) => rename.value(),
+            _ => s.ident.unraw().to_string(),
+        },
+    };
+
+    Some(format!("{}_VALUES", Inflection::ScreamingSnake.apply(&name)))
+}
+
 fn format_variant(
     formatted_variants: &mut Vec<TokenStream>,
+    values: &mut Vec<TokenStream>,
     dependencies: &mut Dependencies,
     enum_attr: &EnumAttr,
+    enum_name: &Expr,
     variant: &Variant,
 ) -> syn::Result<()> {
     let crate_rename = enum_attr.crate_rename();
@@ -89,7 +157,7 @@ fn format_variant(
     }
 
     let untagged_variant = variant_attr.untagged;
-    let ts_name = match (variant_attr.rename.clone(), &enum_attr.rename_all) {
+    let name = match (variant_attr.rename.clone(), &enum_attr.rename_all) {
         (Some(rn), _) => rn,
         (None, None) => {
             make_string_literal(&variant.ident.unraw().to_string(), variant.ident.span())
@@ -99,17 +167,66 @@ fn format_variant(
             variant.ident.span(),
         ),
     };
+    let directional_name = |rename: &Option<String>| match rename {
+        Some(rename) => make_string_literal(rename, variant.ident.span()),
+        None => name.clone(),
+    };
+    let ts_name = direction::name(
+        &crate_rename,
+        directional_name(&variant_attr.rename_serialize),
+        directional_name(&variant_attr.rename_deserialize),
+    );
+
+    let skipped_in = |direction| {
+        direction::skipped(
+            &crate_rename,
+            direction,
+            variant_attr.skip_serializing,
+            variant_attr.skip_deserializing,
+        )
+    };
+    let skip_formatted = |formatted: TokenStream| match skipped_in(quote!(cfg.direction())) {
+        Some(skipped) => quote!(if #skipped { None } else { Some(#formatted) }),
+        None => quote!(Some(#formatted)),
+    };
+    let skip_value = |value: TokenStream| match skipped_in(quote!(cfg.direction())) {
+        Some(skipped) => quote!(if #skipped { String::new() } else { #value }),
+        None => value,
+    };
+
+    if enum_attr.export_values.is_some() {
+        let value = match (&enum_attr.repr, &ts_name) {
+            // members of a TypeScript enum are referred to by their name, e.g. `Color.Red`
+            (Some(_), Expr::Lit(ExprLit {
+                lit: Lit::Str(member),
+                ..
+            })) if raw_name_to_ts_field(member.value()) == member.value() => {
+                quote!(format!("{}.{}", #enum_name, #ts_name))
+            }
+            (Some(_), _) => quote!(format!("{}[\"{}\"]", #enum_name, #ts_name)),
+            (None, _) => quote!(format!("\"{}\"", #ts_name)),
+        };
+        values.push(skip_value(value));
+    }
 
     if let Some(ref repr) = enum_attr.repr {
-        let formatted = match (repr, &variant.discriminant) {
+        let value = match (repr, &variant.discriminant) {
             (Repr::Int, Some((_, value))) => {
-                quote!(format!("\"{}\" = {}", #ts_name, #value))
+                quote!(Some(#crate_rename::ast::Literal::Number((#value).to_string())))
+            }
+            (Repr::Int, None) => quote!(None),
+            (Repr::Name, _) => {
+                quote!(Some(#crate_rename::ast::Literal::String((#ts_name).to_string())))
+            }
+        };
+        let formatted = quote! {
+            #crate_rename::ast::EnumMember {
+                name: (#ts_name).to_string(),
+                value: #value,
             }
-            (Repr::Int, None) => quote!(format!("\"{}\"", #ts_name)),
-            (Repr::Name, _) => quote!(format!("\"{}\" = \"{}\"", #ts_name, #ts_name)),
         };
 
-        formatted_variants.push(formatted);
+        formatted_variants.push(skip_formatted(formatted));
 
         return Ok(());
     }
@@ -122,26 +239,58 @@ fn format_variant(
         &variant.fields,
     )?;
 
-    let variant_dependencies = variant_type.dependencies;
     let inline_type = variant_type.inline;
 
+    let mut variant_dependencies = Dependencies::new(crate_rename.clone());
     let parsed_ty = match (&variant_attr.type_as, &variant_attr.type_override) {
         (Some(_), Some(_)) => syn_err_spanned!(variant; "`type` is not compatible with `as`"),
         (Some(ty), None) => {
-            dependencies.push(ty);
-            quote!(<#ty as #crate_rename::TS>::name(cfg))
+            variant_dependencies.push(ty);
+            quote!(<#ty as #crate_rename::TS>::name_ast(cfg))
         }
-        (None, Some(ty)) => quote!(#ty.to_owned()),
+        (None, Some(ty)) => quote!(#crate_rename::ast::TsType::Raw(#ty.to_owned())),
         (None, None) => {
-            dependencies.append(variant_dependencies);
+            variant_dependencies.append(variant_type.dependencies);
             inline_type
         }
     };
 
+    match skipped_in(quote!(v.direction())) {
+        Some(skipped) => dependencies.append_if(parse_quote!(!(#skipped)), variant_dependencies),
+        None => dependencies.append(variant_dependencies),
+    }
+
+    // expressions of type `TsType` for the parts of a tagged variant
+    let literal = |value: &dyn ToTokens| {
+        quote! {
+            #crate_rename::ast::TsType::Literal(
+                #crate_rename::ast::Literal::String((#value).to_string())
+            )
+        }
+    };
+    let object = |properties: &[(&dyn ToTokens, TokenStream)]| {
+        let properties = properties.iter().map(|(name, ty)| {
+            quote! {
+                #crate_rename::ast::Property {
+                    name: (#name).to_string(),
+                    ty: #ty,
+                    optional: false,
+                    readonly: cfg.readonly(),
+                    docs: None,
+                    style: #crate_rename::ast::PropertyStyle::Variant,
+                }
+            }
+        });
+        quote!(#crate_rename::ast::TsType::Object(vec![#(#properties),*]))
+    };
+    let intersection = |members: [TokenStream; 2]| {
+        quote!(#crate_rename::ast::TsType::Intersection(vec![#(#members),*]))
+    };
+
     let formatted = match (untagged_variant, enum_attr.tagged()?) {
-        (true, _) | (_, Tagged::Untagged) => quote!(#parsed_ty),
+        (true, _) | (_, Tagged::Untagged) => parsed_ty,
         (false, Tagged::Externally) => match &variant.fields {
-            Fields::Unit => quote!(format!("\"{}\"", #ts_name)),
+            Fields::Unit => literal(&ts_name),
             Fields::Unnamed(unnamed) if unnamed.unnamed.len() == 1 => {
                 let field = &unnamed.unnamed[0];
                 let field_attr = FieldAttr::from_attrs(&field.attrs)?;
@@ -149,12 +298,12 @@ fn format_variant(
                 field_attr.assert_validity(field)?;
 
                 if field_attr.skip {
-                    quote!(format!("\"{}\"", #ts_name))
+                    literal(&ts_name)
                 } else {
-                    quote!(format!("{{ \"{}\": {} }}", #ts_name, #parsed_ty))
+                    object(&[(&ts_name, parsed_ty)])
                 }
             }
-            _ => quote!(format!("{{ \"{}\": {} }}", #ts_name, #parsed_ty)),
+            _ => object(&[(&ts_name, parsed_ty)]),
         },
         (false, Tagged::Adjacently { tag, content }) => match &variant.fields {
             Fields::Unnamed(unnamed) if unnamed.unnamed.len() == 1 => {
@@ -164,29 +313,25 @@ fn format_variant(
                 field_attr.assert_validity(field)?;
 
                 if field_attr.skip {
-                    quote!(format!("{{ \"{}\": \"{}\" }}", #tag, #ts_name))
+                    object(&[(&tag, literal(&ts_name))])
                 } else {
                     let ty = match field_attr.type_override {
-                        Some(type_override) => quote!(#type_override),
+                        Some(type_override) => {
+                            quote!(#crate_rename::ast::TsType::Raw(#type_override.to_owned()))
+                        }
                         None => {
                             let ty = field_attr.type_as(&field.ty);
-                            quote!(<#ty as #crate_rename::TS>::name(cfg))
+                            quote!(<#ty as #crate_rename::TS>::name_ast(cfg))
                         }
                     };
-                    quote!(
-                        format!("{{ \"{}\": \"{}\", \"{}\": {} }}", #tag, #ts_name, #content, #ty)
-                    )
+                    object(&[(&tag, literal(&ts_name)), (&content, ty)])
                 }
             }
-            Fields::Unit => quote!(format!("{{ \"{}\": \"{}\" }}", #tag, #ts_name)),
-            _ => quote!(
-                format!("{{ \"{}\": \"{}\", \"{}\": {} }}", #tag, #ts_name, #content, #parsed_ty)
-            ),
+            Fields::Unit => object(&[(&tag, literal(&ts_name))]),
+            _ => object(&[(&tag, literal(&ts_name)), (&content, parsed_ty)]),
         },
         (false, Tagged::Internally { tag }) => match variant_type.inline_flattened {
-            Some(_) => {
-                quote! { #parsed_ty }
-            }
+            Some(_) => parsed_ty,
             None => match &variant.fields {
                 Fields::Unnamed(unnamed) if unnamed.unnamed.len() == 1 => {
                     let field = &unnamed.unnamed[0];
@@ -195,37 +340,40 @@ fn format_variant(
                     field_attr.assert_validity(field)?;
 
                     if field_attr.skip {
-                        quote!(format!("{{ \"{}\": \"{}\" }}", #tag, #ts_name))
+                        object(&[(&tag, literal(&ts_name))])
                     } else {
                         let ty = match field_attr.type_override {
-                            Some(type_override) => quote! { #type_override },
+                            Some(type_override) => {
+                                quote!(#crate_rename::ast::TsType::Raw(#type_override.to_owned()))
+                            }
                             None => {
                                 let ty = field_attr.type_as(&field.ty);
-                                quote!(<#ty as #crate_rename::TS>::name(cfg))
+                                quote!(<#ty as #crate_rename::TS>::name_ast(cfg))
                             }
                         };
 
-                        quote!(format!("{{ \"{}\": \"{}\" }} & {}", #tag, #ts_name, #ty))
+                        intersection([object(&[(&tag, literal(&ts_name))]), ty])
                     }
                 }
-                Fields::Unit => quote!(format!("{{ \"{}\": \"{}\" }}", #tag, #ts_name)),
-                _ => {
-                    quote!(format!("{{ \"{}\": \"{}\" }} & {}", #tag, #ts_name, #parsed_ty))
-                }
+                Fields::Unit => object(&[(&tag, literal(&ts_name))]),
+                _ => intersection([object(&[(&tag, literal(&ts_name))]), parsed_ty]),
             },
         },
     };
 
-    formatted_variants.push(formatted);
+    formatted_variants.push(skip_formatted(formatted));
     Ok(())
 }
 
 // bindings for an empty enum (`never` in TS)
-fn empty_enum(ts_name: Expr, enum_attr: EnumAttr) -> DerivedTS {
+fn empty_enum(ts_name: Expr, enum_attr: EnumAttr, values_name: Option<String>) -> DerivedTS {
     let crate_rename = enum_attr.crate_rename();
     DerivedTS {
         crate_rename: crate_rename.clone(),
-        inline: quote!("never".to_owned()),
+        inline: match enum_attr.repr {
+            Some(_) => quote!(vec![]),
+            None => quote!(#crate_rename::ast::TsType::Keyword(#crate_rename::ast::Keyword::Never)),
+        },
         docs: enum_attr.docs,
         inline_flattened: None,
         dependencies: Dependencies::new(crate_rename),
@@ -236,5 +384,8 @@ fn empty_enum(ts_name: Expr, enum_attr: EnumAttr) -> DerivedTS {
         bound: enum_attr.bound,
         ts_enum: enum_attr.repr,
         is_enum: quote!(false),
+        interface: None,
+        variant_values: values_name.map(|name| quote!((#name.to_owned(), vec![]))),
+        decl_kind: None,
     }
 }
@@ -27,7 +27,7 @@ pub(crate) fn struct_def(s: &ItemStruct) -> Result<DerivedTS> {
     type_def(&attr, ts_name, &s.fields)
 }
 
-fn type_def(attr: &StructAttr, ts_name: Expr, fields: &Fields) -> Result<DerivedTS> {
+pub(crate) fn type_def(attr: &StructAttr, ts_name: Expr, fields: &Fields) -> Result<DerivedTS> {
     attr.assert_validity(fields)?;
 
     if let Some(attr_type_override) = &attr.type_override {
@@ -37,16 +37,29 @@ fn type_def(attr: &StructAttr, ts_name: Expr, fields: &Fields) -> Result<Derived
         return type_as::type_as_struct(attr, ts_name, attr_type_as);
     }
 
-    match fields {
-        Fields::Named(named) => match named.named.len() {
-            0 if attr.tag.is_none() => Ok(unit::empty_object(attr, ts_name)),
-            _ => named::named(attr, ts_name, named),
-        },
-        Fields::Unnamed(unnamed) => match unnamed.unnamed.len() {
-            0 => Ok(unit::empty_array(attr, ts_name)),
-            1 => newtype::newtype(attr, ts_name, unnamed),
-            _ => tuple::tuple(attr, ts_name, unnamed),
-        },
-        Fields::Unit => Ok(unit::null(attr, ts_name)),
-    }
+    let derived = if attr.transparent {
+        newtype::transparent(attr, ts_name, fields)?
+    } else {
+        match fields {
+            Fields::Named(named) => match named.named.len() {
+                0 if attr.tag.is_none() => unit::empty_object(attr, ts_name),
+                _ => named::named(attr, ts_name, named)?,
+            },
+            Fields::Unnamed(unnamed) => match unnamed.unnamed.len() {
+                0 => unit::empty_array(attr, ts_name),
+                1 => newtype::newtype(attr, ts_name, unnamed)?,
+                _ => tuple::tuple(attr, ts_name, unnamed)?,
+            },
+            Fields::Unit => unit::null(attr, ts_name),
+        }
+    };
+
+    let derived = match &attr.type_into {
+        Some(type_into) => type_as::serialize_as(derived, type_into),
+        None => derived,
+    };
+    Ok(match &attr.type_from {
+        Some(type_from) => type_as::deserialize_as(derived, type_from),
+        None => derived,
+    })
 }
@@ -1,25 +1,38 @@
 use proc_macro2::TokenStream;
 use quote::quote;
-use syn::{spanned::Spanned, Expr, Field, FieldsNamed, Path, Result};
+use syn::{parse_quote, spanned::Spanned, Expr, Field, FieldsNamed, Path, Result, Type};
 
 use crate::{
-    attr::{Attr, ContainerAttr, FieldAttr, Inflection, StructAttr},
+    attr::{Attr, ContainerAttr, FieldAttr, StructAttr},
     deps::Dependencies,
-    optional::Optional,
-    utils::{raw_name_to_ts_field, to_ts_ident},
-    DerivedTS,
+    direction,
+    utils::{make_string_literal, to_ts_ident},
+    DerivedTS, Interface,
 };
 
 pub(crate) fn named(attr: &StructAttr, ts_name: Expr, fields: &FieldsNamed) -> Result<DerivedTS> {
     let crate_rename = attr.crate_rename();
 
     let mut formatted_fields = Vec::new();
-    let mut flattened_fields = Vec::new();
+    let mut flattened = Flattened {
+        fields: Vec::new(),
+        types: Vec::new(),
+        dependencies: Dependencies::new(crate_rename.clone()),
+    };
     let mut dependencies = Dependencies::new(crate_rename.clone());
 
     if let Some(tag) = &attr.tag {
         formatted_fields.push(quote! {
-            format!("\"{}\": \"{}\",", #tag, #ts_name)
+            Some(#crate_rename::ast::Property {
+                name: #tag.to_owned(),
+                ty: #crate_rename::ast::TsType::Literal(
+                    #crate_rename::ast::Literal::String((#ts_name).to_string())
+                ),
+                optional: false,
+                readonly: cfg.readonly(),
+                docs: None,
+                style: #crate_rename::ast::PropertyStyle::Tag,
+            })
         });
     }
 
@@ -27,44 +40,59 @@ pub(crate) fn named(attr: &StructAttr, ts_name: Expr, fields: &FieldsNamed) -> R
         format_field(
             &crate_rename,
             &mut formatted_fields,
-            &mut flattened_fields,
+            &mut flattened,
             &mut dependencies,
             field,
-            &attr.rename_all,
-            attr.optional_fields,
+            attr,
         )?;
     }
 
-    let fields = quote!(<[String]>::join(&[#(#formatted_fields),*], " "));
-    let flattened = quote!(<[String]>::join(&[#(#flattened_fields),*], " & "));
-
-    let inline = match (formatted_fields.len(), flattened_fields.len()) {
-        (0, 0) => quote!("{  }".to_owned()),
-        (_, 0) => quote!(format!("{{ {} }}", #fields)),
-        (0, 1) => quote! {{
-            if #flattened.starts_with('(') && #flattened.ends_with(')') {
-                #flattened[1..#flattened.len() - 1].trim().to_owned()
-            } else {
-                #flattened.trim().to_owned()
-            }
-        }},
-        (0, _) => quote!(#flattened),
-        (_, _) => quote!(format!("{{ {} }} & {}", #fields, #flattened)),
+    // fields which are skipped in the current direction are formatted as `None`
+    let fields = quote! {
+        <Vec<Option<#crate_rename::ast::Property>>>::into_iter(vec![#(#formatted_fields),*])
+            .flatten()
+            .collect::<Vec<_>>()
     };
+    let Flattened {
+        fields: flattened_fields,
+        types: flattened_types,
+        dependencies: flattened_dependencies,
+    } = flattened;
+
+    // an interface extends the types flattened into it instead of inlining their fields
+    match flattened_types.as_slice() {
+        [] => dependencies.append(flattened_dependencies),
+        types => {
+            let is_interface = quote!(<Self as #crate_rename::TS>::is_interface(v.interfaces()));
+            let mut extended = Dependencies::new(crate_rename.clone());
+            for ty in types {
+                extended.push(ty);
+            }
+            dependencies.append_if(parse_quote!(!#is_interface), flattened_dependencies);
+            dependencies.append_if(parse_quote!(#is_interface), extended);
+        }
+    }
 
-    let inline_flattened = match (formatted_fields.len(), flattened_fields.len()) {
-        (0, 0) => quote!("{  }".to_owned()),
-        (_, 0) => quote!(format!("{{ {} }}", #fields)),
-        (0, _) => quote!(#flattened),
-        (_, _) => quote!(format!("{{ {} }} & {}", #fields, #flattened)),
+    // the fields are intersected with the flattened types. Merging them into one object is not
+    // necessary, but it results in simpler type definitions.
+    let inline = match (formatted_fields.len(), flattened_fields.len()) {
+        (_, 0) => quote!(#crate_rename::ast::TsType::Object(#fields)),
+        (0, _) => quote! {
+            #crate_rename::ast::TsType::Intersection(vec![#(#flattened_fields),*]).simplify()
+        },
+        (_, _) => quote! {
+            #crate_rename::ast::TsType::Intersection(vec![
+                #crate_rename::ast::TsType::Object(#fields),
+                #(#flattened_fields),*
+            ])
+            .simplify()
+        },
     };
 
     Ok(DerivedTS {
         crate_rename,
-        // the `replace` combines `{ ... } & { ... }` into just one `{ ... }`. Not necessary, but it
-        // results in simpler type definitions.
-        inline: quote!(#inline.replace(" } & { ", " ")),
-        inline_flattened: Some(quote!(#inline_flattened.replace(" } & { ", " "))),
+        inline: inline.clone(),
+        inline_flattened: Some(inline),
         docs: attr.docs.clone(),
         dependencies,
         export: attr.export,
@@ -74,29 +102,45 @@ pub(crate) fn named(attr: &StructAttr, ts_name: Expr, fields: &FieldsNamed) -> R
         bound: attr.bound.clone(),
         ts_enum: None,
         is_enum: quote!(false),
+        variant_values: None,
+        decl_kind: None,
+        interface: Some(Interface {
+            forced: attr.interface,
+            fields,
+            flattened: flattened_types,
+        }),
     })
 }
 
-// build an expression which expands to a string, representing a single field of a struct.
+// the flattened fields of a struct
+struct Flattened {
+    // expressions which expand to the inlined fields of the flattened types
+    fields: Vec<TokenStream>,
+    // the flattened types, which are extended if the struct is an interface
+    types: Vec<Type>,
+    // dependencies of the flattened types, which are only used if they are inlined
+    dependencies: Dependencies,
+}
+
+// build an expression which expands to an `Option<Property>`, representing a single field of a
+// struct, or `None` if the field is skipped in the current direction.
 //
 // formatted_fields will contain all the fields that do not contain the flatten
-// attribute, in the format
-// key: type,
+// attribute.
 //
-// flattened_fields will contain all the fields that contain the flatten attribute
-// in their respective formats, which for a named struct is the same as formatted_fields,
-// but for enums is
-// ({ /* variant data */ } | { /* variant data */ })
+// flattened.fields will contain all the fields that contain the flatten attribute as
+// expressions of type `TsType`, which for a named struct is an object, but for enums is
+// a union, e.g. `{ /* variant data */ } | { /* variant data */ }`
 fn format_field(
     crate_rename: &Path,
     formatted_fields: &mut Vec<TokenStream>,
-    flattened_fields: &mut Vec<TokenStream>,
+    flattened: &mut Flattened,
     dependencies: &mut Dependencies,
     field: &Field,
-    rename_all: &Option<Inflection>,
-    struct_optional: Optional,
+    attr: &StructAttr,
 ) -> Result<()> {
-    let field_attr = FieldAttr::from_attrs(&field.attrs)?;
+    let mut field_attr = FieldAttr::from_attrs(&field.attrs)?;
+    field_attr.has_default |= attr.has_default;
 
     field_attr.assert_validity(field)?;
 
@@ -108,53 +152,98 @@ fn format_field(
 
     let (is_optional, ty) = crate::optional::apply(
         crate_rename,
-        struct_optional,
+        attr.optional_fields,
         &ty,
         &field_attr,
         field.span(),
     );
-    let optional_annotation = quote!(if #is_optional { "?" } else { "" });
 
+    if field_attr.flatten {
+        if field_attr.type_override.is_none() {
+            flattened.dependencies.append_from(&ty);
+        }
+        flattened.types.push(ty.clone());
+        flattened
+            .fields
+            .push(quote!(<#ty as #crate_rename::TS>::inline_flattened_ast(cfg)));
+        return Ok(());
+    }
+
+    let mut field_dependencies = Dependencies::new(crate_rename.clone());
     if field_attr.type_override.is_none() {
-        if field_attr.inline || field_attr.flatten {
-            dependencies.append_from(&ty);
+        if field_attr.inline {
+            field_dependencies.append_from(&ty);
         } else {
-            dependencies.push(&ty);
+            field_dependencies.push(&ty);
         }
     }
 
-    if field_attr.flatten {
-        flattened_fields.push(quote!(<#ty as #crate_rename::TS>::inline_flattened(cfg)));
-        return Ok(());
+    let skipped_in = |direction| {
+        direction::skipped(
+            crate_rename,
+            direction,
+            field_attr.skip_serializing,
+            field_attr.skip_deserializing,
+        )
+    };
+    match skipped_in(quote!(v.direction())) {
+        Some(skipped) => dependencies.append_if(parse_quote!(!(#skipped)), field_dependencies),
+        None => dependencies.append(field_dependencies),
     }
 
+    // the types of readonly fields are rendered read-only, e.g. `ReadonlyArray<T>`
+    let readonly = field_attr.readonly || attr.readonly;
+    let field_cfg = match readonly {
+        true => quote!(&cfg.to_readonly()),
+        false => quote!(cfg),
+    };
     let formatted_ty = field_attr
         .type_override
-        .map(|t| quote!(#t))
+        .as_ref()
+        .map(|t| quote!(#crate_rename::ast::TsType::Raw(#t.to_owned())))
         .unwrap_or_else(|| {
             if field_attr.inline {
-                quote!(<#ty as #crate_rename::TS>::inline(cfg))
+                quote!(<#ty as #crate_rename::TS>::inline_ast(#field_cfg))
             } else {
-                quote!(<#ty as #crate_rename::TS>::name(cfg))
+                quote!(<#ty as #crate_rename::TS>::name_ast(#field_cfg))
             }
         });
 
     let field_name = to_ts_ident(field.ident.as_ref().unwrap());
-    let name = match (field_attr.rename, rename_all) {
-        (Some(rn), _) => rn,
-        (None, Some(rn)) => rn.apply(&field_name),
-        (None, None) => field_name,
-    };
-    let valid_name = raw_name_to_ts_field(name);
+    let (serialize, deserialize) = field_attr.names(match attr.rename_all {
+        Some(rn) => rn.apply(&field_name),
+        None => field_name,
+    });
+    let name = direction::name(
+        crate_rename,
+        make_string_literal(&serialize, field.span()),
+        make_string_literal(&deserialize, field.span()),
+    );
 
-    // Start every doc string with a newline, because when other characters are in front, it is not "understood" by VSCode
     let docs = match &*field_attr.docs {
-        &[] => quote!(""),
-        docs => quote!(format!("\n{}", #crate_rename::format_docs(&[#(#docs),*]))),
+        &[] => quote!(None),
+        docs => quote!(#crate_rename::docs_text(&[#(#docs),*])),
+    };
+
+    let readonly = match readonly {
+        true => quote!(true),
+        false => quote!(cfg.readonly()),
+    };
+
+    let formatted = quote! {
+        Some(#crate_rename::ast::Property {
+            name: (#name).to_owned(),
+            ty: #formatted_ty,
+            optional: #is_optional,
+            readonly: #readonly,
+            docs: #docs,
+            style: #crate_rename::ast::PropertyStyle::Field,
+        })
     };
 
-    formatted_fields.push(quote! {
-        format!("{}{}{}: {},", #docs, #valid_name, #optional_annotation, #formatted_ty)
+    formatted_fields.push(match skipped_in(quote!(cfg.direction())) {
+        Some(skipped) => quote!(if #skipped { None } else { #formatted }),
+        None => formatted,
     });
 
     Ok(())
@@ -1,5 +1,5 @@
 use quote::quote;
-use syn::{Expr, FieldsUnnamed, Result};
+use syn::{Expr, Field, Fields, FieldsUnnamed, Result};
 
 use crate::{
     attr::{Attr, ContainerAttr, FieldAttr, StructAttr},
@@ -17,6 +17,56 @@ pub(crate) fn newtype(
     let field_attr = FieldAttr::from_attrs(&inner.attrs)?;
     field_attr.assert_validity(inner)?;
 
+    newtype_of(attr, ts_name, inner, field_attr, true)
+}
+
+/// Bindings for a struct annotated with `#[ts(transparent)]` or `#[serde(transparent)]`.
+/// Just like a newtype, the struct is represented by its only field which is not skipped.
+pub(crate) fn transparent(attr: &StructAttr, ts_name: Expr, fields: &Fields) -> Result<DerivedTS> {
+    let mut inner = None;
+
+    for field in fields {
+        let field_attr = FieldAttr::from_attrs(&field.attrs)?;
+        field_attr.assert_validity(field)?;
+
+        if field_attr.skip {
+            continue;
+        }
+
+        if inner.is_some() {
+            syn_err_spanned!(
+                field;
+                "`transparent` structs must have exactly one field which is not skipped"
+            );
+        }
+
+        inner = Some((field, field_attr));
+    }
+
+    let Some((inner, field_attr)) = inner else {
+        syn_err!("`transparent` structs must have exactly one field which is not skipped");
+    };
+
+    // serde flattens a transparent struct by flattening its inner type
+    let inline_flattened = field_attr.type_override.is_none().then(|| {
+        let crate_rename = attr.crate_rename();
+        let inner_ty = field_attr.type_as(&inner.ty);
+        quote!(<#inner_ty as #crate_rename::TS>::inline_flattened_ast(cfg))
+    });
+
+    Ok(DerivedTS {
+        inline_flattened,
+        ..newtype_of(attr, ts_name, inner, field_attr, false)?
+    })
+}
+
+fn newtype_of(
+    attr: &StructAttr,
+    ts_name: Expr,
+    inner: &Field,
+    field_attr: FieldAttr,
+    newtype: bool,
+) -> Result<DerivedTS> {
     let crate_rename = attr.crate_rename();
 
     if field_attr.skip {
@@ -34,9 +84,37 @@ pub(crate) fn newtype(
     };
 
     let inline_def = match field_attr.type_override {
-        Some(ref o) => quote!(#o.to_owned()),
-        None if field_attr.inline => quote!(<#inner_ty as #crate_rename::TS>::inline(cfg)),
-        None => quote!(<#inner_ty as #crate_rename::TS>::name(cfg)),
+        Some(ref o) => quote!(#crate_rename::ast::TsType::Raw(#o.to_owned())),
+        None if field_attr.inline => quote!(<#inner_ty as #crate_rename::TS>::inline_ast(cfg)),
+        None => quote!(<#inner_ty as #crate_rename::TS>::name_ast(cfg)),
+    };
+
+    // branded types are intersected with `{ readonly __brand: "Name" }`. Newtypes are branded if
+    // `Config::with_branded_newtypes` is enabled, `transparent` structs only if requested.
+    let brand = quote! {
+        #crate_rename::ast::TsType::Intersection(vec![
+            #inline_def,
+            #crate_rename::ast::TsType::Object(vec![#crate_rename::ast::Property {
+                name: "__brand".to_owned(),
+                ty: #crate_rename::ast::TsType::Literal(
+                    #crate_rename::ast::Literal::String((#ts_name).to_string())
+                ),
+                optional: false,
+                readonly: true,
+                docs: None,
+                style: #crate_rename::ast::PropertyStyle::Entry,
+            }]),
+        ])
+    };
+    let inline_def = match (attr.brand, newtype) {
+        (true, _) => brand,
+        (false, true) => quote! {
+            match cfg.branded_newtypes() {
+                true => #brand,
+                false => #inline_def,
+            }
+        },
+        (false, false) => inline_def,
     };
 
     Ok(DerivedTS {
@@ -56,5 +134,8 @@ pub(crate) fn newtype(
         } else {
             quote!(false)
         },
+        interface: None,
+        variant_values: None,
+        decl_kind: None,
     })
 }
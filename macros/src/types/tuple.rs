@@ -1,10 +1,11 @@
 use proc_macro2::TokenStream;
 use quote::quote;
-use syn::{spanned::Spanned, Expr, Field, FieldsUnnamed, Path, Result};
+use syn::{parse_quote, spanned::Spanned, Expr, Field, FieldsUnnamed, Path, Result};
 
 use crate::{
     attr::{Attr, ContainerAttr, FieldAttr, StructAttr},
     deps::Dependencies,
+    direction,
     optional::Optional,
     DerivedTS,
 };
@@ -23,14 +24,24 @@ pub(crate) fn tuple(attr: &StructAttr, ts_name: Expr, fields: &FieldsUnnamed) ->
         )?;
     }
 
+    let readonly = match attr.readonly {
+        true => quote!(true),
+        false => quote!(cfg.readonly()),
+    };
+
     Ok(DerivedTS {
-        crate_rename,
-        inline: quote! {
-            format!(
-                "[{}]",
-                [#(#formatted_fields),*].join(", ")
-            )
-        },
+        crate_rename: crate_rename.clone(),
+        inline: quote! {{
+            let elements =
+                <Vec<Option<#crate_rename::ast::Element>>>::into_iter(vec![#(#formatted_fields),*])
+                    .flatten()
+                    .collect();
+            let tuple = #crate_rename::ast::TsType::Tuple(elements);
+            match #readonly {
+                true => #crate_rename::ast::TsType::Readonly(Box::new(tuple)),
+                false => tuple,
+            }
+        }},
         inline_flattened: None,
         docs: attr.docs.clone(),
         dependencies,
@@ -41,6 +52,9 @@ pub(crate) fn tuple(attr: &StructAttr, ts_name: Expr, fields: &FieldsUnnamed) ->
         bound: attr.bound.clone(),
         ts_enum: None,
         is_enum: quote!(false),
+        interface: None,
+        variant_values: None,
+        decl_kind: None,
     })
 }
 
@@ -67,31 +81,50 @@ fn format_field(
         field.span(),
     );
 
+    let mut field_dependencies = Dependencies::new(crate_rename.clone());
     if field_attr.type_override.is_none() {
         if field_attr.inline {
-            dependencies.append_from(&ty);
+            field_dependencies.append_from(&ty);
         } else {
-            dependencies.push(&ty);
+            field_dependencies.push(&ty);
         }
     }
 
+    let skipped_in = |direction| {
+        direction::skipped(
+            crate_rename,
+            direction,
+            field_attr.skip_serializing,
+            field_attr.skip_deserializing,
+        )
+    };
+    match skipped_in(quote!(v.direction())) {
+        Some(skipped) => dependencies.append_if(parse_quote!(!(#skipped)), field_dependencies),
+        None => dependencies.append(field_dependencies),
+    }
+
     let formatted_ty = field_attr
         .type_override
-        .map(|t| quote!(#t.to_owned()))
+        .as_ref()
+        .map(|t| quote!(#crate_rename::ast::TsType::Raw(#t.to_owned())))
         .unwrap_or_else(|| {
             if field_attr.inline {
-                quote!(<#ty as #crate_rename::TS>::inline(cfg))
+                quote!(<#ty as #crate_rename::TS>::inline_ast(cfg))
             } else {
-                quote!(<#ty as #crate_rename::TS>::name(cfg))
+                quote!(<#ty as #crate_rename::TS>::name_ast(cfg))
             }
         });
 
-    formatted_fields.push(quote! {
-        if #is_optional {
-            format!("({})?", #formatted_ty)
-        } else {
-            #formatted_ty
-        }
+    let formatted = quote! {
+        Some(#crate_rename::ast::Element {
+            ty: #formatted_ty,
+            optional: #is_optional,
+        })
+    };
+
+    formatted_fields.push(match skipped_in(quote!(cfg.direction())) {
+        Some(skipped) => quote!(if #skipped { None } else { #formatted }),
+        None => formatted,
     });
 
     Ok(())
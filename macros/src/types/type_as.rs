@@ -1,5 +1,5 @@
 use quote::quote;
-use syn::{Expr, Result, Type};
+use syn::{parse_quote, Expr, Result, Type};
 
 use crate::{
     attr::{ContainerAttr, EnumAttr, StructAttr},
@@ -19,7 +19,7 @@ pub(crate) fn type_as_struct(
 
     Ok(DerivedTS {
         crate_rename: crate_rename.clone(),
-        inline: quote!(<#type_as as #crate_rename::TS>::inline(cfg)),
+        inline: quote!(<#type_as as #crate_rename::TS>::inline_ast(cfg)),
         inline_flattened: None,
         docs: attr.docs.clone(),
         dependencies,
@@ -30,6 +30,9 @@ pub(crate) fn type_as_struct(
         bound: attr.bound.clone(),
         ts_enum: None,
         is_enum: quote!(<#type_as as #crate_rename::TS>::IS_ENUM),
+        interface: None,
+        variant_values: None,
+        decl_kind: None,
     })
 }
 
@@ -41,7 +44,7 @@ pub(crate) fn type_as_enum(attr: &EnumAttr, ts_name: Expr, type_as: &Type) -> Re
 
     Ok(DerivedTS {
         crate_rename: crate_rename.clone(),
-        inline: quote!(<#type_as as #crate_rename::TS>::inline(cfg)),
+        inline: quote!(<#type_as as #crate_rename::TS>::inline_ast(cfg)),
         inline_flattened: None,
         docs: attr.docs.clone(),
         dependencies,
@@ -52,5 +55,96 @@ pub(crate) fn type_as_enum(attr: &EnumAttr, ts_name: Expr, type_as: &Type) -> Re
         bound: attr.bound.clone(),
         ts_enum: None,
         is_enum: quote!(<#type_as as #crate_rename::TS>::IS_ENUM),
+        interface: None,
+        variant_values: None,
+        decl_kind: None,
     })
 }
+
+/// Uses `type_into` for the bindings of a type annotated with `#[serde(into = "..")]`, unless
+/// they are generated for `Direction::Deserialize`. Then, the bindings in `derived` are used.
+pub(crate) fn serialize_as(derived: DerivedTS, type_into: &Type) -> DerivedTS {
+    let crate_rename = derived.crate_rename.clone();
+    let deserialize = quote!(#crate_rename::Direction::Deserialize);
+
+    let mut into_dependencies = Dependencies::new(crate_rename.clone());
+    into_dependencies.append_from(type_into);
+
+    let mut dependencies = Dependencies::new(crate_rename.clone());
+    dependencies.append_if(parse_quote!(v.direction() != #deserialize), into_dependencies);
+    dependencies.append_if(
+        parse_quote!(v.direction() == #deserialize),
+        derived.dependencies,
+    );
+
+    let inline = derived.inline;
+    let inline_flattened = derived.inline_flattened.map(|inline_flattened| {
+        quote! {
+            match cfg.direction() {
+                #deserialize => #inline_flattened,
+                _ => <#type_into as #crate_rename::TS>::inline_flattened_ast(cfg),
+            }
+        }
+    });
+
+    DerivedTS {
+        inline: quote! {
+            match cfg.direction() {
+                #deserialize => #inline,
+                _ => <#type_into as #crate_rename::TS>::inline_ast(cfg),
+            }
+        },
+        inline_flattened,
+        dependencies,
+        is_enum: quote!(<#type_into as #crate_rename::TS>::IS_ENUM),
+        // the fields of the type are only used when deserializing
+        interface: None,
+        variant_values: None,
+        decl_kind: None,
+        ..derived
+    }
+}
+
+/// Uses `type_from` for the bindings of a type annotated with `#[serde(from = "..")]` or
+/// `#[serde(try_from = "..")]` when they are generated for `Direction::Deserialize`.
+/// In every other direction, the bindings in `derived` are used.
+pub(crate) fn deserialize_as(derived: DerivedTS, type_from: &Type) -> DerivedTS {
+    let crate_rename = derived.crate_rename.clone();
+    let deserialize = quote!(#crate_rename::Direction::Deserialize);
+
+    let mut from_dependencies = Dependencies::new(crate_rename.clone());
+    from_dependencies.append_from(type_from);
+
+    let mut dependencies = Dependencies::new(crate_rename.clone());
+    dependencies.append_if(
+        parse_quote!(v.direction() != #deserialize),
+        derived.dependencies,
+    );
+    dependencies.append_if(parse_quote!(v.direction() == #deserialize), from_dependencies);
+
+    let inline = derived.inline;
+    let inline_flattened = derived.inline_flattened.map(|inline_flattened| {
+        quote! {
+            match cfg.direction() {
+                #deserialize => <#type_from as #crate_rename::TS>::inline_flattened_ast(cfg),
+                _ => #inline_flattened,
+            }
+        }
+    });
+
+    DerivedTS {
+        inline: quote! {
+            match cfg.direction() {
+                #deserialize => <#type_from as #crate_rename::TS>::inline_ast(cfg),
+                _ => #inline,
+            }
+        },
+        inline_flattened,
+        dependencies,
+        // the fields of the struct are not used when deserializing
+        interface: None,
+        variant_values: None,
+        decl_kind: None,
+        ..derived
+    }
+}
@@ -16,7 +16,7 @@ pub(crate) fn type_override_struct(
 
     Ok(DerivedTS {
         crate_rename: crate_rename.clone(),
-        inline: quote!(#type_override.to_owned()),
+        inline: quote!(#crate_rename::ast::TsType::Raw(#type_override.to_owned())),
         inline_flattened: None,
         docs: attr.docs.clone(),
         dependencies: Dependencies::new(crate_rename),
@@ -27,6 +27,9 @@ pub(crate) fn type_override_struct(
         bound: attr.bound.clone(),
         ts_enum: None,
         is_enum: quote!(false), // we dont know what the override is, so we preserve is_enum
+        interface: None,
+        variant_values: None,
+        decl_kind: None,
     })
 }
 
@@ -39,7 +42,7 @@ pub(crate) fn type_override_enum(
 
     Ok(DerivedTS {
         crate_rename: crate_rename.clone(),
-        inline: quote!(#type_override.to_owned()),
+        inline: quote!(#crate_rename::ast::TsType::Raw(#type_override.to_owned())),
         inline_flattened: None,
         docs: attr.docs.clone(),
         dependencies: Dependencies::new(crate_rename),
@@ -50,5 +53,8 @@ pub(crate) fn type_override_enum(
         bound: attr.bound.clone(),
         ts_enum: None,
         is_enum: quote!(true), // we dont know what the override is, so we preserve is_enum
+        interface: None,
+        variant_values: None,
+        decl_kind: None,
     })
 }
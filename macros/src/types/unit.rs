@@ -12,7 +12,15 @@ pub(crate) fn empty_object(attr: &StructAttr, ts_name: Expr) -> DerivedTS {
 
     DerivedTS {
         crate_rename: crate_rename.clone(),
-        inline: quote!("Record<symbol, never>".to_owned()),
+        inline: quote! {
+            #crate_rename::ast::TsType::Reference {
+                name: "Record".to_owned(),
+                generics: vec![
+                    #crate_rename::ast::TsType::Keyword(#crate_rename::ast::Keyword::Symbol),
+                    #crate_rename::ast::TsType::Keyword(#crate_rename::ast::Keyword::Never),
+                ],
+            }
+        },
         inline_flattened: None,
         docs: attr.docs.clone(),
         dependencies: Dependencies::new(crate_rename),
@@ -23,6 +31,9 @@ pub(crate) fn empty_object(attr: &StructAttr, ts_name: Expr) -> DerivedTS {
         bound: attr.bound.clone(),
         ts_enum: None,
         is_enum: quote!(false),
+        interface: None,
+        variant_values: None,
+        decl_kind: None,
     }
 }
 
@@ -31,7 +42,11 @@ pub(crate) fn empty_array(attr: &StructAttr, ts_name: Expr) -> DerivedTS {
 
     DerivedTS {
         crate_rename: crate_rename.clone(),
-        inline: quote!("never[]".to_owned()),
+        inline: quote! {
+            #crate_rename::ast::TsType::Array(Box::new(
+                #crate_rename::ast::TsType::Keyword(#crate_rename::ast::Keyword::Never)
+            ))
+        },
         inline_flattened: None,
         docs: attr.docs.clone(),
         dependencies: Dependencies::new(crate_rename),
@@ -42,6 +57,9 @@ pub(crate) fn empty_array(attr: &StructAttr, ts_name: Expr) -> DerivedTS {
         bound: attr.bound.clone(),
         ts_enum: None,
         is_enum: quote!(false),
+        interface: None,
+        variant_values: None,
+        decl_kind: None,
     }
 }
 
@@ -50,7 +68,7 @@ pub(crate) fn null(attr: &StructAttr, ts_name: Expr) -> DerivedTS {
 
     DerivedTS {
         crate_rename: crate_rename.clone(),
-        inline: quote!("null".to_owned()),
+        inline: quote!(#crate_rename::ast::TsType::Keyword(#crate_rename::ast::Keyword::Null)),
         inline_flattened: None,
         docs: attr.docs.clone(),
         dependencies: Dependencies::new(crate_rename),
@@ -61,5 +79,8 @@ pub(crate) fn null(attr: &StructAttr, ts_name: Expr) -> DerivedTS {
         bound: attr.bound.clone(),
         ts_enum: None,
         is_enum: quote!(false),
+        interface: None,
+        variant_values: None,
+        decl_kind: None,
     }
 }
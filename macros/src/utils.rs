@@ -122,10 +122,10 @@ pub fn raw_name_to_ts_field(value: String) -> String {
         .chars()
         .all(|c| c.is_alphanumeric() || c == '_' || c == '$');
 
-    let does_not_start_with_digit = value
+    let does_not_start_with_digit = !value
         .chars()
         .next()
-        .map_or(true, |first| !first.is_numeric());
+        .is_some_and(|first| first.is_numeric());
 
     let valid = valid_chars && does_not_start_with_digit;
 
@@ -241,9 +241,10 @@ pub(crate) mod warning {
     }
 }
 
-/// formats the generic arguments (like A, B in struct X<A, B>{..}) as "<X>" where x is a comma
-/// seperated list of generic arguments, or an empty string if there are no type generics (lifetime/const generics are ignored).
-/// this expands to an expression which evaluates to a `String`.
+/// formats the generic arguments (like A, B in struct X<A, B>{..}) as a list of
+/// `ast::Generic`s, which is empty if there are no type generics (lifetime/const generics are
+/// ignored).
+/// this expands to an expression which evaluates to a `Vec<Generic>`.
 ///
 /// If a default type arg is encountered, it will be added to the dependencies.
 pub fn format_generics(
@@ -252,34 +253,30 @@ pub fn format_generics(
     generics: &Generics,
     concrete: &HashMap<Ident, Type>,
 ) -> TokenStream {
-    let mut expanded_params = generics
-        .params
-        .iter()
-        .filter_map(|param| match param {
-            GenericParam::Type(type_param) => {
-                if concrete.contains_key(&type_param.ident) {
-                    return None;
-                }
-                let ty = type_param.ident.to_string();
-                if let Some(default) = &type_param.default {
+    let expanded_params = generics.params.iter().filter_map(|param| match param {
+        GenericParam::Type(type_param) => {
+            if concrete.contains_key(&type_param.ident) {
+                return None;
+            }
+            let ty = type_param.ident.to_string();
+            let default = match &type_param.default {
+                Some(default) => {
                     deps.push(default);
-                    Some(quote!(
-                        format!("{} = {}", #ty, <#default as #crate_rename::TS>::name(cfg))
-                    ))
-                } else {
-                    Some(quote!(#ty.to_owned()))
+                    quote!(Some(<#default as #crate_rename::TS>::name_ast(cfg)))
                 }
-            }
-            _ => None,
-        })
-        .peekable();
-
-    if expanded_params.peek().is_none() {
-        return quote!("");
-    }
+                None => quote!(None),
+            };
+            Some(quote! {
+                #crate_rename::ast::Generic {
+                    name: #ty.to_owned(),
+                    default: #default,
+                }
+            })
+        }
+        _ => None,
+    });
 
-    let comma_separated = quote!([#(#expanded_params),*].join(", "));
-    quote!(format!("<{}>", #comma_separated))
+    quote!(vec![#(#expanded_params),*])
 }
 
 pub fn make_string_literal(content: &str, span: Span) -> Expr {
@@ -0,0 +1,1194 @@
+//! A structured model of the TypeScript types generated by ts-rs.
+//!
+//! The model of a type is returned by [`TS::name_ast`], [`TS::inline_ast`] and [`TS::decl_ast`],
+//! and is printed as TypeScript using its [`Display`] implementation. Types deriving `TS` and the
+//! implementations provided by ts-rs build the model directly, and [`TS::name`], [`TS::inline`]
+//! and [`TS::decl`] print it. Other printers, like the ones generating type guards or Zod schemas,
+//! walk the same model.
+//!
+//! TypeScript given by the user, e.g. using `#[ts(type = "..")]`, is kept as [`TsType::Raw`].
+//! For implementations of `TS` which only provide the strings, the model is parsed from them.
+//! Since the parser only needs to understand what ts-rs itself generates, it is intentionally
+//! simple, and anything it does not understand is kept as [`TsType::Raw`] as well.
+//! ```
+//! # use ts_rs::{ast::{Keyword, Property, TsType}, Config, TS};
+//! #[derive(TS)]
+//! struct User {
+//!     user_id: i32,
+//! }
+//!
+//! let TsType::Object(properties) = User::inline_ast(&Config::new()) else {
+//!     unreachable!()
+//! };
+//! assert_eq!(properties[0].name, "user_id");
+//! assert_eq!(properties[0].ty, TsType::Keyword(Keyword::Number));
+//! ```
+
+use std::fmt::{self, Display, Formatter};
+
+use crate::{Config, TS};
+
+/// A TypeScript type
+#[derive(Clone, Debug, PartialEq)]
+pub enum TsType {
+    /// A keyword type, like `string`, `number` or `null`
+    Keyword(Keyword),
+    /// A literal type, like `"Red"`, `42` or `true`
+    Literal(Literal),
+    /// A type declared elsewhere or a generic parameter, like `User` or `Page<T>`
+    Reference { name: String, generics: Vec<TsType> },
+    /// `Array<T>`
+    Array(Box<TsType>),
+    /// `[A, B, (C)?]`
+    Tuple(Vec<Element>),
+    /// `ReadonlyArray<T>` or `readonly [A, B]`
+    Readonly(Box<TsType>),
+    /// `{ a: A, b?: B, }`
+    Object(Vec<Property>),
+    /// `{ [key in K]: V }`, `{ readonly [key in K]?: V }` or `Record<K, V>`
+    Record {
+        key: Box<TsType>,
+        value: Box<TsType>,
+        optional: bool,
+        readonly: bool,
+    },
+    /// `A | B`
+    Union(Vec<TsType>),
+    /// `A & B`
+    Intersection(Vec<TsType>),
+    /// `(a: A, b: B) => R`. As the type of a property, it is written as a method, e.g.
+    /// `{ getUser(id: UserId): Promise<User>, }`.
+    Function {
+        params: Vec<Param>,
+        returns: Box<TsType>,
+    },
+    /// TypeScript which is not interpreted, e.g. given by `#[ts(type = "..")]`
+    Raw(String),
+}
+
+/// A keyword type, like `string`
+#[derive(Copy, Clone, Debug, PartialEq, Eq)]
+pub enum Keyword {
+    String,
+    Number,
+    Bigint,
+    Boolean,
+    Null,
+    Undefined,
+    Void,
+    Unknown,
+    Any,
+    Never,
+    Object,
+    Symbol,
+}
+
+/// A literal type, like `"Red"`, `42` or `true`
+#[derive(Clone, Debug, PartialEq)]
+pub enum Literal {
+    String(String),
+    /// A number, as it is written in TypeScript
+    Number(String),
+    Boolean(bool),
+}
+
+/// An element of a [`TsType::Tuple`]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Element {
+    pub ty: TsType,
+    pub optional: bool,
+}
+
+/// A property of a [`TsType::Object`]
+#[derive(Clone, Debug, PartialEq)]
+pub struct Property {
+    pub name: String,
+    pub ty: TsType,
+    pub optional: bool,
+    pub readonly: bool,
+    /// The lines of the doc comment as written in Rust, e.g. `" The name of the user"` for
+    /// `/// The name of the user`
+    pub docs: Option<String>,
+    pub style: PropertyStyle,
+}
+
+/// How a [`Property`] is written. ts-rs writes the properties of structs, enums and some
+/// built-in types differently.
+#[derive(Copy, Clone, Debug, Default, PartialEq, Eq)]
+pub enum PropertyStyle {
+    /// A field of a struct, e.g. `name: string,`. The name is only quoted if necessary, and the
+    /// property is followed by a comma even if it is the last one.
+    #[default]
+    Field,
+    /// The tag of a struct, e.g. `"type": "User",`. Like [`PropertyStyle::Field`], but the name
+    /// is always quoted.
+    Tag,
+    /// An enum variant or the tag of a unit variant, e.g. `"Circle": Circle`. The name is always
+    /// quoted, and the property is only followed by a comma if another one follows.
+    Variant,
+    /// Like [`PropertyStyle::Field`], but only followed by a comma if another one follows, e.g.
+    /// `readonly __brand: "UserId"`
+    Entry,
+    /// Like [`PropertyStyle::Entry`], with a space before the colon, e.g. `Ok : T`
+    Spaced,
+}
+
+impl Element {
+    /// A required element of type `ty`
+    pub fn new(ty: TsType) -> Self {
+        Self {
+            ty,
+            optional: false,
+        }
+    }
+}
+
+impl Property {
+    /// A required field without docs, e.g. `name: ty,`
+    pub fn new(name: impl Into<String>, ty: TsType) -> Self {
+        Self {
+            name: name.into(),
+            ty,
+            optional: false,
+            readonly: false,
+            docs: None,
+            style: PropertyStyle::Field,
+        }
+    }
+
+    /// A required property written in `style`, e.g. `"Circle": Circle` for
+    /// [`PropertyStyle::Variant`]
+    pub fn with_style(name: impl Into<String>, ty: TsType, style: PropertyStyle) -> Self {
+        Self {
+            style,
+            ..Self::new(name, ty)
+        }
+    }
+
+    /// Whether the property is followed by a comma if it is the last one of an object
+    pub(crate) fn terminated(&self) -> bool {
+        matches!(self.style, PropertyStyle::Field | PropertyStyle::Tag)
+    }
+
+    /// The name of the property, quoted if necessary, e.g. `id` or `"user-id"`
+    pub(crate) fn key(&self) -> String {
+        match self.style {
+            PropertyStyle::Tag | PropertyStyle::Variant => {
+                Literal::String(self.name.clone()).to_string()
+            }
+            _ if is_identifier(&self.name) => self.name.clone(),
+            _ => Literal::String(self.name.clone()).to_string(),
+        }
+    }
+
+    /// The separator between the name of the property and its type
+    pub(crate) fn colon(&self) -> &'static str {
+        match self.style {
+            PropertyStyle::Spaced => " : ",
+            _ => ": ",
+        }
+    }
+}
+
+/// A parameter of a [`TsType::Function`], e.g. `id: UserId`
+#[derive(Clone, Debug, PartialEq)]
+pub struct Param {
+    pub name: String,
+    pub ty: TsType,
+}
+
+/// A generic parameter of a [`Declaration`], e.g. `T` or `T = number`
+#[derive(Clone, Debug, PartialEq)]
+pub struct Generic {
+    pub name: String,
+    pub default: Option<TsType>,
+}
+
+/// The declaration of a type, e.g. `type Page<T> = { items: Array<T>, };`
+#[derive(Clone, Debug, PartialEq)]
+pub struct Declaration {
+    pub name: String,
+    pub generics: Vec<Generic>,
+    pub ty: TsType,
+    /// The lines of the doc comment as written in Rust, see [`Property::docs`]
+    pub docs: Option<String>,
+    pub kind: DeclarationKind,
+}
+
+/// How a [`Declaration`] is written
+#[derive(Clone, Debug, Default, PartialEq)]
+pub enum DeclarationKind {
+    /// A type alias, e.g. `type User = { id: number, };`
+    #[default]
+    Type,
+    /// An interface, e.g. `interface User extends Base { id: number, }`. Its type is the object
+    /// of its properties, intersected with the types it extends.
+    Interface,
+    /// A TypeScript enum, e.g. `enum Role { "Admin" = 0, "Guest" = 1 }`. Its type is the union
+    /// of the values of its members.
+    Enum(Vec<EnumMember>),
+    /// A function, e.g. `declare function ping(): Promise<void>;`. Its type is a
+    /// [`TsType::Function`].
+    Function,
+}
+
+/// A member of a TypeScript enum, e.g. `"Admin" = 0`. Without a value, the member is numbered
+/// following the previous one.
+#[derive(Clone, Debug, PartialEq)]
+pub struct EnumMember {
+    pub name: String,
+    pub value: Option<Literal>,
+}
+
+impl Declaration {
+    /// Parses the declaration of `T`, which is used if the implementation of `TS` does not
+    /// provide [`TS::decl_ast`]. This function will panic if `T` cannot be declared.
+    pub(crate) fn of<T: TS + ?Sized>(cfg: &Config) -> Self {
+        let docs = T::docs().map(|docs| {
+            let docs = docs.trim().trim_start_matches("/**");
+            parse_docs(docs.trim_end_matches("*/"))
+        });
+
+        Self {
+            docs,
+            ..Self::parse::<T>(cfg)
+        }
+    }
+
+    fn parse<T: TS + ?Sized>(cfg: &Config) -> Self {
+        let decl = T::decl(cfg);
+        let decl = decl.trim();
+
+        let mut parser = Parser::new(decl);
+        let parsed = if parser.eat("enum ") {
+            // the values of a TypeScript enum are given by `inline`
+            parser.enumeration(TsType::parse(&T::inline(cfg)))
+        } else if parser.eat("interface ") {
+            parser.interface()
+        } else {
+            parser.eat("type ");
+            parser.declaration()
+        };
+        let parsed = parsed.filter(|_| parser.is_done());
+
+        parsed.unwrap_or_else(|| Self {
+            name: T::ident(cfg),
+            generics: vec![],
+            ty: TsType::parse(&T::inline(cfg)),
+            docs: None,
+            kind: DeclarationKind::Type,
+        })
+    }
+}
+
+/// The declarations of a type and of all types it references, by their name
+#[cfg(any(feature = "values", feature = "testing"))]
+pub(crate) struct Declarations<'a> {
+    cfg: &'a Config,
+    seen: std::collections::HashSet<std::any::TypeId>,
+    types: std::collections::HashMap<String, Declaration>,
+}
+
+#[cfg(any(feature = "values", feature = "testing"))]
+impl<'a> Declarations<'a> {
+    /// Collects the declarations of `T`, its dependencies and the types it is generic over.
+    pub fn of<T: TS + ?Sized + 'static>(cfg: &'a Config) -> Self {
+        let mut declarations = Self {
+            cfg,
+            seen: Default::default(),
+            types: Default::default(),
+        };
+        crate::TypeVisitor::visit::<T>(&mut declarations);
+        T::visit_generics(&mut declarations);
+        declarations
+    }
+
+    /// Replaces a reference to a declared type with the type it refers to
+    pub fn resolve(&self, ty: &TsType) -> TsType {
+        match ty {
+            TsType::Reference { name, generics } => match self.types.get(name) {
+                Some(decl) => {
+                    let args = decl.generics.iter().enumerate().filter_map(|(i, generic)| {
+                        let arg = generics.get(i).or(generic.default.as_ref())?;
+                        Some((generic.name.as_str(), arg))
+                    });
+                    self.resolve(&decl.ty.substitute(&args.collect()))
+                }
+                None => ty.clone(),
+            },
+            TsType::Readonly(ty) => self.resolve(ty),
+            ty => ty.clone(),
+        }
+    }
+}
+
+#[cfg(any(feature = "values", feature = "testing"))]
+impl crate::TypeVisitor for Declarations<'_> {
+    fn visit<T: TS + 'static + ?Sized>(&mut self) {
+        if T::output_path().is_none() || !self.seen.insert(std::any::TypeId::of::<T>()) {
+            return;
+        }
+
+        let decl = T::decl_ast(self.cfg);
+        self.types.insert(decl.name.clone(), decl);
+        T::visit_dependencies(self);
+    }
+
+    fn direction(&self) -> crate::Direction {
+        self.cfg.direction()
+    }
+
+    fn interfaces(&self) -> bool {
+        self.cfg.interfaces()
+    }
+}
+
+impl TsType {
+    /// A reference to a type without generic arguments, e.g. `User`
+    pub fn reference(name: impl Into<String>) -> Self {
+        Self::Reference {
+            name: name.into(),
+            generics: vec![],
+        }
+    }
+
+    /// The union of `variants`, which is `never` if there are none
+    pub fn union(mut variants: Vec<TsType>) -> Self {
+        match variants.len() {
+            0 => Self::Keyword(Keyword::Never),
+            1 => variants.remove(0),
+            _ => Self::Union(variants),
+        }
+    }
+
+    /// Replaces references to generic parameters with the given arguments
+    pub fn substitute(&self, args: &std::collections::HashMap<&str, &TsType>) -> TsType {
+        let substitute = |ty: &TsType| ty.substitute(args);
+        match self {
+            Self::Reference { name, generics } if generics.is_empty() => {
+                args.get(name.as_str()).map_or_else(|| self.clone(), |&arg| arg.clone())
+            }
+            Self::Reference { name, generics } => Self::Reference {
+                name: name.clone(),
+                generics: generics.iter().map(substitute).collect(),
+            },
+            Self::Array(ty) => Self::Array(Box::new(substitute(ty))),
+            Self::Readonly(ty) => Self::Readonly(Box::new(substitute(ty))),
+            Self::Tuple(elements) => Self::Tuple(
+                elements
+                    .iter()
+                    .map(|element| Element {
+                        ty: substitute(&element.ty),
+                        optional: element.optional,
+                    })
+                    .collect(),
+            ),
+            Self::Object(properties) => Self::Object(
+                properties
+                    .iter()
+                    .map(|property| Property {
+                        ty: substitute(&property.ty),
+                        ..property.clone()
+                    })
+                    .collect(),
+            ),
+            Self::Record {
+                key,
+                value,
+                optional,
+                readonly,
+            } => Self::Record {
+                key: Box::new(substitute(key)),
+                value: Box::new(substitute(value)),
+                optional: *optional,
+                readonly: *readonly,
+            },
+            Self::Union(variants) => Self::Union(variants.iter().map(substitute).collect()),
+            Self::Intersection(members) => {
+                Self::Intersection(members.iter().map(substitute).collect())
+            }
+            Self::Function { params, returns } => Self::Function {
+                params: params
+                    .iter()
+                    .map(|param| Param {
+                        name: param.name.clone(),
+                        ty: substitute(&param.ty),
+                    })
+                    .collect(),
+                returns: Box::new(substitute(returns)),
+            },
+            Self::Keyword(_) | Self::Literal(_) | Self::Raw(_) => self.clone(),
+        }
+    }
+
+    /// Returns the name of the brand if this is the object `{ readonly __brand: "Name" }`, which
+    /// is intersected with the type of a branded newtype, see `#[ts(brand)]`.
+    pub(crate) fn brand(&self) -> Option<&str> {
+        match self {
+            Self::Object(properties) => brand(properties),
+            _ => None,
+        }
+    }
+
+    /// Simplifies intersections, flattening nested ones and merging adjacent objects into one,
+    /// e.g. `{ a: A, } & { b: B, }` into `{ a: A, b: B, }`.
+    /// Brands of branded newtypes are kept as they are.
+    pub fn simplify(self) -> TsType {
+        match self {
+            Self::Intersection(members) => {
+                let mut simplified: Vec<TsType> = vec![];
+                for member in members.into_iter().map(Self::simplify) {
+                    let nested = match member {
+                        Self::Intersection(nested) => nested,
+                        member => vec![member],
+                    };
+                    for member in nested {
+                        match (simplified.last_mut(), member) {
+                            (Some(Self::Object(properties)), Self::Object(more))
+                                if brand(properties).is_none() && brand(&more).is_none() =>
+                            {
+                                properties.extend(more)
+                            }
+                            (_, member) => simplified.push(member),
+                        }
+                    }
+                }
+
+                match simplified.len() {
+                    1 => simplified.remove(0),
+                    _ => Self::Intersection(simplified),
+                }
+            }
+            Self::Union(variants) => {
+                Self::Union(variants.into_iter().map(Self::simplify).collect())
+            }
+            Self::Array(ty) => Self::Array(Box::new(ty.simplify())),
+            Self::Readonly(ty) => Self::Readonly(Box::new(ty.simplify())),
+            Self::Tuple(elements) => Self::Tuple(
+                elements
+                    .into_iter()
+                    .map(|element| Element {
+                        ty: element.ty.simplify(),
+                        optional: element.optional,
+                    })
+                    .collect(),
+            ),
+            Self::Object(properties) => Self::Object(
+                properties
+                    .into_iter()
+                    .map(|property| Property {
+                        ty: property.ty.simplify(),
+                        ..property
+                    })
+                    .collect(),
+            ),
+            Self::Record {
+                key,
+                value,
+                optional,
+                readonly,
+            } => Self::Record {
+                key: Box::new(key.simplify()),
+                value: Box::new(value.simplify()),
+                optional,
+                readonly,
+            },
+            Self::Reference { name, generics } => Self::Reference {
+                name,
+                generics: generics.into_iter().map(Self::simplify).collect(),
+            },
+            Self::Function { params, returns } => Self::Function {
+                params: params
+                    .into_iter()
+                    .map(|param| Param {
+                        ty: param.ty.simplify(),
+                        ..param
+                    })
+                    .collect(),
+                returns: Box::new(returns.simplify()),
+            },
+            ty @ (Self::Keyword(_) | Self::Literal(_) | Self::Raw(_)) => ty,
+        }
+    }
+
+    /// Parses a TypeScript type, falling back to [`TsType::Raw`] if it cannot be understood.
+    /// This is used if the implementation of `TS` does not provide the model of a type.
+    pub(crate) fn parse(ts: &str) -> Self {
+        let mut parser = Parser::new(ts);
+        match parser.ty() {
+            Some(ty) if parser.is_done() => ty,
+            _ => Self::Raw(ts.trim().to_owned()),
+        }
+    }
+
+    /// Whether the type has to be enclosed in parentheses when it is a member of a union, or of
+    /// an intersection if `intersection` is set, e.g. `(string | null) & { .. }`.
+    /// TypeScript given by the user is parsed to find out. Within intersections, it is enclosed
+    /// if it cannot be understood.
+    pub(crate) fn binds_looser(&self, intersection: bool) -> bool {
+        match self {
+            Self::Function { .. } => true,
+            Self::Union(_) => intersection,
+            // e.g. the flattened map of `{ a: number, } & ({ [key in string]: number })`
+            Self::Record { .. } => intersection,
+            Self::Raw(ts) => match Self::parse(ts) {
+                Self::Raw(_) => intersection,
+                ty => ty.binds_looser(intersection),
+            },
+            _ => false,
+        }
+    }
+}
+
+struct Parser<'a> {
+    input: &'a str,
+    docs: Option<String>,
+}
+
+impl<'a> Parser<'a> {
+    fn new(input: &'a str) -> Self {
+        Self { input, docs: None }
+    }
+
+    fn is_done(&mut self) -> bool {
+        self.skip_whitespace();
+        self.input.is_empty() || self.input == ";"
+    }
+
+    /// Skips whitespace and comments, remembering the contents of the last doc comment
+    fn skip_whitespace(&mut self) {
+        loop {
+            self.input = self.input.trim_start();
+
+            if let Some(rest) = self.input.strip_prefix("/**") {
+                let Some((comment, rest)) = rest.split_once("*/") else {
+                    return;
+                };
+                self.docs = Some(parse_docs(comment));
+                self.input = rest;
+            } else if let Some(rest) = self.input.strip_prefix("/*") {
+                self.input = rest.split_once("*/").map_or("", |(_, rest)| rest);
+            } else if let Some(rest) = self.input.strip_prefix("//") {
+                self.input = rest.split_once('\n').map_or("", |(_, rest)| rest);
+            } else {
+                return;
+            }
+        }
+    }
+
+    fn peek(&mut self, token: &str) -> bool {
+        self.skip_whitespace();
+        self.input.starts_with(token)
+    }
+
+    fn eat(&mut self, token: &str) -> bool {
+        let found = self.peek(token);
+        if found {
+            self.input = &self.input[token.len()..];
+        }
+        found
+    }
+
+    fn expect(&mut self, token: &str) -> Option<()> {
+        self.eat(token).then_some(())
+    }
+
+    fn ident(&mut self) -> Option<String> {
+        self.skip_whitespace();
+        let len = self
+            .input
+            .find(|c: char| !(c.is_alphanumeric() || c == '_' || c == '$' || c == '.'))
+            .unwrap_or(self.input.len());
+
+        if len == 0 || self.input.starts_with(|c: char| c.is_ascii_digit()) {
+            return None;
+        }
+
+        let (ident, rest) = self.input.split_at(len);
+        self.input = rest;
+        Some(ident.to_owned())
+    }
+
+    fn string(&mut self) -> Option<String> {
+        self.skip_whitespace();
+        let quote = self
+            .input
+            .chars()
+            .next()
+            .filter(|c| *c == '"' || *c == '\'')?;
+
+        let mut value = String::new();
+        let mut chars = self.input.char_indices().skip(1);
+        while let Some((i, c)) = chars.next() {
+            match c {
+                '\\' => value.push(chars.next()?.1),
+                c if c == quote => {
+                    self.input = &self.input[i + 1..];
+                    return Some(value);
+                }
+                c => value.push(c),
+            }
+        }
+
+        None
+    }
+
+    fn number(&mut self) -> Option<String> {
+        self.skip_whitespace();
+        let len = self
+            .input
+            .char_indices()
+            .find(|(i, c)| !(c.is_ascii_digit() || *c == '.' || (*i == 0 && *c == '-')))
+            .map_or(self.input.len(), |(i, _)| i);
+
+        let (number, rest) = self.input.split_at(len);
+        if !number.contains(|c: char| c.is_ascii_digit()) {
+            return None;
+        }
+
+        self.input = rest;
+        Some(number.to_owned())
+    }
+
+    fn declaration(&mut self) -> Option<Declaration> {
+        let name = self.ident()?;
+        let generics = self.generics()?;
+
+        self.expect("=")?;
+        let ty = self.ty()?;
+
+        Some(Declaration {
+            name,
+            generics,
+            ty,
+            docs: None,
+            kind: DeclarationKind::Type,
+        })
+    }
+
+    /// Parses the declaration of an interface, which is equivalent to the intersection of its
+    /// properties and the types it extends.
+    fn interface(&mut self) -> Option<Declaration> {
+        let name = self.ident()?;
+        let generics = self.generics()?;
+
+        let mut extends = vec![];
+        if self.eat("extends ") {
+            loop {
+                extends.push(self.postfix()?);
+                if !self.eat(",") {
+                    break;
+                }
+            }
+        }
+
+        self.expect("{")?;
+        let properties = self.object()?;
+
+        Some(Declaration {
+            name,
+            generics,
+            ty: match extends.is_empty() {
+                true => properties,
+                false => TsType::Intersection([vec![properties], extends].concat()),
+            },
+            docs: None,
+            kind: DeclarationKind::Interface,
+        })
+    }
+
+    /// Parses the declaration of a TypeScript enum, e.g. `Role { "Admin" = 0, "Guest" }`, whose
+    /// values are given by `ty`.
+    fn enumeration(&mut self, ty: TsType) -> Option<Declaration> {
+        let name = self.ident()?;
+        self.expect("{")?;
+
+        let mut members = vec![];
+        while !self.eat("}") {
+            let name = match self.string() {
+                Some(name) => name,
+                None => self.ident()?,
+            };
+            let value = match self.eat("=") {
+                true => Some(match self.string() {
+                    Some(value) => Literal::String(value),
+                    None => Literal::Number(self.number()?),
+                }),
+                false => None,
+            };
+            members.push(EnumMember { name, value });
+
+            if !self.eat(",") {
+                self.expect("}")?;
+                break;
+            }
+        }
+
+        Some(Declaration {
+            name,
+            generics: vec![],
+            ty,
+            docs: None,
+            kind: DeclarationKind::Enum(members),
+        })
+    }
+
+    fn generics(&mut self) -> Option<Vec<Generic>> {
+        let mut generics = vec![];
+        if self.eat("<") {
+            loop {
+                let name = self.ident()?;
+                let default = self.eat("=").then(|| self.ty()).flatten();
+                generics.push(Generic { name, default });
+
+                if !self.eat(",") {
+                    break;
+                }
+            }
+            self.expect(">")?;
+        }
+        Some(generics)
+    }
+
+    fn ty(&mut self) -> Option<TsType> {
+        self.eat("|");
+
+        let mut variants = vec![self.intersection()?];
+        while self.eat("|") {
+            variants.push(self.intersection()?);
+        }
+
+        Some(match variants.len() {
+            1 => variants.remove(0),
+            _ => TsType::Union(variants),
+        })
+    }
+
+    fn intersection(&mut self) -> Option<TsType> {
+        let mut members = vec![self.postfix()?];
+        while self.eat("&") {
+            members.push(self.postfix()?);
+        }
+
+        Some(match members.len() {
+            1 => members.remove(0),
+            _ => TsType::Intersection(members),
+        })
+    }
+
+    fn postfix(&mut self) -> Option<TsType> {
+        let mut ty = self.primary()?;
+        while self.eat("[]") {
+            ty = TsType::Array(Box::new(ty));
+        }
+        Some(ty)
+    }
+
+    fn primary(&mut self) -> Option<TsType> {
+        self.skip_whitespace();
+
+        if self.eat("(") {
+            let ty = self.ty()?;
+            self.expect(")")?;
+            return Some(ty);
+        }
+
+        if self.eat("[") {
+            return self.tuple();
+        }
+
+        if self.eat("readonly ") {
+            return Some(TsType::Readonly(Box::new(self.primary()?)));
+        }
+
+        if self.eat("{") {
+            return self.object();
+        }
+
+        if self.peek("\"") || self.peek("'") {
+            return Some(TsType::Literal(Literal::String(self.string()?)));
+        }
+
+        if self.peek("-") || self.input.starts_with(|c: char| c.is_ascii_digit()) {
+            return Some(TsType::Literal(Literal::Number(self.number()?)));
+        }
+
+        let name = self.ident()?;
+        let mut generics = vec![];
+        if self.eat("<") {
+            loop {
+                generics.push(self.ty()?);
+                if !self.eat(",") {
+                    break;
+                }
+            }
+            self.expect(">")?;
+        }
+
+        Some(match (name.as_str(), generics.len()) {
+            ("string", 0) => TsType::Keyword(Keyword::String),
+            ("number", 0) => TsType::Keyword(Keyword::Number),
+            ("bigint", 0) => TsType::Keyword(Keyword::Bigint),
+            ("boolean", 0) => TsType::Keyword(Keyword::Boolean),
+            ("null", 0) => TsType::Keyword(Keyword::Null),
+            ("undefined", 0) => TsType::Keyword(Keyword::Undefined),
+            ("void", 0) => TsType::Keyword(Keyword::Void),
+            ("unknown", 0) => TsType::Keyword(Keyword::Unknown),
+            ("any", 0) => TsType::Keyword(Keyword::Any),
+            ("never", 0) => TsType::Keyword(Keyword::Never),
+            ("object", 0) => TsType::Keyword(Keyword::Object),
+            ("symbol", 0) => TsType::Keyword(Keyword::Symbol),
+            ("true", 0) => TsType::Literal(Literal::Boolean(true)),
+            ("false", 0) => TsType::Literal(Literal::Boolean(false)),
+            ("Array", 1) => TsType::Array(Box::new(generics.remove(0))),
+            ("ReadonlyArray", 1) => {
+                TsType::Readonly(Box::new(TsType::Array(Box::new(generics.remove(0)))))
+            }
+            ("Record", 2) => TsType::Record {
+                key: Box::new(generics.remove(0)),
+                value: Box::new(generics.remove(0)),
+                optional: false,
+                readonly: false,
+            },
+            _ => TsType::Reference { name, generics },
+        })
+    }
+
+    fn tuple(&mut self) -> Option<TsType> {
+        let mut elements = vec![];
+        while !self.eat("]") {
+            let ty = self.ty()?;
+            let optional = self.eat("?");
+            elements.push(Element { ty, optional });
+
+            if !self.eat(",") {
+                self.expect("]")?;
+                break;
+            }
+        }
+
+        Some(TsType::Tuple(elements))
+    }
+
+    fn object(&mut self) -> Option<TsType> {
+        let mut properties = vec![];
+        loop {
+            self.docs = None;
+            if self.eat("}") {
+                break;
+            }
+
+            let readonly = self.eat("readonly ");
+
+            // index signature, e.g. `{ [key in K]?: V }`
+            if self.eat("[") {
+                self.ident()?;
+                if !self.eat(":") {
+                    self.expect("in")?;
+                }
+                let key = self.ty()?;
+                self.expect("]")?;
+                let optional = self.eat("?");
+                self.expect(":")?;
+                let value = self.ty()?;
+                self.eat(",");
+                self.eat(";");
+                self.expect("}")?;
+
+                // an object with an index signature and other properties is not supported
+                if !properties.is_empty() {
+                    return None;
+                }
+
+                return Some(TsType::Record {
+                    key: Box::new(key),
+                    value: Box::new(value),
+                    optional,
+                    readonly,
+                });
+            }
+
+            let (name, quoted) = match self.string() {
+                Some(name) => (name, true),
+                None => (self.ident()?, false),
+            };
+            let docs = self.docs.take();
+            let optional = self.eat("?");
+            let spaced = self.input.starts_with(' ');
+            self.expect(":")?;
+            let ty = self.ty()?;
+            let style = match (spaced, quoted && is_identifier(&name)) {
+                (true, _) => PropertyStyle::Spaced,
+                (false, true) => PropertyStyle::Tag,
+                (false, false) => PropertyStyle::Field,
+            };
+            properties.push(Property {
+                name,
+                ty,
+                optional,
+                readonly,
+                docs,
+                style,
+            });
+
+            if !(self.eat(",") || self.eat(";")) {
+                self.expect("}")?;
+                // without a trailing comma, e.g. `{ readonly __brand: "UserId" }`
+                for property in &mut properties {
+                    property.style = match property.style {
+                        PropertyStyle::Field => PropertyStyle::Entry,
+                        PropertyStyle::Tag => PropertyStyle::Variant,
+                        style => style,
+                    };
+                }
+                break;
+            }
+        }
+
+        Some(TsType::Object(properties))
+    }
+}
+
+/// Returns the name of the brand if `properties` are the ones of `{ readonly __brand: "Name" }`
+fn brand(properties: &[Property]) -> Option<&str> {
+    match properties {
+        [Property {
+            name,
+            ty: TsType::Literal(Literal::String(brand)),
+            optional: false,
+            readonly: true,
+            ..
+        }] if name == "__brand" => Some(brand),
+        _ => None,
+    }
+}
+
+/// Extracts the lines of a doc comment generated by `format_docs`, e.g. `" Docs"` for
+/// `/**\n * Docs\n */`
+pub(crate) fn parse_docs(comment: &str) -> String {
+    comment
+        .lines()
+        .map(|line| {
+            let line = line.trim_start();
+            line.strip_prefix('*').unwrap_or(line).trim_end()
+        })
+        .collect::<Vec<_>>()
+        .join("\n")
+        .trim_matches('\n')
+        .to_owned()
+}
+
+/// Formats `docs` as a JSDoc comment, which is followed by a newline
+pub(crate) fn jsdoc(docs: &str) -> String {
+    crate::format_docs(&docs.lines().collect::<Vec<_>>())
+}
+
+impl Display for TsType {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Keyword(keyword) => keyword.fmt(f),
+            Self::Literal(literal) => literal.fmt(f),
+            Self::Reference { name, generics } => {
+                f.write_str(name)?;
+                if !generics.is_empty() {
+                    write!(f, "<{}>", join(generics, ", "))?;
+                }
+                Ok(())
+            }
+            // the type of empty tuple structs
+            Self::Array(ty) if **ty == Self::Keyword(Keyword::Never) => f.write_str("never[]"),
+            Self::Array(ty) => write!(f, "Array<{ty}>"),
+            Self::Readonly(ty) => match &**ty {
+                Self::Array(ty) => write!(f, "ReadonlyArray<{ty}>"),
+                ty => write!(f, "readonly {ty}"),
+            },
+            Self::Tuple(elements) => {
+                let elements = elements.iter().map(|element| match element.optional {
+                    true => format!("({})?", element.ty),
+                    false => element.ty.to_string(),
+                });
+                write!(f, "[{}]", elements.collect::<Vec<_>>().join(", "))
+            }
+            Self::Object(properties) if properties.is_empty() => f.write_str("{  }"),
+            Self::Object(properties) => {
+                f.write_str("{ ")?;
+                for (i, property) in properties.iter().enumerate() {
+                    property.fmt(f)?;
+                    if !property.terminated() && i + 1 < properties.len() {
+                        f.write_str(",")?;
+                    }
+                    f.write_str(" ")?;
+                }
+                f.write_str("}")
+            }
+            Self::Record {
+                key,
+                value,
+                optional,
+                readonly,
+            } => {
+                let optional = if *optional { "?" } else { "" };
+                let readonly = if *readonly { "readonly " } else { "" };
+                write!(f, "{{ {readonly}[key in {key}]{optional}: {value} }}")
+            }
+            Self::Union(variants) => {
+                let variants = variants
+                    .iter()
+                    .map(|variant| match variant.binds_looser(false) {
+                        true => format!("({variant})"),
+                        false => variant.to_string(),
+                    });
+                f.write_str(&variants.collect::<Vec<_>>().join(" | "))
+            }
+            Self::Intersection(members) => {
+                let members = members
+                    .iter()
+                    .map(|member| match member.binds_looser(true) {
+                        true => format!("({member})"),
+                        false => member.to_string(),
+                    });
+                f.write_str(&members.collect::<Vec<_>>().join(" & "))
+            }
+            Self::Function { params, returns } => {
+                write!(f, "({}) => {returns}", join(params, ", "))
+            }
+            Self::Raw(ts) => f.write_str(ts),
+        }
+    }
+}
+
+impl Display for Property {
+    /// Writes the property, e.g. `readonly id?: number,`, which is followed by a comma in the
+    /// style of struct fields. Properties of a function type are written as methods, e.g.
+    /// `getUser(id: UserId): Promise<User>,`, unless they are `readonly`.
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        if let Some(docs) = &self.docs {
+            write!(f, "\n{}", jsdoc(docs))?;
+        }
+        if self.readonly {
+            f.write_str("readonly ")?;
+        }
+        f.write_str(&self.key())?;
+        if self.optional {
+            f.write_str("?")?;
+        }
+        match &self.ty {
+            TsType::Function { params, returns } if !self.readonly => {
+                write!(f, "({}): {returns}", join(params, ", "))?;
+            }
+            ty => write!(f, "{}{ty}", self.colon())?,
+        }
+        match self.terminated() {
+            true => f.write_str(","),
+            false => Ok(()),
+        }
+    }
+}
+
+impl Display for Param {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.name, self.ty)
+    }
+}
+
+impl Display for Generic {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.name)?;
+        match &self.default {
+            Some(default) => write!(f, " = {default}"),
+            None => Ok(()),
+        }
+    }
+}
+
+impl Display for Declaration {
+    /// Writes the declaration without its docs, e.g. `type User = { id: number, };`
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let name = &self.name;
+        let generics = match self.generics.as_slice() {
+            [] => String::new(),
+            generics => format!("<{}>", join(generics, ", ")),
+        };
+
+        match (&self.kind, &self.ty) {
+            (DeclarationKind::Interface, TsType::Object(_)) => {
+                write!(f, "interface {name}{generics} {}", self.ty)
+            }
+            (DeclarationKind::Interface, TsType::Intersection(members))
+                if matches!(members.first(), Some(TsType::Object(_))) =>
+            {
+                let extends = join(&members[1..], ", ");
+                write!(
+                    f,
+                    "interface {name}{generics} extends {extends} {}",
+                    members[0]
+                )
+            }
+            (DeclarationKind::Enum(members), _) => {
+                let members = members.iter().map(|member| match &member.value {
+                    Some(value) => format!("{} = {value}", Literal::String(member.name.clone())),
+                    None => Literal::String(member.name.clone()).to_string(),
+                });
+                write!(
+                    f,
+                    "enum {name} {{ {} }}",
+                    members.collect::<Vec<_>>().join(", ")
+                )
+            }
+            (DeclarationKind::Function, TsType::Function { params, returns }) => {
+                let params = join(params, ", ");
+                write!(f, "declare function {name}{generics}({params}): {returns};")
+            }
+            (_, ty) => write!(f, "type {name}{generics} = {ty};"),
+        }
+    }
+}
+
+impl Display for Keyword {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(match self {
+            Self::String => "string",
+            Self::Number => "number",
+            Self::Bigint => "bigint",
+            Self::Boolean => "boolean",
+            Self::Null => "null",
+            Self::Undefined => "undefined",
+            Self::Void => "void",
+            Self::Unknown => "unknown",
+            Self::Any => "any",
+            Self::Never => "never",
+            Self::Object => "object",
+            Self::Symbol => "symbol",
+        })
+    }
+}
+
+/// Returns `true` if `name` can be used as a property key without quoting it
+pub(crate) fn is_identifier(name: &str) -> bool {
+    !name.is_empty()
+        && !name.starts_with(|c: char| c.is_ascii_digit())
+        && name
+            .chars()
+            .all(|c| c.is_alphanumeric() || c == '_' || c == '$')
+}
+
+impl Display for Literal {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::String(string) => {
+                let escaped = string.replace('\\', "\\\\").replace('"', "\\\"");
+                write!(f, "\"{escaped}\"")
+            }
+            Self::Number(number) => f.write_str(number),
+            Self::Boolean(boolean) => write!(f, "{boolean}"),
+        }
+    }
+}
+
+fn join(items: &[impl Display], separator: &str) -> String {
+    items
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(separator)
+}
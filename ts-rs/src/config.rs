@@ -0,0 +1,291 @@
+//! Reads the [`Config`] of a crate from `ts-rs.toml` or `[package.metadata.ts-rs]`.
+
+use std::path::Path;
+#[cfg(feature = "config-file")]
+use std::{any::type_name, path::PathBuf};
+
+#[cfg(feature = "config-file")]
+use toml::{Table, Value};
+
+use crate::{Config, ExportError};
+#[cfg(feature = "config-file")]
+use crate::{Direction, Layout};
+
+impl Config {
+    /// Loads the configuration of the crate whose bindings are exported.
+    ///
+    /// The configuration is read from `ts-rs.toml` in the directory given by
+    /// `CARGO_MANIFEST_DIR`, or, if there is no such file, from the `[package.metadata.ts-rs]`
+    /// table of the crate's `Cargo.toml`. Environment variables, see [`Config::from_env`], take
+    /// precedence over the file. If neither exists, this is the same as [`Config::from_env`].
+    ///
+    /// Reading the file requires the `config-file` feature. Without it, only the environment
+    /// variables are read, and a `ts-rs.toml` is reported as [`ExportError::InvalidConfig`].
+    ///
+    /// This is used by the tests generated for `#[ts(export)]`. See `Config::from_file` for
+    /// the available options.
+    pub fn load() -> Result<Self, ExportError> {
+        let dir = std::env::var_os("CARGO_MANIFEST_DIR").ok_or(ExportError::ManifestDirNotSet)?;
+        Self::load_from(dir)
+    }
+
+    /// Loads the configuration of the crate in `dir`, like [`Config::load`].
+    pub fn load_from(dir: impl AsRef<Path>) -> Result<Self, ExportError> {
+        let dir = dir.as_ref();
+        let file = dir.join("ts-rs.toml");
+
+        #[cfg(feature = "config-file")]
+        let cfg = match file.is_file() {
+            true => Self::from_file(file)?,
+            false => from_manifest(&dir.join("Cargo.toml"))?,
+        };
+        #[cfg(not(feature = "config-file"))]
+        let cfg = match file.is_file() {
+            true => {
+                return Err(ExportError::InvalidConfig {
+                    path: Some(file),
+                    reason: "reading it requires the `config-file` feature of ts-rs".to_owned(),
+                })
+            }
+            false => Self::default(),
+        };
+
+        cfg.with_env(true)
+    }
+
+    /// Reads the configuration from a TOML file, e.g. `ts-rs.toml`:
+    /// ```toml
+    /// out-dir = "../frontend/src/bindings"
+    /// large-int = "number"
+    /// import-extension = "js"
+    ///
+    /// [format]
+    /// indent-width = 4
+    /// ```
+    ///
+    /// | Key                  | Description                                                  | Default        |
+    /// |----------------------|--------------------------------------------------------------|----------------|
+    /// | `array-tuple-limit`  | See `Config::with_array_tuple_limit`                         | `64`           |
+    /// | `barrels`            | See `Config::with_barrels`                                   | `false`        |
+    /// | `brand-constructors` | See `Config::with_brand_constructors`                        | `false`        |
+    /// | `branded-newtypes`   | See `Config::with_branded_newtypes`                          | `false`        |
+    /// | `check`              | See `Config::with_check_only`                                | `false`        |
+    /// | `direction`          | `"both"`, `"serialize"` or `"deserialize"`                   | `"both"`       |
+    /// | `import-extension`   | `"js"` or `"ts"`, see `Config::with_import_extension`        | *none*         |
+    /// | `indent`             | See `Config::with_indent`                                    | `"  "`         |
+    /// | `interfaces`         | See `Config::with_interfaces`                                | `false`        |
+    /// | `large-int`          | See `Config::with_large_int`                                 | `"bigint"`     |
+    /// | `layout`             | `"single-line"` or `"multi-line"`, see `Config::with_layout` | `"multi-line"` |
+    /// | `line-width`         | See `Config::with_line_width`                                | `80`           |
+    /// | `out-dir`            | Relative to the directory of the file                        | `./bindings`   |
+    /// | `readonly`           | See `Config::with_readonly`                                  | `false`        |
+    /// | `single-file`        | See `Config::with_single_file`                               | *none*         |
+    /// | `type-guards`        | See `Config::with_type_guards`                               | `false`        |
+    /// | `zod`                | See `Config::with_zod` (`zod` feature)                       | `false`        |
+    /// | `[format]`           | See `Config::with_formatting` (`format` feature)             |                |
+    ///
+    /// The `[format]` table accepts `indent-width`, `use-tabs`, `line-width`, `quote-style`
+    /// (`"double"` or `"single"`), `semicolons` and `trailing-commas`.
+    ///
+    /// Unknown keys and invalid values are reported as [`ExportError::InvalidConfig`].
+    /// Environment variables are not read, see [`Config::load`].
+    /// Requires the `config-file` feature.
+    #[cfg(feature = "config-file")]
+    pub fn from_file(path: impl AsRef<Path>) -> Result<Self, ExportError> {
+        let path = path.as_ref();
+        let table = read(path)?;
+        Reader { path, prefix: "" }.config(&table)
+    }
+}
+
+/// Reads the configuration from `[package.metadata.ts-rs]` in `Cargo.toml`, if it exists
+#[cfg(feature = "config-file")]
+fn from_manifest(path: &Path) -> Result<Config, ExportError> {
+    if !path.is_file() {
+        return Ok(Config::default());
+    }
+
+    let manifest = read(path)?;
+    let metadata = ["package", "metadata", "ts-rs"]
+        .iter()
+        .try_fold(&manifest, |table, key| table.get(*key)?.as_table());
+    match metadata {
+        Some(table) => Reader {
+            path,
+            prefix: "package.metadata.ts-rs.",
+        }
+        .config(table),
+        None => Ok(Config::default()),
+    }
+}
+
+#[cfg(feature = "config-file")]
+fn read(path: &Path) -> Result<Table, ExportError> {
+    let contents = std::fs::read_to_string(path)?;
+    contents
+        .parse::<Table>()
+        .map_err(|e| ExportError::InvalidConfig {
+            path: Some(path.to_owned()),
+            reason: e.to_string().trim_end().to_owned(),
+        })
+}
+
+#[cfg(feature = "config-file")]
+struct Reader<'a> {
+    // the file the configuration is read from
+    path: &'a Path,
+    // the prefix of keys in errors, e.g. `package.metadata.ts-rs.`
+    prefix: &'a str,
+}
+
+#[cfg(feature = "config-file")]
+impl Reader<'_> {
+    fn config(&self, table: &Table) -> Result<Config, ExportError> {
+        let mut cfg = Config::default();
+
+        for (key, value) in table {
+            match key.as_str() {
+                "out-dir" => {
+                    let dir = Path::new(self.string(key, value)?);
+                    cfg.export_dir = match self.path.parent() {
+                        Some(parent) => parent.join(dir),
+                        None => dir.to_owned(),
+                    };
+                }
+                "large-int" => cfg.large_int_type = self.string(key, value)?.to_owned(),
+                "import-extension" => match self.string(key, value)? {
+                    ext @ ("js" | "ts") => cfg.import_extension = Some(ext.to_owned()),
+                    _ => return Err(self.invalid(key, value, r#"expected "js" or "ts""#)),
+                },
+                "array-tuple-limit" => {
+                    cfg.array_tuple_limit = self.integer(key, value)?;
+                }
+                "direction" => {
+                    cfg.direction = match self.string(key, value)? {
+                        "both" => Direction::Both,
+                        "serialize" => Direction::Serialize,
+                        "deserialize" => Direction::Deserialize,
+                        _ => {
+                            let expected = r#"expected "both", "serialize" or "deserialize""#;
+                            return Err(self.invalid(key, value, expected));
+                        }
+                    }
+                }
+                "type-guards" => cfg.type_guards = self.bool(key, value)?,
+                "single-file" => {
+                    cfg.single_file = Some(PathBuf::from(self.string(key, value)?));
+                }
+                "barrels" => cfg.barrels = self.bool(key, value)?,
+                "check" => cfg.check_only = self.bool(key, value)?,
+                "interfaces" => cfg.interfaces = self.bool(key, value)?,
+                "branded-newtypes" => cfg.branded_newtypes = self.bool(key, value)?,
+                "brand-constructors" => cfg.brand_constructors = self.bool(key, value)?,
+                "readonly" => cfg.readonly = self.bool(key, value)?,
+                "layout" => {
+                    cfg.layout = match self.string(key, value)? {
+                        "single-line" => Layout::SingleLine,
+                        "multi-line" => Layout::MultiLine,
+                        _ => {
+                            let expected = r#"expected "single-line" or "multi-line""#;
+                            return Err(self.invalid(key, value, expected));
+                        }
+                    }
+                }
+                "indent" => cfg.indent = self.string(key, value)?.to_owned(),
+                "line-width" => cfg.line_width = self.integer(key, value)?,
+                #[cfg(feature = "zod")]
+                "zod" => cfg.zod = self.bool(key, value)?,
+                #[cfg(feature = "format")]
+                "format" => cfg.formatting = self.format(key, value)?,
+                #[cfg(not(feature = "zod"))]
+                "zod" => return Err(self.requires(key, "zod")),
+                #[cfg(not(feature = "format"))]
+                "format" => return Err(self.requires(key, "format")),
+                _ => return Err(self.error(format!("unknown key `{}{key}`", self.prefix))),
+            }
+        }
+
+        Ok(cfg)
+    }
+
+    #[cfg(feature = "format")]
+    fn format(&self, key: &str, value: &Value) -> Result<crate::FormatOptions, ExportError> {
+        use crate::{FormatOptions, QuoteStyle};
+
+        let table = value
+            .as_table()
+            .ok_or_else(|| self.invalid(key, value, "expected a table"))?;
+        let reader = Reader {
+            path: self.path,
+            prefix: &format!("{}{key}.", self.prefix),
+        };
+
+        let mut options = FormatOptions::new();
+        for (key, value) in table {
+            options = match key.as_str() {
+                "indent-width" => options.with_indent_width(reader.integer(key, value)?),
+                "use-tabs" if reader.bool(key, value)? => options.with_tabs(),
+                "use-tabs" => options,
+                "line-width" => options.with_line_width(reader.integer(key, value)?),
+                "quote-style" => options.with_quote_style(match reader.string(key, value)? {
+                    "double" => QuoteStyle::Double,
+                    "single" => QuoteStyle::Single,
+                    _ => {
+                        let expected = r#"expected "double" or "single""#;
+                        return Err(reader.invalid(key, value, expected));
+                    }
+                }),
+                "semicolons" => options.with_semicolons(reader.bool(key, value)?),
+                "trailing-commas" => options.with_trailing_commas(reader.bool(key, value)?),
+                _ => return Err(reader.error(format!("unknown key `{}{key}`", reader.prefix))),
+            };
+        }
+
+        Ok(options)
+    }
+
+    fn string<'v>(&self, key: &str, value: &'v Value) -> Result<&'v str, ExportError> {
+        value
+            .as_str()
+            .ok_or_else(|| self.invalid(key, value, "expected a string"))
+    }
+
+    fn bool(&self, key: &str, value: &Value) -> Result<bool, ExportError> {
+        value
+            .as_bool()
+            .ok_or_else(|| self.invalid(key, value, "expected `true` or `false`"))
+    }
+
+    fn integer<T: TryFrom<i64>>(&self, key: &str, value: &Value) -> Result<T, ExportError> {
+        value
+            .as_integer()
+            .and_then(|int| T::try_from(int).ok())
+            .ok_or_else(|| {
+                let expected =
+                    format!("expected an integer in the range of `{}`", type_name::<T>());
+                self.invalid(key, value, &expected)
+            })
+    }
+
+    fn invalid(&self, key: &str, value: &Value, expected: &str) -> ExportError {
+        self.error(format!(
+            "invalid value `{value}` for `{}{key}`, {expected}",
+            self.prefix
+        ))
+    }
+
+    #[cfg(not(all(feature = "zod", feature = "format")))]
+    fn requires(&self, key: &str, feature: &str) -> ExportError {
+        self.error(format!(
+            "`{}{key}` requires the `{feature}` feature of ts-rs",
+            self.prefix
+        ))
+    }
+
+    fn error(&self, reason: String) -> ExportError {
+        ExportError::InvalidConfig {
+            path: Some(self.path.to_owned()),
+            reason,
+        }
+    }
+}
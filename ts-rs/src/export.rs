@@ -2,34 +2,70 @@ use std::{
     any::TypeId,
     collections::{BTreeMap, BTreeSet, HashMap, HashSet},
     fmt::Write,
-    fs::File,
-    io::{Seek, SeekFrom},
     path::{Component, Path, PathBuf},
     sync::{Mutex, OnceLock},
 };
 
 pub use error::ExportError;
+use format::format;
+#[cfg(feature = "format")]
+pub use format::{FormatOptions, QuoteStyle};
+use lock::{write_atomic, FileLock};
+use merge::merge;
 use path::diff_paths;
 pub(crate) use recursive_export::export_all_into;
+#[cfg(feature = "registry")]
+pub(crate) use recursive_export::export_recursive;
+pub use check::check_files;
+pub use stale::{remove_stale_files, stale_files};
+
+use crate::{
+    ast::{DeclarationKind, TsType},
+    Config, Dependency, Layout, TS,
+};
 
-use crate::{Config, TS};
-
+mod check;
 mod error;
+mod format;
+mod lock;
+mod merge;
 mod path;
+mod pretty;
+mod run;
+mod stale;
 
-static EXPORT_PATHS: OnceLock<Mutex<HashMap<PathBuf, HashSet<String>>>> = OnceLock::new();
+static EXPORT_PATHS: OnceLock<Mutex<HashMap<PathBuf, HashSet<Exported>>>> = OnceLock::new();
 
-fn get_export_paths<'a>() -> &'a Mutex<HashMap<PathBuf, HashSet<String>>> {
+fn get_export_paths<'a>() -> &'a Mutex<HashMap<PathBuf, HashSet<Exported>>> {
     EXPORT_PATHS.get_or_init(Default::default)
 }
 
-const NOTE: &str = "// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.\n";
+/// A type or value exported into a file
+#[derive(Clone, PartialEq, Eq, Hash)]
+enum Exported {
+    /// A type, and the values exported together with it, like the constant holding its variants
+    /// if it is declared using `#[ts(export_values)]`, or its brand constructor. A TypeScript
+    /// `enum` is a value itself, so its name is among the values.
+    Type(String, Vec<String>),
+    #[cfg_attr(not(feature = "values"), allow(dead_code))]
+    Value(String),
+}
+
+impl Exported {
+    fn name(&self) -> &str {
+        match self {
+            Self::Type(name, _) | Self::Value(name) => name,
+        }
+    }
+}
+
+pub(crate) const NOTE: &str = "// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.\n";
 
 mod recursive_export {
     use std::{any::TypeId, collections::HashSet};
 
     use super::export_into;
-    use crate::{Config, ExportError, TypeVisitor, TS};
+    use crate::{Config, Direction, ExportError, TypeVisitor, TS};
 
     /// Exports `T` to the file specified by the `#[ts(export_to = ..)]` attribute within the given
     /// base directory.  
@@ -57,10 +93,36 @@ mod recursive_export {
 
             self.error = export_recursive::<T>(self.cfg, self.seen).err();
         }
+
+        fn direction(&self) -> Direction {
+            self.cfg.direction()
+        }
+
+        fn interfaces(&self) -> bool {
+            self.cfg.interfaces()
+        }
+    }
+
+    /// Exports the types used by a value of type `T`, which are `T` itself, if it can be
+    /// exported, and the types it is generic over, together with all of their dependencies.
+    #[cfg(feature = "values")]
+    pub(crate) fn export_used_by<T: TS + ?Sized + 'static>(
+        cfg: &Config,
+    ) -> Result<(), ExportError> {
+        let mut seen = HashSet::new();
+        let mut visitor = Visit {
+            cfg,
+            seen: &mut seen,
+            error: None,
+        };
+        visitor.visit::<T>();
+        <T as crate::TS>::visit_generics(&mut visitor);
+
+        visitor.error.map_or(Ok(()), Err)
     }
 
     // exports T, then recursively calls itself with all of its dependencies
-    fn export_recursive<T: TS + ?Sized + 'static>(
+    pub(crate) fn export_recursive<T: TS + ?Sized + 'static>(
         cfg: &Config,
         seen: &mut HashSet<TypeId>,
     ) -> Result<(), ExportError> {
@@ -87,12 +149,19 @@ mod recursive_export {
 
 /// Export `T` to the file specified by the `#[ts(export_to = ..)]` attribute
 pub(crate) fn export_into<T: TS + ?Sized + 'static>(cfg: &Config) -> Result<(), ExportError> {
+    let path = cfg.export_dir.join(output_path::<T>(cfg)?);
+
+    export_to::<T, _>(cfg, path::absolute(path)?)
+}
+
+/// Returns the path to which `T` is exported, relative to the output directory.
+/// This is the file given by `#[ts(export_to = "..")]`, unless [`Config::with_single_file`] is used.
+pub(crate) fn output_path<T: TS + ?Sized>(cfg: &Config) -> Result<PathBuf, ExportError> {
     let path = <T as crate::TS>::output_path()
         .ok_or_else(std::any::type_name::<T>)
         .map_err(ExportError::CannotBeExported)?;
-    let path = cfg.export_dir.join(path);
 
-    export_to::<T, _>(cfg, path::absolute(path)?)
+    Ok(cfg.single_file().map_or(path, Path::to_owned))
 }
 
 /// Export `T` to the file specified by the `path` argument.
@@ -100,218 +169,296 @@ pub(crate) fn export_to<T: TS + ?Sized + 'static, P: AsRef<Path>>(
     cfg: &Config,
     path: P,
 ) -> Result<(), ExportError> {
-    let path = path.as_ref().to_owned();
+    // the same file must always be referred to by the same path, so that types exported into it
+    // using `TS::export` and `TS::export_all` are merged
+    let path = path::absolute(path)?;
     let type_name = <T as crate::TS>::ident(cfg);
+    let mut values = Vec::from_iter(<T as crate::TS>::variant_values(cfg).map(|(name, _)| name));
+    let is_enum = matches!(T::decl_ast(cfg).kind, DeclarationKind::Enum(_));
+    if is_enum || exports_brand_constructor::<T>(cfg) {
+        values.push(type_name.clone());
+    }
 
-    #[allow(unused_mut)]
-    let mut buffer = export_to_string::<T>(cfg)?;
-
-    // format output
-    #[cfg(feature = "format")]
-    {
-        use dprint_plugin_typescript::{
-            configuration::ConfigurationBuilder, format_text, FormatTextOptions,
-        };
+    let buffer = format(cfg, &path, &type_name, export_to_string::<T>(cfg)?)?;
 
-        let fmt_cfg = ConfigurationBuilder::new().deno().build();
-        let options = FormatTextOptions {
-            config: &fmt_cfg,
-            path: path.as_ref(),
-            text: buffer.clone(),
-            extension: None,
-            external_formatter: None,
-        };
-        if let Some(formatted) =
-            format_text(options).map_err(|e| ExportError::Formatting(e.to_string()))?
-        {
-            buffer = formatted;
-        }
+    if let Some(parent) = path.parent().filter(|_| !cfg.check_only()) {
+        std::fs::create_dir_all(parent)?;
     }
 
-    if let Some(parent) = path.parent() {
+    export_and_merge(cfg, path, Exported::Type(type_name, values), buffer)?;
+
+    Ok(())
+}
+
+/// Exports `value` as a constant named `name` into the file `path`, relative to the output
+/// directory. The types used by the value are exported as well.
+#[cfg(feature = "values")]
+pub(crate) fn export_value<T: TS + serde::Serialize + ?Sized + 'static>(
+    value: &T,
+    name: &str,
+    path: &Path,
+    cfg: &Config,
+) -> Result<(), ExportError> {
+    let path = cfg.export_dir.join(cfg.single_file().unwrap_or(path));
+
+    let mut decl = String::from("export ");
+    decl.push_str(&crate::value::declaration(value, name, cfg)?);
+
+    let mut buffer = String::with_capacity(decl.len() + 256);
+    buffer.push_str(NOTE);
+    let deps = crate::value::dependencies::<T>(cfg);
+    write_imports(cfg, &path, deps, &mut buffer, &decl)?;
+    buffer.push_str(&decl);
+    buffer.push('\n');
+    let path = path::absolute(path)?;
+    let buffer = format(cfg, &path, name, buffer)?;
+
+    if let Some(parent) = path.parent().filter(|_| !cfg.check_only()) {
         std::fs::create_dir_all(parent)?;
     }
 
-    export_and_merge(path, type_name, buffer)?;
+    export_and_merge(cfg, path, Exported::Value(name.to_owned()), buffer)?;
 
-    Ok(())
+    recursive_export::export_used_by::<T>(cfg)
 }
 
-/// Exports the type to a new file if the file hasn't yet been written to.
-/// Otherwise, finds its place in the already existing file and inserts it.
+/// Exports the type into the file at `path`, finding its place among the declarations already
+/// in the file and inserting it.
+///
+/// Other processes may export into the same file concurrently, so the file is locked while it is
+/// being read and written, and its contents are replaced atomically. The first type this process
+/// exports into a file is merged with its contents on disk if they were written by another
+/// process of the same run, e.g. another test binary. Contents left behind by a previous run are
+/// overwritten, so declarations which are no longer generated disappear.
 fn export_and_merge(
+    cfg: &Config,
     path: PathBuf,
-    type_name: String,
+    exported: Exported,
     generated_type: String,
 ) -> Result<(), ExportError> {
-    use std::io::{Read, Write};
+    if cfg.check_only() {
+        return check::check_and_merge(cfg, path, exported.name().to_owned(), generated_type);
+    }
 
     let lock = &mut get_export_paths().lock().unwrap();
 
     let Some(entry) = lock.get_mut(&path) else {
-        // The file hasn't been written to yet, so it must be
-        // overwritten
-        let mut file = File::create(&path)?;
-        file.write_all(generated_type.as_bytes())?;
-        file.sync_all()?;
+        // This process hasn't written to the file yet, but others of the same run may have.
+        // Files which were not generated by ts-rs, or which cannot be merged, are overwritten.
+        {
+            let _file_lock = FileLock::acquire(&path)?;
+            let original_contents = std::fs::read_to_string(&path).unwrap_or_default();
+            let merged = (run::claim(&path)? && original_contents.starts_with(NOTE))
+                .then(|| merge(&path, &original_contents, &generated_type).ok())
+                .flatten();
+            let buffer = match merged {
+                Some(buffer) => format(cfg, &path, exported.name(), buffer)?,
+                None => generated_type,
+            };
+            write_atomic(&path, &buffer)?;
+        }
 
         let mut set = HashSet::new();
-        set.insert(type_name);
-        lock.insert(path, set);
+        set.insert(exported);
+        lock.insert(path.clone(), set);
+
+        if cfg.barrels() {
+            write_barrel(cfg, &path, lock)?;
+        }
 
         return Ok(());
     };
 
-    if entry.contains(&type_name) {
+    if entry.contains(&exported) {
         return Ok(());
     }
 
-    let mut file = std::fs::OpenOptions::new()
-        .read(true)
-        .write(true)
-        .open(&path)?;
-
-    let file_len = file.metadata()?.len();
-
-    let mut original_contents = String::with_capacity(file_len as usize);
-    file.read_to_string(&mut original_contents)?;
-
-    let buffer = merge(original_contents, generated_type);
-
-    file.seek(SeekFrom::Start(NOTE.len() as u64))?;
+    {
+        let _file_lock = FileLock::acquire(&path)?;
+        let original_contents = std::fs::read_to_string(&path)?;
+        let buffer = merge(&path, &original_contents, &generated_type)?;
+        let buffer = format(cfg, &path, exported.name(), buffer)?;
+        write_atomic(&path, &buffer)?;
+    }
 
-    file.write_all(buffer.as_bytes())?;
-    file.sync_all()?;
+    entry.insert(exported);
 
-    entry.insert(type_name);
+    if cfg.barrels() {
+        write_barrel(cfg, &path, lock)?;
+    }
 
     Ok(())
 }
 
-const HEADER_ERROR_MESSAGE: &str = "The generated strings must have their NOTE and imports separated from their type declarations by a new line";
-
-const DECLARATION_START: &str = "export type ";
+/// Writes `contents` into the file `path`, which is generated as a whole instead of being merged
+/// with other declarations, e.g. a JSON Schema.
+/// Like other files, it is locked while it is being written, compared to its contents on disk in
+/// check mode, and is not considered stale.
+pub(crate) fn write_generated_file(
+    cfg: &Config,
+    path: &Path,
+    contents: &str,
+) -> Result<(), ExportError> {
+    let path = path::absolute(path)?;
+    if cfg.check_only() {
+        return check::check_file(path, contents);
+    }
 
-/// Inserts the imports and declaration from the newly generated type
-/// into the contents of the file, removimg duplicate imports and organazing
-/// both imports and declarations alphabetically
-fn merge(original_contents: String, new_contents: String) -> String {
-    let (original_header, original_decls) = original_contents
-        .split_once("\n\n")
-        .expect(HEADER_ERROR_MESSAGE);
-    let (new_header, new_decl) = new_contents.split_once("\n\n").expect(HEADER_ERROR_MESSAGE);
+    if let Some(parent) = path.parent() {
+        std::fs::create_dir_all(parent)?;
+    }
 
-    let import_lines = original_header
-        .lines()
-        .skip(1)
-        .chain(new_header.lines().skip(1))
-        .map(|line| {
-            let (import, from) = line.split_once(" from ").unwrap();
-            let path = from.trim_start_matches('"').trim_end_matches(['"', ';']);
+    let lock = &mut get_export_paths().lock().unwrap();
+    {
+        let _file_lock = FileLock::acquire(&path)?;
+        write_atomic(&path, contents)?;
+    }
+    lock.entry(path).or_default();
 
-            let types = import
-                .trim_start_matches("import type { ")
-                .trim_end_matches(" }")
-                .split(", ");
+    Ok(())
+}
 
-            (path, types)
-        });
+const BARREL: &str = "index.ts";
 
-    let mut imports_map: BTreeMap<&str, BTreeSet<&str>> = Default::default();
+/// Writes the barrel file `index.ts`, which re-exports all types exported into the directory
+/// of the file `path`.
+/// Since other processes of the same run may export types into the same directory, the barrel is
+/// merged with the contents they wrote, keeping it independent of the order in which types are
+/// exported.
+fn write_barrel(
+    cfg: &Config,
+    path: &Path,
+    export_paths: &HashMap<PathBuf, HashSet<Exported>>,
+) -> Result<(), ExportError> {
+    let dir = path::absolute(path.parent().unwrap())?;
+    let barrel = dir.join(BARREL);
 
-    for (path, types) in import_lines {
-        let entry = imports_map.entry(path).or_default();
+    // `(import path, is_value)` => names
+    let mut exports: BTreeMap<(String, bool), BTreeSet<String>> = Default::default();
+    let mut known = HashSet::new();
 
-        for ty in types {
-            entry.insert(ty);
+    for (file, types) in export_paths {
+        let file = path::absolute(file)?;
+        if file.parent() != Some(dir.as_path()) {
+            continue;
+        }
+        // types are exported into the barrel file itself, which must not be overwritten
+        if file == barrel {
+            return Ok(());
         }
-    }
-
-    let mut imports = String::new();
-    for (path, types) in imports_map {
-        imports.push_str("import type { ");
 
-        let mut types = types.iter().peekable();
-        while let Some(ty) = types.next() {
-            imports.push_str(ty);
+        let from = import_path(cfg, &barrel, &file)?;
+        known.extend(types.iter().map(|exported| exported.name().to_owned()));
+        for exported in types {
+            let (ty, values) = match exported {
+                Exported::Type(ty, values) => (ty, values),
+                Exported::Value(name) => {
+                    let entry = exports.entry((from.clone(), true)).or_default();
+                    entry.insert(name.clone());
+                    continue;
+                }
+            };
+
+            let mut values = values.clone();
+            if cfg.type_guards() {
+                values.push(format!("is{ty}"));
+            }
+            #[cfg(feature = "zod")]
+            if cfg.zod() {
+                values.push(format!("{ty}Schema"));
+            }
 
-            if types.peek().is_some() {
-                imports.push_str(", ");
+            // an enum, or a brand constructor, has the name of its type, and re-exporting it
+            // re-exports both
+            if !values.contains(ty) {
+                let entry = exports.entry((from.clone(), false)).or_default();
+                entry.insert(ty.clone());
+            }
+            if !values.is_empty() {
+                let entry = exports.entry((from.clone(), true)).or_default();
+                entry.extend(values);
             }
         }
-
-        imports.push_str(" } from \"");
-        imports.push_str(path);
-        imports.push_str("\";\n");
     }
 
-    let capacity = imports.len() + original_decls.len() + new_decl.len() + 2;
-
-    let mut buffer = String::with_capacity(capacity);
-    buffer.push_str(&imports);
-
-    let new_decl = new_decl.trim_matches('\n');
-
-    let new_decl_name = new_decl
-        .split(DECLARATION_START)
-        .last()
-        .unwrap()
-        .split_whitespace()
-        .next()
-        .unwrap();
-
-    let original_decls = original_decls.split("\n\n").map(|x| x.trim_matches('\n'));
-
-    let mut inserted = false;
-    for decl in original_decls {
-        let decl_name = decl
-            .split(DECLARATION_START)
-            .last()
-            .unwrap()
-            .split_whitespace()
-            .next()
-            .unwrap();
-
-        if inserted || decl_name < new_decl_name {
-            buffer.push('\n');
-            buffer.push_str(decl);
-            buffer.push('\n');
-        } else {
-            buffer.push('\n');
-            buffer.push_str(new_decl);
-            buffer.push('\n');
-
-            buffer.push('\n');
-            buffer.push_str(decl);
-            buffer.push('\n');
-
-            inserted = true;
+    let _file_lock = FileLock::acquire(&barrel)?;
+    let contents = match run::claim(&barrel)? {
+        true => std::fs::read_to_string(&barrel),
+        false => Ok(String::new()),
+    };
+    if let Ok(contents) = contents {
+        for line in contents.lines() {
+            let Some((names, from)) = line.split_once(" } from ") else {
+                continue;
+            };
+            let (is_value, names) = match names.strip_prefix("export type { ") {
+                Some(names) => (false, names),
+                None => match names.strip_prefix("export { ") {
+                    Some(names) => (true, names),
+                    None => continue,
+                },
+            };
+
+            // types exported by this process may have moved, so only others are kept
+            let names = names.split(", ").filter(|name| {
+                let ty = name.strip_prefix("is").unwrap_or(name);
+                let ty = name.strip_suffix("Schema").unwrap_or(ty);
+                !name.is_empty() && !known.contains(*name) && !known.contains(ty)
+            });
+
+            let from = from.trim_end_matches(';').trim_matches('"').to_owned();
+            let entry = exports.entry((from, is_value)).or_default();
+            entry.extend(names.map(ToOwned::to_owned));
         }
     }
 
-    if !inserted {
-        buffer.push('\n');
-        buffer.push_str(new_decl);
-        buffer.push('\n');
+    let mut buffer = String::from(NOTE);
+    for ((from, is_value), names) in exports {
+        if names.is_empty() {
+            continue;
+        }
+
+        let names = names.into_iter().collect::<Vec<_>>().join(", ");
+        match is_value {
+            true => writeln!(buffer, r#"export {{ {names} }} from "{from}";"#)?,
+            false => writeln!(buffer, r#"export type {{ {names} }} from "{from}";"#)?,
+        }
     }
 
-    buffer
+    write_atomic(&barrel, &buffer)?;
+    Ok(())
 }
 
 /// Returns the generated definition for `T`.
 pub(crate) fn export_to_string<T: TS + ?Sized + 'static>(
     cfg: &Config,
 ) -> Result<String, ExportError> {
-    let mut buffer = String::with_capacity(1024);
+    let mut decl = String::with_capacity(1024);
+    generate_decl::<T>(cfg, &mut decl);
+
+    // The guard directly follows the declaration, so both are kept together when merging files
+    if cfg.type_guards() {
+        decl.push_str("\nexport ");
+        decl.push_str(&<T as crate::TS>::guard(cfg));
+    }
+
+    let mut buffer = String::with_capacity(decl.len() + 256);
     buffer.push_str(NOTE);
-    generate_imports::<<T as crate::TS>::WithoutGenerics>(cfg, &mut buffer)?;
-    generate_decl::<T>(cfg, &mut buffer);
+    generate_imports::<<T as crate::TS>::WithoutGenerics>(cfg, &mut buffer, &decl)?;
+    buffer.push_str(&decl);
     buffer.push('\n');
     Ok(buffer)
 }
 
 /// Push the declaration of `T`
-fn generate_decl<T: TS + ?Sized>(cfg: &Config, out: &mut String) {
+fn generate_decl<T: TS + ?Sized + 'static>(cfg: &Config, out: &mut String) {
+    #[cfg(feature = "zod")]
+    if cfg.zod() {
+        out.push_str(&crate::zod::declarations::<T>(cfg));
+        push_variant_values::<T>(cfg, out);
+        return;
+    }
+
     // Type Docs
     if let Some(docs) = <T as crate::TS>::docs() {
         out.push_str(&docs);
@@ -319,31 +466,141 @@ fn generate_decl<T: TS + ?Sized>(cfg: &Config, out: &mut String) {
 
     // Type Definition
     out.push_str("export ");
-    out.push_str(&<T as crate::TS>::decl(cfg));
+    out.push_str(&layout::<T>(cfg));
+
+    if cfg.brand_constructors() {
+        if let Some(constructor) = brand_constructor::<T>(cfg) {
+            out.push('\n');
+            out.push_str(&constructor);
+        }
+    }
+
+    push_variant_values::<T>(cfg, out);
+}
+
+/// Lays out the declaration of `T` following `export `, see [`Config::with_layout`]
+fn layout<T: TS + ?Sized + 'static>(cfg: &Config) -> String {
+    // the formatter decides on the layout itself
+    if cfg!(feature = "format") {
+        return T::decl(cfg);
+    }
+
+    match cfg.layout() {
+        Layout::SingleLine => T::decl(cfg),
+        Layout::MultiLine => {
+            let decl = T::decl_ast(cfg);
+            pretty::pretty(&decl, "export ".len(), cfg.indent(), cfg.line_width())
+        }
+    }
+}
+
+/// Push the array of the variants of `T`, if it is an enum annotated with `#[ts(export_values)]`,
+/// e.g. `export const COLOR_VALUES = ["Red", "Green"] as const;`
+fn push_variant_values<T: TS + ?Sized + 'static>(cfg: &Config, out: &mut String) {
+    if let Some((name, values)) = <T as crate::TS>::variant_values(cfg) {
+        out.push_str(&format!(
+            "\nexport const {name} = [{}] as const;",
+            values.join(", ")
+        ));
+    }
+}
+
+/// Returns `true` if the brand constructor of `T` is exported together with its declaration
+fn exports_brand_constructor<T: TS + ?Sized + 'static>(cfg: &Config) -> bool {
+    #[cfg(feature = "zod")]
+    if cfg.zod() {
+        return false;
+    }
+
+    cfg.brand_constructors() && brand_constructor::<T>(cfg).is_some()
+}
+
+/// Returns a function casting a value to the branded type `T`, e.g.
+/// `export const UserId = (value: string): UserId => value as UserId;`
+fn brand_constructor<T: TS + ?Sized + 'static>(cfg: &Config) -> Option<String> {
+    let decl = T::decl_ast(cfg);
+    let TsType::Intersection(members) = decl.ty else {
+        return None;
+    };
+    if !members.iter().any(|member| member.brand() == Some(&decl.name)) {
+        return None;
+    }
+
+    let mut inner = members
+        .into_iter()
+        .filter(|member| member.brand().is_none())
+        .collect::<Vec<_>>();
+    let inner = match inner.len() {
+        1 => inner.remove(0),
+        _ => TsType::Intersection(inner),
+    };
+
+    let (generics, ty) = match decl.generics.is_empty() {
+        true => (String::new(), decl.name.clone()),
+        false => {
+            let names = decl.generics.iter().map(|g| g.name.as_str());
+            let names = names.collect::<Vec<_>>().join(", ");
+            (format!("<{names}>"), format!("{}<{names}>", decl.name))
+        }
+    };
+
+    Some(format!(
+        "export const {} = {generics}(value: {inner}): {ty} => value as {ty};",
+        decl.name
+    ))
 }
 
 /// Push an import statement for all dependencies of `T`.
+/// Values used by the declaration `decl`, like the type guards or Zod schemas of dependencies,
+/// are imported as well.
 fn generate_imports<T: TS + ?Sized + 'static>(
     cfg: &Config,
     out: &mut String,
+    decl: &str,
 ) -> Result<(), ExportError> {
     let path = <T as crate::TS>::output_path()
         .ok_or_else(std::any::type_name::<T>)
         .map(|x| cfg.export_dir.join(x))
         .map_err(ExportError::CannotBeExported)?;
 
-    let deps = <T as crate::TS>::dependencies(cfg);
+    let deps = <T as crate::TS>::dependencies(cfg)
+        .into_iter()
+        .filter(|dep| dep.type_id != TypeId::of::<T>())
+        .collect();
+    write_imports(cfg, &path, deps, out, decl)
+}
+
+/// Push an import statement for the dependencies `deps` of the declaration `decl`, which is
+/// exported into the file `path`.
+fn write_imports(
+    cfg: &Config,
+    path: &Path,
+    deps: Vec<Dependency>,
+    out: &mut String,
+    decl: &str,
+) -> Result<(), ExportError> {
     let deduplicated_deps = deps
         .iter()
-        .filter(|dep| dep.type_id != TypeId::of::<T>())
+        // when bundling all types into a single file, there is nothing to import
+        .filter(|_| cfg.single_file().is_none())
         .map(|dep| (&dep.ts_name, dep))
         .collect::<BTreeMap<_, _>>();
 
-    let mut imports: BTreeMap<String, BTreeSet<&str>> = Default::default();
+    // types are imported using `import type`, values like type guards using `import`
+    let mut imports: BTreeMap<(String, bool), BTreeSet<String>> = Default::default();
+
+    #[cfg(feature = "zod")]
+    let zod = cfg.zod();
+    #[cfg(not(feature = "zod"))]
+    let zod = false;
+
+    if zod {
+        imports.insert(("zod".to_owned(), true), ["z".to_owned()].into());
+    }
 
     for (_, dep) in deduplicated_deps {
         let dep_path = cfg.export_dir.join(&dep.output_path);
-        let rel_path = import_path(cfg, &path, &dep_path)?;
+        let rel_path = import_path(cfg, path, &dep_path)?;
 
         let is_same_file = path
             .file_name()
@@ -357,32 +614,61 @@ fn generate_imports<T: TS + ?Sized + 'static>(
             continue;
         }
 
-        imports.entry(rel_path).or_default().insert(&dep.ts_name);
-    }
-
-    for (path, types) in imports {
-        write!(out, "import type {{ ")?;
+        let values = [format!("is{}", dep.ts_name), format!("{}Schema", dep.ts_name)];
+        for value in values {
+            if mentions(decl, &value) {
+                let entry = imports.entry((rel_path.clone(), true)).or_default();
+                entry.insert(value);
+            }
+        }
 
-        let mut types = types.iter().peekable();
+        // the type of a Zod schema is usually inferred, so the types it depends on are not used
+        if zod && !mentions(decl, &dep.ts_name) {
+            continue;
+        }
 
-        while let Some(ty) = types.next() {
-            write!(out, "{ty}")?;
+        let entry = imports.entry((rel_path, false)).or_default();
+        entry.insert(dep.ts_name.clone());
+    }
 
-            if types.peek().is_some() {
-                write!(out, ", ")?;
-            }
+    for ((path, is_value), names) in imports {
+        let names = names.into_iter().collect::<Vec<_>>().join(", ");
+        match is_value {
+            true => writeln!(out, r#"import {{ {names} }} from "{path}";"#)?,
+            false => writeln!(out, r#"import type {{ {names} }} from "{path}";"#)?,
         }
-
-        writeln!(out, r#" }} from "{path}";"#)?;
     }
 
     writeln!(out)?;
     Ok(())
 }
 
+/// Returns `true` if the identifier `ident` occurs in `code`
+fn mentions(code: &str, ident: &str) -> bool {
+    let is_ident_char = |c: char| c.is_alphanumeric() || c == '_' || c == '$';
+    code.match_indices(ident).any(|(i, _)| {
+        let before = code[..i].chars().next_back();
+        let after = code[i + ident.len()..].chars().next();
+        !before.is_some_and(is_ident_char) && !after.is_some_and(is_ident_char)
+    })
+}
+
 /// Returns the required import path for importing `import` from the file `from`
 fn import_path(cfg: &Config, from: &Path, import: &Path) -> Result<String, ExportError> {
-    let rel_path = diff_paths(import, from.parent().unwrap())?;
+    let path = relative_path(from, import)?;
+
+    let mut path = path.trim_end_matches(".ts").to_owned();
+    if let Some(ext) = cfg.import_extension() {
+        path.push('.');
+        path.push_str(ext);
+    }
+
+    Ok(path)
+}
+
+/// Returns the path of the file `to`, relative to the file `from`, e.g. `./User.ts` or `../User.ts`
+pub(crate) fn relative_path(from: &Path, to: &Path) -> Result<String, ExportError> {
+    let rel_path = diff_paths(to, from.parent().unwrap())?;
     let str_path = match rel_path.components().next() {
         Some(Component::Normal(_)) => {
             format!("./{}", rel_path.to_string_lossy())
@@ -390,17 +676,9 @@ fn import_path(cfg: &Config, from: &Path, import: &Path) -> Result<String, Expor
         _ => rel_path.to_string_lossy().into(),
     };
 
-    let path = if cfg!(target_os = "windows") {
+    Ok(if cfg!(target_os = "windows") {
         str_path.replace('\\', "/")
     } else {
         str_path
-    };
-
-    let mut path = path.trim_end_matches(".ts").to_owned();
-    if let Some(ext) = cfg.import_extension() {
-        path.push('.');
-        path.push_str(ext);
-    }
-
-    Ok(path)
+    })
 }
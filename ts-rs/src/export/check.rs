@@ -0,0 +1,172 @@
+use std::{
+    collections::{HashMap, HashSet},
+    path::{Path, PathBuf},
+    sync::{Mutex, OnceLock},
+};
+
+use super::{format, merge, merge::File, path, ExportError};
+use crate::Config;
+
+// the types checked and the expected contents of every file checked so far
+type Checked = HashMap<PathBuf, (HashSet<String>, String)>;
+static CHECKED: OnceLock<Mutex<Checked>> = OnceLock::new();
+
+/// Returns the paths of all files checked by this process
+pub(super) fn checked_paths() -> Vec<PathBuf> {
+    let checked = CHECKED.get_or_init(Default::default).lock().unwrap();
+    checked.keys().cloned().collect()
+}
+
+/// Instead of writing `generated_type` into the file at `path`, compares what the file would
+/// contain to its contents on disk.
+pub(super) fn check_and_merge(
+    cfg: &Config,
+    path: PathBuf,
+    type_name: String,
+    generated_type: String,
+) -> Result<(), ExportError> {
+    let mut checked = CHECKED.get_or_init(Default::default).lock().unwrap();
+
+    let contents = match checked.get_mut(&path) {
+        Some((types, _)) if types.contains(&type_name) => return Ok(()),
+        Some((types, original)) => {
+            let merged = merge(&path, original, &generated_type)?;
+            let merged = format(cfg, &path, &type_name, merged)?;
+            types.insert(type_name);
+            (std::mem::take(types), merged)
+        }
+        None => (HashSet::from([type_name]), generated_type),
+    };
+
+    let result = check(&path, &contents.1);
+    checked.insert(path, contents);
+    result
+}
+
+/// Instead of writing the file at `path`, which is generated as a whole, compares `expected` to
+/// its contents on disk.
+pub(super) fn check_file(path: PathBuf, expected: &str) -> Result<(), ExportError> {
+    let mut checked = CHECKED.get_or_init(Default::default).lock().unwrap();
+
+    let actual = std::fs::read_to_string(&path).unwrap_or_default();
+    let result = match actual == expected {
+        true => Ok(()),
+        false => Err(ExportError::OutOfDate {
+            path: path.clone(),
+            diff: diff(&actual, expected),
+        }),
+    };
+
+    checked.insert(path, (HashSet::new(), expected.to_owned()));
+    result
+}
+
+/// Checks that every file in the output directory which was checked by this process contains
+/// exactly the bindings of the types checked, returning [`ExportError::OutOfDate`] for the first
+/// file containing declarations or imports which are no longer generated. Afterwards, files which
+/// are no longer generated at all, see [`stale_files`], are reported as [`ExportError::Stale`].
+///
+/// While checking a type, other types exported into the same file might not have been checked
+/// yet, so the file may contain additional declarations. This should therefore be called after
+/// checking all types using [`Config::with_check_only`], like [`stale_files`] is.
+/// `export_registered` does so on its own.
+///
+/// [`stale_files`]: crate::stale_files
+pub fn check_files(cfg: &Config) -> Result<(), ExportError> {
+    let dir = path::absolute(cfg.out_dir())?;
+    let checked = CHECKED.get_or_init(Default::default).lock().unwrap();
+
+    let mut paths = checked
+        .keys()
+        .filter(|path| path.starts_with(&dir))
+        .collect::<Vec<_>>();
+    paths.sort();
+
+    for path in paths {
+        let (_, expected) = &checked[path];
+        let actual = std::fs::read_to_string(path).unwrap_or_default();
+        if actual == *expected || (contains(&actual, expected) && contains(expected, &actual)) {
+            continue;
+        }
+
+        return Err(ExportError::OutOfDate {
+            path: path.clone(),
+            diff: diff(&actual, expected),
+        });
+    }
+    drop(checked);
+
+    let paths = super::stale_files(cfg)?;
+    match paths.is_empty() {
+        true => Ok(()),
+        false => Err(ExportError::Stale { paths }),
+    }
+}
+
+/// Checks that the file at `path` contains everything in `expected`.
+/// Other types exported into the same file might not have been checked yet, so the file may
+/// contain additional imports and declarations.
+fn check(path: &Path, expected: &str) -> Result<(), ExportError> {
+    let actual = std::fs::read_to_string(path).unwrap_or_default();
+
+    if actual == expected || contains(&actual, expected) {
+        return Ok(());
+    }
+
+    Err(ExportError::OutOfDate {
+        path: path.to_owned(),
+        diff: diff(&actual, expected),
+    })
+}
+
+/// Returns `true` if all imports and declarations of the file `expected` are part of `actual`.
+fn contains(actual: &str, expected: &str) -> bool {
+    let (Ok(actual), Ok(expected)) = (File::parse(actual), File::parse(expected)) else {
+        return false;
+    };
+
+    let imports = expected.imports.iter().all(|(key, names)| {
+        let actual = actual.imports.get(key);
+        actual.is_some_and(|actual| actual.is_superset(names))
+    });
+    imports
+        && expected
+            .units
+            .iter()
+            .all(|unit| actual.units.contains(unit))
+}
+
+/// Returns a line based diff between the file on disk and the expected contents.
+/// Lines which are missing from the file are prefixed with `+`, and those which should not be
+/// there with `-`.
+fn diff(actual: &str, expected: &str) -> String {
+    let actual = actual.lines().collect::<Vec<_>>();
+    let expected = expected.lines().collect::<Vec<_>>();
+
+    // lengths of the longest common subsequences of all suffixes
+    let mut lcs = vec![vec![0usize; expected.len() + 1]; actual.len() + 1];
+    for i in (0..actual.len()).rev() {
+        for j in (0..expected.len()).rev() {
+            lcs[i][j] = match actual[i] == expected[j] {
+                true => lcs[i + 1][j + 1] + 1,
+                false => lcs[i + 1][j].max(lcs[i][j + 1]),
+            };
+        }
+    }
+
+    let mut diff = String::new();
+    let (mut i, mut j) = (0, 0);
+    while i < actual.len() || j < expected.len() {
+        if i < actual.len() && j < expected.len() && actual[i] == expected[j] {
+            i += 1;
+            j += 1;
+        } else if j == expected.len() || (i < actual.len() && lcs[i + 1][j] >= lcs[i][j + 1]) {
+            diff.push_str(&format!("-{}\n", actual[i]));
+            i += 1;
+        } else {
+            diff.push_str(&format!("+{}\n", expected[j]));
+            j += 1;
+        }
+    }
+    diff
+}
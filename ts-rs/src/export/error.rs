@@ -4,14 +4,57 @@ pub enum ExportError {
     #[error("this type cannot be exported")]
     CannotBeExported(&'static str),
     #[cfg(feature = "format")]
-    #[error("an error occurred while formatting the generated typescript output")]
-    Formatting(String),
+    #[error("the bindings of {name} could not be formatted in {}: {reason}", path.display())]
+    Formatting {
+        /// The file into which the bindings were exported
+        path: std::path::PathBuf,
+        /// The type or value whose bindings could not be formatted
+        name: String,
+        /// The error reported by the formatter
+        reason: String,
+    },
     #[error("an error occurred while performing IO")]
     Io(#[from] std::io::Error),
     #[error("the environment variable CARGO_MANIFEST_DIR is not set")]
     ManifestDirNotSet,
     #[error("an error occurred while writing to a formatted buffer")]
     Fmt(#[from] std::fmt::Error),
+    #[cfg(feature = "values")]
+    #[error("the value could not be serialized: {0}")]
+    Serialize(#[from] ::serde_json::Error),
     #[error(r#"TS_RS_IMPORT_EXTENSION must be either "js" or "ts""#)]
     InvalidImportExtension,
+    #[error(
+        "the configuration{} is invalid: {reason}",
+        path.as_ref().map(|path| format!(" in {}", path.display())).unwrap_or_default()
+    )]
+    InvalidConfig {
+        /// The configuration file, or `None` if an environment variable is invalid
+        path: Option<std::path::PathBuf>,
+        /// What is wrong with the file
+        reason: String,
+    },
+    #[error("the bindings could not be merged into {}: {reason}", path.display())]
+    Merge {
+        /// The file into which the bindings were exported
+        path: std::path::PathBuf,
+        /// Why the contents of the file could not be parsed
+        reason: &'static str,
+    },
+    #[error("the bindings in {} are out of date:\n{diff}", path.display())]
+    OutOfDate {
+        /// The file which is out of date
+        path: std::path::PathBuf,
+        /// A diff between the file and the generated bindings. Lines which are missing from
+        /// the file start with `+`, lines which should be removed start with `-`.
+        diff: String,
+    },
+    #[error(
+        "the bindings in {} are no longer generated",
+        paths.iter().map(|path| path.display().to_string()).collect::<Vec<_>>().join(", ")
+    )]
+    Stale {
+        /// The files which were generated by ts-rs, but are no longer, see `stale_files`
+        paths: Vec<std::path::PathBuf>,
+    },
 }
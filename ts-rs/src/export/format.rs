@@ -0,0 +1,365 @@
+use std::path::Path;
+
+#[cfg(feature = "format")]
+pub use options::{FormatOptions, QuoteStyle};
+
+use super::ExportError;
+use crate::Config;
+
+/// Formats the bindings of `name`, a type or value, to be exported into `path`, if the `format`
+/// feature is enabled
+#[allow(unused_variables)]
+pub(super) fn format(
+    cfg: &Config,
+    path: &Path,
+    name: &str,
+    buffer: String,
+) -> Result<String, ExportError> {
+    #[cfg(feature = "format")]
+    {
+        use dprint_plugin_typescript::{format_text, FormatTextOptions};
+
+        let fmt_cfg = cfg.formatting().dprint();
+        let options = FormatTextOptions {
+            config: &fmt_cfg,
+            path,
+            text: buffer.clone(),
+            extension: None,
+            external_formatter: None,
+        };
+        let formatted = format_text(options).map_err(|e| ExportError::Formatting {
+            path: path.to_owned(),
+            name: name.to_owned(),
+            reason: e.to_string(),
+        })?;
+        if let Some(formatted) = formatted {
+            return Ok(formatted);
+        }
+    }
+
+    Ok(buffer)
+}
+
+#[cfg(feature = "format")]
+mod options {
+    use std::path::Path;
+
+    use ::serde_json::{Map, Value};
+    use dprint_plugin_typescript::configuration::{
+        self as dprint, Configuration, ConfigurationBuilder,
+    };
+
+    use crate::ExportError;
+
+    /// Options of the formatter used by the `format` feature, see [`Config::with_formatting`].
+    ///
+    /// The defaults follow the style of `deno fmt`: 2-space indentation, double quotes,
+    /// semicolons, trailing commas in multi-line lists and a line width of 80.
+    ///
+    /// [`Config::with_formatting`]: crate::Config::with_formatting
+    #[derive(Clone, Debug, PartialEq, Eq)]
+    pub struct FormatOptions {
+        indent_width: u8,
+        use_tabs: bool,
+        line_width: u32,
+        quote_style: QuoteStyle,
+        semicolons: bool,
+        trailing_commas: bool,
+    }
+
+    /// The quotes used for strings and import paths, see [`FormatOptions::with_quote_style`]
+    #[derive(Copy, Clone, Debug, Default, PartialEq, Eq, Hash)]
+    pub enum QuoteStyle {
+        /// `"double"`, unless a string contains more double quotes than single quotes
+        #[default]
+        Double,
+        /// `'single'`, unless a string contains more single quotes than double quotes
+        Single,
+    }
+
+    impl Default for FormatOptions {
+        fn default() -> Self {
+            Self {
+                indent_width: 2,
+                use_tabs: false,
+                line_width: 80,
+                quote_style: QuoteStyle::Double,
+                semicolons: true,
+                trailing_commas: true,
+            }
+        }
+    }
+
+    impl FormatOptions {
+        /// Creates new `FormatOptions` with default values.
+        pub fn new() -> Self {
+            Self::default()
+        }
+
+        /// Reads the options from a [dprint](https://dprint.dev) configuration file, e.g.
+        /// `dprint.json`.
+        ///
+        /// `indentWidth`, `useTabs` and `lineWidth` are read from the top level of the file and
+        /// the `typescript` section, together with `quoteStyle`, `semiColons` and
+        /// `trailingCommas`. Other options are ignored, and options which are not set keep
+        /// their default value.
+        pub fn from_dprint_json(path: impl AsRef<Path>) -> Result<Self, ExportError> {
+            let path = path.as_ref();
+            let error = |reason: String| ExportError::InvalidConfig {
+                path: Some(path.to_owned()),
+                reason,
+            };
+
+            let contents = std::fs::read_to_string(path)?;
+            let json = ::serde_json::from_str::<Map<String, Value>>(&strip_comments(&contents))
+                .map_err(|e| error(e.to_string()))?;
+
+            let mut options = Self::default();
+            options.apply_dprint(&json).map_err(error)?;
+            if let Some(typescript) = json.get("typescript") {
+                let typescript = typescript
+                    .as_object()
+                    .ok_or_else(|| error("`typescript` must be an object".to_owned()))?;
+                options.apply_dprint(typescript).map_err(error)?;
+            }
+
+            Ok(options)
+        }
+
+        /// Reads the options from an [EditorConfig](https://editorconfig.org) file, using the
+        /// sections which apply to `.ts` files.
+        ///
+        /// `indent_style`, `indent_size`, `tab_width` and `max_line_length` are supported, as
+        /// well as `quote_type`, which is understood by some editors. Options which are not set
+        /// keep their default value.
+        pub fn from_editorconfig(path: impl AsRef<Path>) -> Result<Self, ExportError> {
+            let path = path.as_ref();
+            let error = |reason: String| ExportError::InvalidConfig {
+                path: Some(path.to_owned()),
+                reason,
+            };
+
+            let contents = std::fs::read_to_string(path)?;
+            let mut options = Self::default();
+            let mut applies = false;
+            let mut indent_size = None;
+
+            for line in contents.lines().map(str::trim) {
+                if line.is_empty() || line.starts_with(['#', ';']) {
+                    continue;
+                }
+                if let Some(section) = line.strip_prefix('[') {
+                    let glob = section.strip_suffix(']');
+                    let glob = glob.ok_or_else(|| error(format!("invalid section `{line}`")))?;
+                    applies = matches_ts(glob);
+                    continue;
+                }
+                if !applies {
+                    continue;
+                }
+
+                let (key, value) = line
+                    .split_once('=')
+                    .ok_or_else(|| error(format!("invalid property `{line}`")))?;
+                let key = key.trim().to_lowercase();
+                let value = value.trim().to_lowercase();
+                let invalid = || error(format!("invalid value `{value}` for `{key}`"));
+
+                match key.as_str() {
+                    "indent_style" => match value.as_str() {
+                        "tab" => options.use_tabs = true,
+                        "space" => options.use_tabs = false,
+                        _ => return Err(invalid()),
+                    },
+                    "indent_size" if value == "tab" => indent_size = None,
+                    "indent_size" => indent_size = Some(value.parse().map_err(|_| invalid())?),
+                    "tab_width" if indent_size.is_none() => {
+                        options.indent_width = value.parse().map_err(|_| invalid())?
+                    }
+                    "max_line_length" if value == "off" => (),
+                    "max_line_length" => {
+                        options.line_width = value.parse().map_err(|_| invalid())?
+                    }
+                    "quote_type" => match value.as_str() {
+                        "double" | "auto" => options.quote_style = QuoteStyle::Double,
+                        "single" => options.quote_style = QuoteStyle::Single,
+                        _ => return Err(invalid()),
+                    },
+                    _ => (),
+                }
+            }
+
+            if let Some(indent_size) = indent_size {
+                options.indent_width = indent_size;
+            }
+
+            Ok(options)
+        }
+
+        /// Sets the number of spaces used for indentation, or the width of a tab.
+        ///
+        /// Default: `2`
+        pub fn with_indent_width(mut self, width: u8) -> Self {
+            self.indent_width = width;
+            self
+        }
+
+        /// Indents using tabs instead of spaces.
+        ///
+        /// Default: disabled
+        pub fn with_tabs(mut self) -> Self {
+            self.use_tabs = true;
+            self
+        }
+
+        /// Sets the width after which lines are broken, if possible.
+        ///
+        /// Default: `80`
+        pub fn with_line_width(mut self, width: u32) -> Self {
+            self.line_width = width;
+            self
+        }
+
+        /// Sets the quotes used for strings and import paths.
+        ///
+        /// Default: [`QuoteStyle::Double`]
+        pub fn with_quote_style(mut self, style: QuoteStyle) -> Self {
+            self.quote_style = style;
+            self
+        }
+
+        /// Sets whether statements and members are terminated by semicolons. If disabled, they
+        /// are only used where they are required.
+        ///
+        /// Default: enabled
+        pub fn with_semicolons(mut self, semicolons: bool) -> Self {
+            self.semicolons = semicolons;
+            self
+        }
+
+        /// Sets whether the last element of a list which spans multiple lines is followed by a
+        /// comma.
+        ///
+        /// Default: enabled
+        pub fn with_trailing_commas(mut self, trailing_commas: bool) -> Self {
+            self.trailing_commas = trailing_commas;
+            self
+        }
+
+        /// Applies the options of a section of a dprint configuration file
+        fn apply_dprint(&mut self, json: &Map<String, Value>) -> Result<(), String> {
+            for (key, value) in json {
+                let invalid = || format!("invalid value `{value}` for `{key}`");
+                let as_u64 = || value.as_u64().ok_or_else(invalid);
+
+                match (key.as_str(), value) {
+                    ("indentWidth", _) => {
+                        self.indent_width = as_u64()?.try_into().map_err(|_| invalid())?
+                    }
+                    ("lineWidth", _) => {
+                        self.line_width = as_u64()?.try_into().map_err(|_| invalid())?
+                    }
+                    ("useTabs", _) => self.use_tabs = value.as_bool().ok_or_else(invalid)?,
+                    ("quoteStyle", Value::String(style)) => {
+                        self.quote_style = match style.as_str() {
+                            "alwaysDouble" | "preferDouble" => QuoteStyle::Double,
+                            "alwaysSingle" | "preferSingle" => QuoteStyle::Single,
+                            _ => return Err(invalid()),
+                        }
+                    }
+                    ("semiColons", Value::String(semicolons)) => {
+                        self.semicolons = match semicolons.as_str() {
+                            "always" | "prefer" => true,
+                            "asi" => false,
+                            _ => return Err(invalid()),
+                        }
+                    }
+                    ("trailingCommas", Value::String(commas)) => {
+                        self.trailing_commas = match commas.as_str() {
+                            "always" | "onlyMultiLine" => true,
+                            "never" => false,
+                            _ => return Err(invalid()),
+                        }
+                    }
+                    ("quoteStyle" | "semiColons" | "trailingCommas", _) => return Err(invalid()),
+                    _ => (),
+                }
+            }
+
+            Ok(())
+        }
+
+        /// Returns the configuration of `dprint-plugin-typescript`
+        pub(crate) fn dprint(&self) -> Configuration {
+            ConfigurationBuilder::new()
+                .deno()
+                .indent_width(self.indent_width)
+                .use_tabs(self.use_tabs)
+                .line_width(self.line_width)
+                .quote_style(match self.quote_style {
+                    QuoteStyle::Double => dprint::QuoteStyle::PreferDouble,
+                    QuoteStyle::Single => dprint::QuoteStyle::PreferSingle,
+                })
+                .semi_colons(match self.semicolons {
+                    true => dprint::SemiColons::Prefer,
+                    false => dprint::SemiColons::Asi,
+                })
+                .trailing_commas(match self.trailing_commas {
+                    true => dprint::TrailingCommas::OnlyMultiLine,
+                    false => dprint::TrailingCommas::Never,
+                })
+                .build()
+        }
+    }
+
+    /// Returns `true` if the glob of an EditorConfig section, e.g. `*`, `*.ts` or
+    /// `**/*.{js,ts}`, matches TypeScript files
+    fn matches_ts(glob: &str) -> bool {
+        let glob = glob.rsplit('/').next().unwrap_or(glob);
+        match glob.trim_start_matches('*') {
+            "" => true,
+            extension => match extension.strip_prefix('.') {
+                Some(extension) => match extension.strip_prefix('{') {
+                    Some(list) => list.trim_end_matches('}').split(',').any(|e| e == "ts"),
+                    None => extension == "ts",
+                },
+                None => false,
+            },
+        }
+    }
+
+    /// Removes `//` and `/* */` comments, which dprint allows in its configuration files
+    fn strip_comments(json: &str) -> String {
+        let mut out = String::with_capacity(json.len());
+        let mut chars = json.chars().peekable();
+        let mut in_string = false;
+
+        while let Some(c) = chars.next() {
+            match (c, chars.peek()) {
+                ('"', _) => in_string = !in_string,
+                ('\\', Some(_)) if in_string => {
+                    out.push(c);
+                    out.extend(chars.next());
+                    continue;
+                }
+                ('/', Some('/')) if !in_string => {
+                    chars.by_ref().find(|c| *c == '\n');
+                    out.push('\n');
+                    continue;
+                }
+                ('/', Some('*')) if !in_string => {
+                    chars.next();
+                    let mut last = ' ';
+                    chars
+                        .by_ref()
+                        .find(|c| std::mem::replace(&mut last, *c) == '*' && *c == '/');
+                    continue;
+                }
+                _ => (),
+            }
+            out.push(c);
+        }
+
+        out
+    }
+}
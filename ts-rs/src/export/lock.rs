@@ -0,0 +1,79 @@
+use std::{
+    fs::OpenOptions,
+    io::{ErrorKind, Write},
+    path::{Path, PathBuf},
+    time::{Duration, SystemTime},
+};
+
+use super::ExportError;
+
+// a lock which has been held for longer than this was left behind by a process which was killed
+const STALE_AFTER: Duration = Duration::from_secs(30);
+
+/// A lock on a file, shared between all processes exporting into it, e.g. the test binaries of
+/// a crate or of the crates in a workspace. It is released when dropped.
+///
+/// The lock is held by the process which created the lock file `<file>.lock` next to the file.
+pub(super) struct FileLock {
+    path: PathBuf,
+}
+
+impl FileLock {
+    /// Blocks until the lock on `file` has been acquired
+    pub(super) fn acquire(file: &Path) -> Result<Self, ExportError> {
+        let path = sibling(file, ".lock");
+
+        loop {
+            match OpenOptions::new().write(true).create_new(true).open(&path) {
+                Ok(_) => return Ok(Self { path }),
+                Err(e) if e.kind() == ErrorKind::AlreadyExists => {
+                    if is_stale(&path) {
+                        let _ = std::fs::remove_file(&path);
+                    } else {
+                        std::thread::sleep(Duration::from_millis(5));
+                    }
+                }
+                Err(e) => return Err(e.into()),
+            }
+        }
+    }
+}
+
+impl Drop for FileLock {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.path);
+    }
+}
+
+fn is_stale(lock: &Path) -> bool {
+    let Ok(modified) = std::fs::metadata(lock).and_then(|m| m.modified()) else {
+        return false;
+    };
+    SystemTime::now()
+        .duration_since(modified)
+        .is_ok_and(|age| age > STALE_AFTER)
+}
+
+/// Replaces the contents of `file` by writing them to a temporary file first, which is then
+/// renamed. Other processes reading the file never observe partially written contents.
+pub(super) fn write_atomic(file: &Path, contents: &str) -> Result<(), ExportError> {
+    let tmp = sibling(file, &format!(".{}.tmp", std::process::id()));
+
+    let result = (|| {
+        let mut tmp_file = std::fs::File::create(&tmp)?;
+        tmp_file.write_all(contents.as_bytes())?;
+        tmp_file.sync_all()?;
+        std::fs::rename(&tmp, file)
+    })();
+
+    if result.is_err() {
+        let _ = std::fs::remove_file(&tmp);
+    }
+    Ok(result?)
+}
+
+fn sibling(file: &Path, suffix: &str) -> PathBuf {
+    let mut name = file.file_name().unwrap_or_default().to_owned();
+    name.push(suffix);
+    file.with_file_name(name)
+}
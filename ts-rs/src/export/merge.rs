@@ -0,0 +1,315 @@
+use std::{
+    collections::{BTreeMap, BTreeSet},
+    fmt::{Display, Formatter},
+    path::Path,
+};
+
+use super::{ExportError, NOTE};
+
+/// Merges the bindings `new` into the contents of the file at `path`.
+///
+/// Imports are combined and sorted, declarations are kept in alphabetical order. Declarations
+/// in the file which are part of `new` are replaced.
+pub(super) fn merge(path: &Path, original: &str, new: &str) -> Result<String, ExportError> {
+    let error = |reason| ExportError::Merge {
+        path: path.to_owned(),
+        reason,
+    };
+
+    let mut file = File::parse(original).map_err(error)?;
+    file.merge(File::parse(new).map_err(error)?);
+    Ok(file.to_string())
+}
+
+/// The contents of a file generated by ts-rs
+#[derive(Default)]
+pub(super) struct File<'a> {
+    // `(import path, is_value)` => names
+    pub imports: BTreeMap<(&'a str, bool), BTreeSet<&'a str>>,
+    pub units: Vec<Unit<'a>>,
+}
+
+/// Declarations exported together for one type, e.g. a type alias followed by its type guard.
+/// Units are separated by a blank line.
+#[derive(PartialEq, Eq)]
+pub(super) struct Unit<'a> {
+    pub name: &'a str,
+    pub statements: Vec<&'a str>,
+}
+
+impl<'a> File<'a> {
+    pub fn parse(contents: &'a str) -> Result<Self, &'static str> {
+        let contents = contents.strip_prefix(NOTE).unwrap_or(contents);
+
+        let mut file = File::default();
+        for statement in Lexer::new(contents).statements() {
+            let Statement { text, after_blank } = statement?;
+            let (keywords, _) = keywords(text);
+
+            if keywords.first() == Some(&"import") {
+                let (names, from, is_value) = parse_import(text).ok_or("invalid import")?;
+                let entry = file.imports.entry((from, is_value)).or_default();
+                entry.extend(names);
+                continue;
+            }
+
+            match file.units.last_mut() {
+                Some(unit) if !after_blank => unit.statements.push(text),
+                _ => file.units.push(Unit {
+                    name: "",
+                    statements: vec![text],
+                }),
+            }
+        }
+
+        for unit in &mut file.units {
+            unit.name = unit_name(&unit.statements).ok_or("declaration without a name")?;
+        }
+
+        Ok(file)
+    }
+
+    /// Adds the imports and declarations of `other`, replacing units with the same name.
+    /// Imports which were only used by the replaced units are removed.
+    pub fn merge(&mut self, other: File<'a>) {
+        for (key, names) in other.imports {
+            self.imports.entry(key).or_default().extend(names);
+        }
+
+        for unit in other.units {
+            self.units.retain(|u| u.name != unit.name);
+            let index = self
+                .units
+                .iter()
+                .position(|u| u.name > unit.name)
+                .unwrap_or(self.units.len());
+            self.units.insert(index, unit);
+        }
+
+        let statements = self.units.iter().flat_map(|unit| &unit.statements);
+        let statements = statements.collect::<Vec<_>>();
+        for names in self.imports.values_mut() {
+            names.retain(|name| statements.iter().any(|s| mentions(s, name)));
+        }
+    }
+}
+
+impl Display for File<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(NOTE)?;
+
+        for ((from, is_value), names) in &self.imports {
+            if names.is_empty() {
+                continue;
+            }
+
+            let names = names.iter().copied().collect::<Vec<_>>().join(", ");
+            match is_value {
+                true => writeln!(f, r#"import {{ {names} }} from "{from}";"#)?,
+                false => writeln!(f, r#"import type {{ {names} }} from "{from}";"#)?,
+            }
+        }
+
+        for unit in &self.units {
+            writeln!(f)?;
+            writeln!(f, "{}", unit.statements.join("\n"))?;
+        }
+
+        Ok(())
+    }
+}
+
+/// Returns `true` if `statement` contains the identifier `name`
+fn mentions(statement: &str, name: &str) -> bool {
+    let is_ident = |c: char| c.is_alphanumeric() || c == '_' || c == '$';
+    statement.match_indices(name).any(|(i, _)| {
+        let before = statement[..i].chars().next_back();
+        let after = statement[i + name.len()..].chars().next();
+        !before.is_some_and(is_ident) && !after.is_some_and(is_ident)
+    })
+}
+
+/// Returns the name of the type declared by a unit, e.g. `User` for
+/// `export type User = ..; export function isUser(..) { .. }`.
+fn unit_name<'a>(statements: &[&'a str]) -> Option<&'a str> {
+    let declared = statements.iter().map(|s| keywords(s)).collect::<Vec<_>>();
+
+    declared
+        .iter()
+        .find(|(keywords, _)| {
+            keywords
+                .iter()
+                .any(|k| matches!(*k, "type" | "enum" | "interface" | "class"))
+        })
+        .or(declared.first())
+        .and_then(|(_, name)| *name)
+}
+
+/// Returns the leading keywords of a statement, skipping comments, and the name it declares
+fn keywords(statement: &str) -> (Vec<&str>, Option<&str>) {
+    let mut rest = statement.trim_start();
+    loop {
+        if let Some(comment) = rest.strip_prefix("/*") {
+            rest = comment.split_once("*/").map_or("", |(_, r)| r).trim_start();
+        } else if let Some(comment) = rest.strip_prefix("//") {
+            rest = comment.split_once('\n').map_or("", |(_, r)| r).trim_start();
+        } else {
+            break;
+        }
+    }
+
+    let mut keywords = vec![];
+    let words = rest.split(|c: char| !(c.is_alphanumeric() || c == '_' || c == '$'));
+    for word in words.filter(|w| !w.is_empty()) {
+        match word {
+            "export" | "declare" | "default" | "const" | "let" | "var" | "async" | "import"
+            | "type" | "enum" | "interface" | "class" | "function" | "namespace" => {
+                keywords.push(word);
+                // `import type { .. }` and `export type X`
+                if word == "import" {
+                    return (keywords, None);
+                }
+            }
+            name => return (keywords, Some(name)),
+        }
+    }
+    (keywords, None)
+}
+
+/// Parses `import { A, B } from "./path";` and `import type { .. } from "..";`
+fn parse_import(statement: &str) -> Option<(impl Iterator<Item = &str>, &str, bool)> {
+    let (_, rest) = statement.split_once("import")?;
+    let (kind, rest) = rest.split_once('{')?;
+    let (names, rest) = rest.split_once('}')?;
+    let (_, from) = rest.split_once("from")?;
+
+    let from = from.trim().trim_end_matches(';').trim_end();
+    let from = from.strip_prefix(['"', '\''])?.strip_suffix(['"', '\''])?;
+    let names = names.split(',').map(str::trim).filter(|n| !n.is_empty());
+
+    Some((names, from, kind.trim() != "type"))
+}
+
+struct Statement<'a> {
+    // the statement, including leading comments
+    text: &'a str,
+    // whether the statement is separated from the previous one by a blank line
+    after_blank: bool,
+}
+
+/// Splits the contents of a file into top-level statements, skipping over strings and comments.
+struct Lexer<'a> {
+    src: &'a str,
+    pos: usize,
+}
+
+impl<'a> Lexer<'a> {
+    fn new(src: &'a str) -> Self {
+        Self { src, pos: 0 }
+    }
+
+    fn statements(mut self) -> impl Iterator<Item = Result<Statement<'a>, &'static str>> {
+        std::iter::from_fn(move || self.next_statement().transpose())
+    }
+
+    fn next_statement(&mut self) -> Result<Option<Statement<'a>>, &'static str> {
+        let whitespace = &self.src[self.pos..];
+        let text = whitespace.trim_start();
+        let after_blank = whitespace[..whitespace.len() - text.len()]
+            .matches('\n')
+            .count()
+            > 1;
+        self.pos = self.src.len() - text.len();
+
+        if text.is_empty() {
+            return Ok(None);
+        }
+
+        let start = self.pos;
+        let bytes = self.src.as_bytes();
+        let mut depth = 0usize;
+
+        while let Some(&c) = bytes.get(self.pos) {
+            self.pos += 1;
+            match c {
+                b'/' if bytes.get(self.pos) == Some(&b'/') => {
+                    self.skip_past("\n");
+                }
+                b'/' if bytes.get(self.pos) == Some(&b'*') => {
+                    self.pos += 1;
+                    if !self.skip_past("*/") {
+                        return Err("unterminated comment");
+                    }
+                }
+                b'"' | b'\'' | b'`' => self.skip_string(c)?,
+                b'{' | b'(' | b'[' => depth += 1,
+                b'}' | b')' | b']' => {
+                    depth = depth.checked_sub(1).ok_or("unbalanced brackets")?;
+
+                    // declarations with a body, e.g. `export enum X { .. }`, are not terminated
+                    let (keywords, _) = keywords(&self.src[start..self.pos]);
+                    let has_body = keywords.iter().any(|k| {
+                        matches!(
+                            *k,
+                            "enum" | "function" | "interface" | "class" | "namespace"
+                        )
+                    });
+                    if depth == 0 && c == b'}' && has_body {
+                        break;
+                    }
+                }
+                b';' if depth == 0 => break,
+                b'\n' if depth == 0 && self.at_statement(start) => break,
+                _ => (),
+            }
+        }
+
+        if depth != 0 {
+            return Err("unterminated declaration");
+        }
+
+        // line breaks after the statement separate it from the next one
+        let text = self.src[start..self.pos].trim_end();
+        self.pos = start + text.len();
+
+        Ok(Some(Statement { text, after_blank }))
+    }
+
+    /// Returns whether a new statement starts at the current position, which follows a line
+    /// break. Statements without semicolons, e.g. formatted using automatic semicolon
+    /// insertion, end at a line which is not indented.
+    fn at_statement(&self, start: usize) -> bool {
+        let starts_line = self.src[self.pos..]
+            .starts_with(|c: char| !c.is_whitespace() && !"})]|&.=?:,".contains(c));
+        // comments before a declaration belong to it
+        let (keywords, name) = keywords(&self.src[start..self.pos]);
+        starts_line && (!keywords.is_empty() || name.is_some())
+    }
+
+    /// Advances past the next occurrence of `end`, returning whether it was found
+    fn skip_past(&mut self, end: &str) -> bool {
+        match self.src[self.pos..].find(end) {
+            Some(i) => {
+                self.pos += i + end.len();
+                true
+            }
+            None => {
+                self.pos = self.src.len();
+                false
+            }
+        }
+    }
+
+    fn skip_string(&mut self, quote: u8) -> Result<(), &'static str> {
+        let bytes = self.src.as_bytes();
+        while let Some(&c) = bytes.get(self.pos) {
+            self.pos += 1;
+            match c {
+                b'\\' => self.pos += 1,
+                c if c == quote => return Ok(()),
+                _ => (),
+            }
+        }
+        Err("unterminated string")
+    }
+}
@@ -0,0 +1,422 @@
+//! Lays out declarations over multiple lines, see [`Layout::MultiLine`].
+//!
+//! The declarations generated by ts-rs are printed on a single line by [`Declaration`]'s
+//! `Display` implementation. Here, the same model is printed again, breaking the lists which do
+//! not fit on a line. Only whitespace differs from the single line declaration.
+//!
+//! [`Layout::MultiLine`]: crate::Layout::MultiLine
+
+use crate::ast::{jsdoc, Declaration, DeclarationKind, Keyword, Literal, Param, Property, TsType};
+
+/// Lays out the declaration `decl`, e.g. `type User = { id: number, };`, which is written
+/// starting at the column `start`, e.g. after `export `.
+///
+/// Objects which are the body of a declaration are always written with one property per line.
+/// Other lists, like nested objects, tuples or parameters, are only broken if they don't fit
+/// within `width` or contain docs. Type aliases of unions which don't fit are written with every
+/// variant on its own line.
+pub(super) fn pretty(decl: &Declaration, start: usize, indent: &str, width: usize) -> String {
+    let mut printer = Printer {
+        indent,
+        width,
+        out: String::new(),
+        col: start,
+    };
+    printer.declaration(decl);
+    printer.out
+}
+
+/// How the items of a list are separated
+#[derive(Copy, Clone, PartialEq)]
+enum Items {
+    /// Items followed by a comma, except for the last one, e.g. `[string, number]`
+    Separated,
+    /// Items which end with a comma themselves, e.g. the properties of `{ id: number, }`
+    Terminated,
+    /// The variants of a union, e.g. `("A" | "B")`, which are prefixed with `|` when broken
+    Union,
+}
+
+struct Printer<'a> {
+    indent: &'a str,
+    width: usize,
+    out: String,
+    // the column at which the next character is written
+    col: usize,
+}
+
+impl Printer<'_> {
+    fn write(&mut self, text: &str) {
+        self.out.push_str(text);
+        self.col = match text.rfind('\n') {
+            Some(i) => text[i + 1..].chars().count(),
+            None => self.col + text.chars().count(),
+        };
+    }
+
+    fn newline(&mut self, margin: &str) {
+        self.write("\n");
+        self.write(margin);
+    }
+
+    fn fits(&self, source: &str) -> bool {
+        self.col + source.chars().count() <= self.width
+    }
+
+    fn declaration(&mut self, decl: &Declaration) {
+        let name = &decl.name;
+        let generics = match decl.generics.as_slice() {
+            [] => String::new(),
+            generics => format!("<{}>", join(generics)),
+        };
+
+        match (&decl.kind, &decl.ty) {
+            (DeclarationKind::Interface, ty @ TsType::Object(_)) => {
+                self.write(&format!("interface {name}{generics} "));
+                self.ty(ty, "", true);
+            }
+            (DeclarationKind::Interface, TsType::Intersection(members))
+                if matches!(members.first(), Some(TsType::Object(_))) =>
+            {
+                let extends = join(&members[1..]);
+                self.write(&format!("interface {name}{generics} extends {extends} "));
+                self.ty(&members[0], "", true);
+            }
+            (DeclarationKind::Enum(members), _) => {
+                let members = members
+                    .iter()
+                    .map(|member| {
+                        let name = Literal::String(member.name.clone());
+                        match &member.value {
+                            Some(value) => format!("{name} = {value}"),
+                            None => name.to_string(),
+                        }
+                    })
+                    .collect::<Vec<_>>();
+                self.write(&format!("enum {name} "));
+                let source = format!("{{ {} }}", members.join(", "));
+                self.group(
+                    &source,
+                    &members,
+                    ("{", "}"),
+                    Items::Separated,
+                    "",
+                    true,
+                    |p, member, _| p.write(member),
+                );
+            }
+            (DeclarationKind::Function, TsType::Function { params, returns }) => {
+                self.write(&format!("declare function {name}{generics}"));
+                self.params(params, "");
+                self.write(": ");
+                self.ty(returns, "", false);
+                self.write(";");
+            }
+            (_, TsType::Union(variants)) if self.breaks_union(decl, variants) => {
+                // type E =
+                //   | "A"
+                //   | { B: number, };
+                self.write(&format!("type {name}{generics} ="));
+                let margin = format!("{}  ", self.indent);
+                for variant in variants {
+                    self.newline(self.indent);
+                    self.write("| ");
+                    self.member(variant, false, &margin);
+                }
+                self.write(";");
+            }
+            (_, ty) => {
+                self.write(&format!("type {name}{generics} = "));
+                // the object of `type User = { .. };` is laid out on its own, unions which fit
+                // on a line are kept as they are
+                self.ty(ty, "", !matches!(ty, TsType::Union(_)));
+                self.write(";");
+            }
+        }
+    }
+
+    /// Whether the variants of a type alias of a union are written on their own lines
+    fn breaks_union(&self, decl: &Declaration, variants: &[TsType]) -> bool {
+        variants.len() > 1 && (!self.fits(&decl.to_string()) || variants.iter().any(documented))
+    }
+
+    /// Writes a type starting at the current position. `margin` is the indentation of the line
+    /// the type starts on. If `forced`, objects are broken even if they fit on a line.
+    fn ty(&mut self, ty: &TsType, margin: &str, forced: bool) {
+        match ty {
+            TsType::Keyword(_) | TsType::Literal(_) | TsType::Raw(_) => self.write(&ty.to_string()),
+            TsType::Array(element) if **element == TsType::Keyword(Keyword::Never) => {
+                self.write(&ty.to_string())
+            }
+            TsType::Reference { name, generics } => {
+                self.write(name);
+                if !generics.is_empty() {
+                    self.write("<");
+                    for (i, generic) in generics.iter().enumerate() {
+                        if i > 0 {
+                            self.write(", ");
+                        }
+                        self.ty(generic, margin, false);
+                    }
+                    self.write(">");
+                }
+            }
+            TsType::Array(element) => {
+                self.write("Array<");
+                self.ty(element, margin, false);
+                self.write(">");
+            }
+            TsType::Readonly(inner) => match &**inner {
+                TsType::Array(element) => {
+                    self.write("ReadonlyArray<");
+                    self.ty(element, margin, false);
+                    self.write(">");
+                }
+                inner => {
+                    self.write("readonly ");
+                    self.ty(inner, margin, false);
+                }
+            },
+            TsType::Tuple(elements) => {
+                let source = ty.to_string();
+                self.group(
+                    &source,
+                    elements,
+                    ("[", "]"),
+                    Items::Separated,
+                    margin,
+                    documented(ty),
+                    |p, element, m| match element.optional {
+                        true => {
+                            p.write("(");
+                            p.ty(&element.ty, m, false);
+                            p.write(")?");
+                        }
+                        false => p.ty(&element.ty, m, false),
+                    },
+                );
+            }
+            TsType::Object(properties) => {
+                let source = ty.to_string();
+                self.group(
+                    &source,
+                    properties,
+                    ("{", "}"),
+                    Items::Terminated,
+                    margin,
+                    forced || documented(ty),
+                    |p, property, m| {
+                        p.property(property, m);
+                        // e.g. `{ id: number, }`, but `{ "A": number, "B": string }`
+                        let last = std::ptr::eq(property, properties.last().unwrap());
+                        if property.terminated() || !last {
+                            p.write(",");
+                        }
+                    },
+                );
+            }
+            TsType::Record {
+                key,
+                value,
+                optional,
+                readonly,
+            } => {
+                let source = ty.to_string();
+                self.group(
+                    &source,
+                    &[()],
+                    ("{", "}"),
+                    Items::Separated,
+                    margin,
+                    forced || documented(ty),
+                    |p, _, m| {
+                        if *readonly {
+                            p.write("readonly ");
+                        }
+                        p.write("[key in ");
+                        p.ty(key, m, false);
+                        p.write(if *optional { "]?: " } else { "]: " });
+                        p.ty(value, m, false);
+                    },
+                );
+            }
+            TsType::Union(variants) => {
+                for (i, variant) in variants.iter().enumerate() {
+                    if i > 0 {
+                        self.write(" | ");
+                    }
+                    self.member(variant, false, margin);
+                }
+            }
+            TsType::Intersection(members) => {
+                for (i, member) in members.iter().enumerate() {
+                    if i > 0 {
+                        self.write(" & ");
+                    }
+                    match member.binds_looser(true) {
+                        true => self.member(member, true, margin),
+                        false => self.ty(member, margin, forced),
+                    }
+                }
+            }
+            TsType::Function { params, returns } => {
+                self.params(params, margin);
+                self.write(" => ");
+                self.ty(returns, margin, false);
+            }
+        }
+    }
+
+    /// Writes a member of a union, or of an intersection if `intersection` is set, enclosing it
+    /// in parentheses if necessary.
+    fn member(&mut self, ty: &TsType, intersection: bool, margin: &str) {
+        if !ty.binds_looser(intersection) {
+            return self.ty(ty, margin, false);
+        }
+
+        let source = format!("({ty})");
+        match ty {
+            TsType::Union(variants) => {
+                self.group(
+                    &source,
+                    variants,
+                    ("(", ")"),
+                    Items::Union,
+                    margin,
+                    documented(ty),
+                    |p, variant, m| p.member(variant, false, m),
+                );
+            }
+            ty => {
+                self.group(
+                    &source,
+                    &[ty],
+                    ("(", ")"),
+                    Items::Separated,
+                    margin,
+                    documented(ty),
+                    |p, ty, m| p.ty(ty, m, false),
+                );
+            }
+        }
+    }
+
+    fn params(&mut self, params: &[Param], margin: &str) {
+        let source = format!("({})", join(params));
+        let broken = params.iter().any(|param| documented(&param.ty));
+        self.group(
+            &source,
+            params,
+            ("(", ")"),
+            Items::Separated,
+            margin,
+            broken,
+            |p, param, m| {
+                p.write(&format!("{}: ", param.name));
+                p.ty(&param.ty, m, false);
+            },
+        );
+    }
+
+    /// Writes a property, with its docs on the lines above it
+    fn property(&mut self, property: &Property, margin: &str) {
+        if let Some(docs) = &property.docs {
+            for (i, line) in jsdoc(docs).lines().enumerate() {
+                if i > 0 {
+                    self.newline(margin);
+                }
+                self.write(line);
+            }
+            self.newline(margin);
+        }
+        if property.readonly {
+            self.write("readonly ");
+        }
+        self.write(&property.key());
+        if property.optional {
+            self.write("?");
+        }
+        match &property.ty {
+            TsType::Function { params, returns } if !property.readonly => {
+                self.params(params, margin);
+                self.write(": ");
+                self.ty(returns, margin, false);
+            }
+            ty => {
+                self.write(property.colon());
+                self.ty(ty, margin, false);
+            }
+        }
+    }
+
+    /// Writes a list on one line if it fits, or with one item per line otherwise. `source` is
+    /// the list written on one line, and `margin` the indentation of the line the list starts on.
+    /// If `broken`, e.g. because the list contains docs, it is broken even if it fits.
+    #[allow(clippy::too_many_arguments)]
+    fn group<T>(
+        &mut self,
+        source: &str,
+        items: &[T],
+        (open, close): (&str, &str),
+        separated: Items,
+        margin: &str,
+        broken: bool,
+        mut item: impl FnMut(&mut Self, &T, &str),
+    ) {
+        if items.is_empty() || (!broken && self.fits(source)) {
+            self.write(source);
+            return;
+        }
+
+        let inner = format!("{margin}{}", self.indent);
+        let variant_margin = format!("{inner}  ");
+        self.write(open);
+        for (i, it) in items.iter().enumerate() {
+            self.newline(&inner);
+            match separated {
+                Items::Union => {
+                    self.write("| ");
+                    item(self, it, &variant_margin);
+                }
+                Items::Terminated => item(self, it, &inner),
+                Items::Separated => {
+                    item(self, it, &inner);
+                    if i + 1 < items.len() {
+                        self.write(",");
+                    }
+                }
+            }
+        }
+        self.newline(margin);
+        self.write(close);
+    }
+}
+
+/// Whether a type contains docs, so it cannot be written on one line
+fn documented(ty: &TsType) -> bool {
+    match ty {
+        TsType::Keyword(_) | TsType::Literal(_) | TsType::Raw(_) => false,
+        TsType::Reference {
+            generics: types, ..
+        }
+        | TsType::Union(types)
+        | TsType::Intersection(types) => types.iter().any(documented),
+        TsType::Array(ty) | TsType::Readonly(ty) => documented(ty),
+        TsType::Tuple(elements) => elements.iter().any(|element| documented(&element.ty)),
+        TsType::Object(properties) => properties
+            .iter()
+            .any(|property| property.docs.is_some() || documented(&property.ty)),
+        TsType::Record { key, value, .. } => documented(key) || documented(value),
+        TsType::Function { params, returns } => {
+            params.iter().any(|param| documented(&param.ty)) || documented(returns)
+        }
+    }
+}
+
+fn join(items: &[impl std::fmt::Display]) -> String {
+    items
+        .iter()
+        .map(ToString::to_string)
+        .collect::<Vec<_>>()
+        .join(", ")
+}
@@ -0,0 +1,61 @@
+use std::{
+    collections::HashSet,
+    hash::{DefaultHasher, Hash, Hasher},
+    path::{Path, PathBuf},
+    sync::{Mutex, OnceLock},
+};
+
+use super::ExportError;
+
+// files this process has written to
+static CLAIMED: OnceLock<Mutex<HashSet<PathBuf>>> = OnceLock::new();
+
+/// Records that the current run writes into `file`, returning whether its contents on disk were
+/// written by the same run, and should therefore be merged with. Contents left behind by a
+/// previous run are overwritten instead, so declarations which are no longer generated disappear.
+///
+/// A run is made up of the processes started by the same parent, e.g. the test binaries of all
+/// crates run by one `cargo test`. The run which last wrote into a file is recorded in the
+/// temporary directory, keeping the file itself the same across runs. Where the parent of a
+/// process is not known, the contents on disk are always merged with.
+///
+/// The file must be locked by the caller.
+pub(super) fn claim(file: &Path) -> Result<bool, ExportError> {
+    let claimed = &mut CLAIMED.get_or_init(Default::default).lock().unwrap();
+    if claimed.contains(file) {
+        return Ok(true);
+    }
+
+    let Some(run) = run_id() else {
+        return Ok(true);
+    };
+
+    let record = record_path(file);
+    let same_run = std::fs::read_to_string(&record).is_ok_and(|last| last == run);
+    if !same_run {
+        std::fs::create_dir_all(record.parent().unwrap())?;
+        std::fs::write(&record, &run)?;
+    }
+
+    claimed.insert(file.to_owned());
+    Ok(same_run)
+}
+
+#[cfg(unix)]
+fn run_id() -> Option<String> {
+    Some(std::os::unix::process::parent_id().to_string())
+}
+
+#[cfg(not(unix))]
+fn run_id() -> Option<String> {
+    None
+}
+
+/// The file recording the run which last wrote into `file`
+fn record_path(file: &Path) -> PathBuf {
+    let mut hasher = DefaultHasher::new();
+    file.hash(&mut hasher);
+    std::env::temp_dir()
+        .join("ts-rs")
+        .join(format!("{:016x}", hasher.finish()))
+}
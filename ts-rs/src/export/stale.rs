@@ -0,0 +1,83 @@
+use std::{
+    collections::HashSet,
+    io::{BufRead, BufReader},
+    path::{Path, PathBuf},
+};
+
+use super::{check::checked_paths, get_export_paths, path, ExportError, BARREL, NOTE};
+use crate::Config;
+
+/// Returns all files in the output directory which were generated by ts-rs, but to which no
+/// type has been exported or checked by this process, e.g. because the type was renamed or removed.
+///
+/// Only files starting with the note ts-rs puts at the top of every file, and JSON Schemas
+/// containing it as their `$comment`, are considered, so files which were not generated by ts-rs
+/// are never returned.
+/// Since only types exported by this process are known, this should be called after exporting
+/// all types, e.g. using [`TS::export_all`](crate::TS::export_all).
+pub fn stale_files(cfg: &Config) -> Result<Vec<PathBuf>, ExportError> {
+    let exported = get_export_paths()
+        .lock()
+        .unwrap()
+        .keys()
+        .cloned()
+        .chain(checked_paths())
+        .map(path::absolute)
+        .collect::<Result<HashSet<_>, _>>()?;
+
+    let mut stale = vec![];
+    let dir = path::absolute(cfg.out_dir())?;
+    if dir.is_dir() {
+        visit(cfg, &dir, &exported, &mut stale)?;
+    }
+
+    stale.sort();
+    Ok(stale)
+}
+
+/// Removes all files returned by [`stale_files`], returning their paths.
+pub fn remove_stale_files(cfg: &Config) -> Result<Vec<PathBuf>, ExportError> {
+    let stale = stale_files(cfg)?;
+    for file in &stale {
+        std::fs::remove_file(file)?;
+    }
+    Ok(stale)
+}
+
+fn visit(
+    cfg: &Config,
+    dir: &Path,
+    exported: &HashSet<PathBuf>,
+    stale: &mut Vec<PathBuf>,
+) -> Result<(), ExportError> {
+    for entry in std::fs::read_dir(dir)? {
+        let path = entry?.path();
+
+        if path.is_dir() {
+            visit(cfg, &path, exported, stale)?;
+            continue;
+        }
+
+        let name = path.file_name().unwrap_or_default().to_string_lossy();
+        let is_ts = name.ends_with(".ts");
+        let is_schema = name.ends_with(".schema.json");
+        let is_barrel = cfg.barrels() && name == BARREL;
+        if !(is_ts || is_schema) || is_barrel || exported.contains(&path) {
+            continue;
+        }
+
+        let generated = match is_schema {
+            true => crate::json_schema::is_generated(&std::fs::read_to_string(&path)?),
+            false => {
+                let mut first_line = String::new();
+                BufReader::new(std::fs::File::open(&path)?).read_line(&mut first_line)?;
+                first_line == NOTE
+            }
+        };
+        if generated {
+            stale.push(path);
+        }
+    }
+
+    Ok(())
+}
@@ -0,0 +1,227 @@
+use std::collections::HashSet;
+
+use crate::{
+    ast::{Keyword, Literal, TsType},
+    Config, TS,
+};
+
+/// Generates the type guard of `T`, see [`TS::guard`].
+pub(crate) fn guard<T: TS + ?Sized + 'static>(cfg: &Config) -> String {
+    let decl = T::decl_ast(cfg);
+
+    // guards can only be called for types which are exported together with `T`
+    let mut guarded = <T::WithoutGenerics as TS>::dependencies(cfg)
+        .into_iter()
+        .map(|dep| dep.ts_name)
+        .collect::<HashSet<_>>();
+    guarded.insert(decl.name.clone());
+
+    let mut checker = Checker {
+        generics: decl.generics.iter().map(|g| g.name.clone()).collect(),
+        guarded,
+        variables: 0,
+    };
+    let check = checker.check(&decl.ty, "value");
+
+    let (generics, params, args) = match decl.generics.as_slice() {
+        [] => (String::new(), String::new(), String::new()),
+        generics => {
+            let declared = generics.iter().map(|generic| match &generic.default {
+                Some(default) => format!("{} = {default}", generic.name),
+                None => generic.name.clone(),
+            });
+            let params = generics.iter().map(|generic| {
+                let name = &generic.name;
+                format!(", is{name}: (value: unknown) => value is {name}")
+            });
+            let args = generics.iter().map(|generic| generic.name.as_str());
+
+            (
+                format!("<{}>", declared.collect::<Vec<_>>().join(", ")),
+                params.collect(),
+                format!("<{}>", args.collect::<Vec<_>>().join(", ")),
+            )
+        }
+    };
+
+    let name = &decl.name;
+    format!(
+        "function is{name}{generics}(value: unknown{params}): value is {name}{args} {{ return {check}; }}"
+    )
+}
+
+struct Checker {
+    // generic parameters of the declaration, which are checked by a guard passed as an argument
+    generics: HashSet<String>,
+    // types for which a guard `is<Name>` is available
+    guarded: HashSet<String>,
+    // number of variables introduced in arrow functions so far
+    variables: usize,
+}
+
+impl Checker {
+    /// Returns an expression which evaluates to `true` if `value` is of type `ty`
+    fn check(&mut self, ty: &TsType, value: &str) -> String {
+        match ty {
+            TsType::Keyword(keyword) => match keyword {
+                Keyword::String => format!(r#"typeof {value} === "string""#),
+                Keyword::Number => format!(r#"typeof {value} === "number""#),
+                Keyword::Bigint => format!(r#"typeof {value} === "bigint""#),
+                Keyword::Boolean => format!(r#"typeof {value} === "boolean""#),
+                Keyword::Symbol => format!(r#"typeof {value} === "symbol""#),
+                Keyword::Object => format!(r#"(typeof {value} === "object" && {value} !== null)"#),
+                Keyword::Null => format!("{value} === null"),
+                Keyword::Undefined | Keyword::Void => format!("{value} === undefined"),
+                Keyword::Unknown | Keyword::Any => "true".to_owned(),
+                Keyword::Never => "false".to_owned(),
+            },
+            TsType::Literal(literal) => format!("{value} === {literal}"),
+            TsType::Reference { name, .. } if self.generics.contains(name) => {
+                format!("is{name}({value})")
+            }
+            TsType::Reference { name, generics } if self.guarded.contains(name) => {
+                let args = generics
+                    .iter()
+                    .map(|generic| format!(", {}", self.guard(generic)))
+                    .collect::<String>();
+                format!("is{name}({value}{args})")
+            }
+            // there is no guard for types which are not exported by ts-rs
+            TsType::Reference { .. } | TsType::Function { .. } | TsType::Raw(_) => {
+                "true".to_owned()
+            }
+            TsType::Array(element) => {
+                let array = format!("({value} as unknown[])");
+                let element = self.guard(element);
+                format!("(Array.isArray({value}) && {array}.every({element}))")
+            }
+            // `readonly` only exists at compile time
+            TsType::Readonly(ty) => self.check(ty, value),
+            TsType::Tuple(elements) => {
+                let array = format!("({value} as unknown[])");
+                let required = elements.iter().filter(|e| !e.optional).count();
+                let length = match required == elements.len() {
+                    true => format!("{array}.length === {required}"),
+                    false => format!(
+                        "{array}.length >= {required} && {array}.length <= {}",
+                        elements.len()
+                    ),
+                };
+
+                let mut checks = vec![format!("Array.isArray({value})"), length];
+                for (i, element) in elements.iter().enumerate() {
+                    let item = format!("{array}[{i}]");
+                    let check = self.check(&element.ty, &item);
+                    checks.push(match element.optional {
+                        true => format!("({item} === undefined || {check})"),
+                        false => check,
+                    });
+                }
+                all(checks)
+            }
+            TsType::Object(properties) => {
+                let object = format!("({value} as Record<string, unknown>)");
+
+                let mut checks = is_object(value);
+                for property in properties {
+                    let item = format!("{object}[{}]", Literal::String(property.name.clone()));
+                    let check = self.check(&property.ty, &item);
+                    checks.push(match property.optional {
+                        true => format!("({item} === undefined || {check})"),
+                        false => check,
+                    });
+                }
+                all(checks)
+            }
+            TsType::Record {
+                key,
+                value: ty,
+                optional,
+                ..
+            } => {
+                let object = format!("({value} as Record<string, unknown>)");
+                let mut checks = is_object(value);
+
+                // keys are always strings, so only string literals can be checked
+                if is_string_literal(key) {
+                    let key = self.guard(key);
+                    checks.push(format!("Object.keys({object}).every({key})"));
+                }
+
+                let variable = self.variable();
+                let check = self.check(ty, &variable);
+                let check = match optional {
+                    true => format!("{variable} === undefined || {check}"),
+                    false => check,
+                };
+                if check != "true" {
+                    checks.push(format!(
+                        "Object.values({object}).every(({variable}: unknown) => {check})"
+                    ));
+                }
+                all(checks)
+            }
+            TsType::Union(variants) => {
+                let checks = variants
+                    .iter()
+                    .map(|variant| self.check(variant, value))
+                    .collect::<Vec<_>>();
+
+                if checks.iter().any(|check| check == "true") {
+                    return "true".to_owned();
+                }
+                format!("({})", checks.join(" || "))
+            }
+            TsType::Intersection(members) => {
+                // brands only exist at compile time
+                let checks = members
+                    .iter()
+                    .filter(|member| member.brand().is_none())
+                    .map(|member| self.check(member, value))
+                    .collect();
+                all(checks)
+            }
+        }
+    }
+
+    /// Returns a type guard for `ty`, e.g. `(v1: unknown): v1 is string => typeof v1 === "string"`
+    fn guard(&mut self, ty: &TsType) -> String {
+        let variable = self.variable();
+        let check = self.check(ty, &variable);
+        format!("({variable}: unknown): {variable} is {ty} => {check}")
+    }
+
+    fn variable(&mut self) -> String {
+        self.variables += 1;
+        format!("v{}", self.variables)
+    }
+}
+
+fn is_object(value: &str) -> Vec<String> {
+    vec![
+        format!(r#"typeof {value} === "object""#),
+        format!("{value} !== null"),
+    ]
+}
+
+fn is_string_literal(ty: &TsType) -> bool {
+    match ty {
+        TsType::Literal(Literal::String(_)) => true,
+        TsType::Union(variants) => variants.iter().all(is_string_literal),
+        _ => false,
+    }
+}
+
+/// Combines the given checks using `&&`, omitting those which are always `true`
+fn all(checks: Vec<String>) -> String {
+    let checks = checks
+        .into_iter()
+        .filter(|check| check != "true")
+        .collect::<Vec<_>>();
+
+    match checks.len() {
+        0 => "true".to_owned(),
+        1 => checks.into_iter().next().unwrap(),
+        _ => format!("({})", checks.join(" && ")),
+    }
+}
@@ -0,0 +1,476 @@
+use std::{
+    any::TypeId,
+    collections::{HashMap, HashSet},
+    fmt::{self, Display, Formatter, Write},
+    path::{Path, PathBuf},
+};
+
+use crate::{
+    ast::{Declaration, Keyword, Literal, TsType},
+    export::{relative_path, write_generated_file, NOTE},
+    Config, Direction, ExportError, TypeVisitor, TS,
+};
+
+const DIALECT: &str = "https://json-schema.org/draft/2020-12/schema";
+
+/// Generates the JSON Schema of `T`, see [`TS::json_schema`].
+pub(crate) fn json_schema<T: TS + ?Sized + 'static>(cfg: &Config) -> Result<String, ExportError> {
+    let path = path_of::<T>(cfg)?;
+    let decl = T::decl_ast(cfg);
+
+    let mut types = Types {
+        cfg,
+        seen: HashSet::new(),
+        files: HashMap::new(),
+        instances: HashMap::new(),
+    };
+    <T::WithoutGenerics as TS>::visit_dependencies(&mut types);
+
+    let mut generator = Generator {
+        path: &path,
+        decl: &decl,
+        types,
+        inlining: vec![],
+    };
+    let schema = generator.schema(&decl.ty)?;
+
+    let mut root = vec![
+        ("$schema".to_owned(), Json::String(DIALECT.to_owned())),
+        ("$comment".to_owned(), Json::String(note())),
+        ("title".to_owned(), Json::String(decl.name.clone())),
+    ];
+    if let Some(docs) = &decl.docs {
+        root.push(("description".to_owned(), Json::String(description(docs))));
+    }
+    match schema {
+        Json::Object(fields) => root.extend(fields),
+        schema => root.push(("allOf".to_owned(), Json::Array(vec![schema]))),
+    }
+
+    Ok(format!("{}\n", Json::Object(root)))
+}
+
+/// The note ts-rs puts at the top of every file, which is the `$comment` of every JSON Schema
+fn note() -> String {
+    NOTE.trim_start_matches("// ").trim_end().to_owned()
+}
+
+/// Returns `true` if `contents` is a JSON Schema generated by ts-rs
+pub(crate) fn is_generated(contents: &str) -> bool {
+    let mut comment = String::from("\"$comment\": ");
+    write!(comment, "{}", Json::String(note())).unwrap();
+    contents.contains(&comment)
+}
+
+/// Exports the JSON Schema of `T` and all of its dependencies, see [`TS::export_json_schema`].
+pub(crate) fn export_all<T: TS + ?Sized + 'static>(cfg: &Config) -> Result<(), ExportError> {
+    let mut export = Export {
+        cfg,
+        seen: HashSet::new(),
+        error: None,
+    };
+
+    export.export::<T>()?;
+    <T as TS>::visit_dependencies(&mut export);
+
+    match export.error {
+        Some(error) => Err(error),
+        None => Ok(()),
+    }
+}
+
+/// Returns the path of the JSON Schema of `T`, which is placed next to its TypeScript bindings
+fn path_of<T: TS + ?Sized>(cfg: &Config) -> Result<PathBuf, ExportError> {
+    let output_path = T::output_path()
+        .ok_or_else(std::any::type_name::<T>)
+        .map_err(ExportError::CannotBeExported)?;
+
+    Ok(schema_path(cfg, &T::ident(cfg), &output_path))
+}
+
+fn schema_path(cfg: &Config, ident: &str, output_path: &Path) -> PathBuf {
+    cfg.export_dir
+        .join(output_path)
+        .with_file_name(format!("{ident}.schema.json"))
+}
+
+struct Export<'a> {
+    cfg: &'a Config,
+    seen: HashSet<PathBuf>,
+    error: Option<ExportError>,
+}
+
+impl Export<'_> {
+    // exports the schema of `T`, returning `false` if it has already been exported
+    fn export<T: TS + ?Sized + 'static>(&mut self) -> Result<bool, ExportError> {
+        let path = path_of::<T>(self.cfg)?;
+        if !self.seen.insert(path.clone()) {
+            return Ok(false);
+        }
+
+        let schema = json_schema::<T>(self.cfg)?;
+        write_generated_file(self.cfg, &path, &schema)?;
+
+        Ok(true)
+    }
+}
+
+impl TypeVisitor for Export<'_> {
+    fn visit<T: TS + 'static + ?Sized>(&mut self) {
+        if self.error.is_some() || <T as TS>::output_path().is_none() {
+            return;
+        }
+
+        match self.export::<T>() {
+            Ok(true) => <T as TS>::visit_dependencies(self),
+            Ok(false) => (),
+            Err(error) => self.error = Some(error),
+        }
+    }
+
+    fn direction(&self) -> Direction {
+        self.cfg.direction()
+    }
+
+    fn interfaces(&self) -> bool {
+        self.cfg.interfaces()
+    }
+}
+
+/// Collects the exported types a declaration refers to
+struct Types<'a> {
+    cfg: &'a Config,
+    seen: HashSet<TypeId>,
+    // path of the schema of every type, by its name
+    files: HashMap<String, PathBuf>,
+    // definitions of generic types with concrete type arguments, e.g. `Page<User>`
+    instances: HashMap<String, TsType>,
+}
+
+impl TypeVisitor for Types<'_> {
+    fn visit<T: TS + 'static + ?Sized>(&mut self) {
+        let Some(output_path) = <T as TS>::output_path() else {
+            return;
+        };
+        if !self.seen.insert(TypeId::of::<T>()) {
+            return;
+        }
+
+        let ident = <T as TS>::ident(self.cfg);
+        let name = <T as TS>::name_ast(self.cfg).to_string();
+        self.files
+            .insert(ident.clone(), schema_path(self.cfg, &ident, &output_path));
+
+        // JSON Schema has no generics, so instances of generic types are inlined, which requires
+        // their dependencies as well
+        if name != ident {
+            let definition = <T as TS>::inline_ast(self.cfg);
+            self.instances.insert(name, definition);
+            <T as TS>::visit_dependencies(self);
+        }
+    }
+
+    fn direction(&self) -> Direction {
+        self.cfg.direction()
+    }
+
+    fn interfaces(&self) -> bool {
+        self.cfg.interfaces()
+    }
+}
+
+struct Generator<'a> {
+    // path of the generated schema, used to resolve `$ref`s
+    path: &'a Path,
+    decl: &'a Declaration,
+    types: Types<'a>,
+    // instances of generic types which are currently being inlined
+    inlining: Vec<String>,
+}
+
+impl Generator<'_> {
+    fn schema(&mut self, ty: &TsType) -> Result<Json, ExportError> {
+        Ok(match ty {
+            TsType::Keyword(keyword) => match keyword {
+                Keyword::String => typed("string"),
+                Keyword::Number => typed("number"),
+                Keyword::Bigint => typed("integer"),
+                Keyword::Boolean => typed("boolean"),
+                Keyword::Null => typed("null"),
+                Keyword::Object => typed("object"),
+                Keyword::Unknown | Keyword::Any => Json::Bool(true),
+                // there are no JSON values of these types
+                Keyword::Undefined | Keyword::Void | Keyword::Never | Keyword::Symbol => {
+                    Json::Bool(false)
+                }
+            },
+            TsType::Literal(literal) => object([("const", literal.into())]),
+            TsType::Reference { name, .. }
+                if self.decl.generics.iter().any(|g| &g.name == name) =>
+            {
+                Json::Bool(true)
+            }
+            TsType::Reference { name, .. } if name == &self.decl.name => {
+                object([("$ref", Json::String("#".to_owned()))])
+            }
+            TsType::Reference { name, generics } => {
+                let instance = ty.to_string();
+                match self.types.instances.get(&instance) {
+                    Some(definition)
+                        if !generics.is_empty() && !self.inlining.contains(&instance) =>
+                    {
+                        let definition = definition.clone();
+                        self.inlining.push(instance);
+                        let schema = self.schema(&definition)?;
+                        self.inlining.pop();
+                        schema
+                    }
+                    _ => match self.types.files.get(name) {
+                        Some(path) => {
+                            object([("$ref", Json::String(relative_path(self.path, path)?))])
+                        }
+                        // there is no schema for types which are not exported by ts-rs
+                        None => Json::Bool(true),
+                    },
+                }
+            }
+            TsType::Array(element) => object([
+                ("type", Json::String("array".to_owned())),
+                ("items", self.schema(element)?),
+            ]),
+            // JSON Schema's `readOnly` means something else, the value is not sent by clients
+            TsType::Readonly(ty) => self.schema(ty)?,
+            TsType::Tuple(elements) => {
+                let required = elements.iter().filter(|e| !e.optional).count();
+                let items = elements
+                    .iter()
+                    .map(|element| self.schema(&element.ty))
+                    .collect::<Result<_, _>>()?;
+
+                object([
+                    ("type", Json::String("array".to_owned())),
+                    ("prefixItems", Json::Array(items)),
+                    ("minItems", Json::Number(required.to_string())),
+                    ("maxItems", Json::Number(elements.len().to_string())),
+                ])
+            }
+            TsType::Object(properties) => {
+                let mut fields = vec![];
+                let mut required = vec![];
+                for property in properties {
+                    let schema = self.schema(&property.ty)?;
+                    let schema = match &property.docs {
+                        Some(docs) => describe(schema, docs),
+                        None => schema,
+                    };
+                    fields.push((property.name.clone(), schema));
+                    if !property.optional {
+                        required.push(Json::String(property.name.clone()));
+                    }
+                }
+
+                let mut schema = vec![("type".to_owned(), Json::String("object".to_owned()))];
+                if !fields.is_empty() {
+                    schema.push(("properties".to_owned(), Json::Object(fields)));
+                }
+                if !required.is_empty() {
+                    schema.push(("required".to_owned(), Json::Array(required)));
+                }
+                Json::Object(schema)
+            }
+            TsType::Record {
+                key,
+                value,
+                optional,
+                ..
+            } => {
+                let value = self.schema(value)?;
+
+                // a record with literal keys is an object with a property for every key
+                if let Some(keys) = string_literals(key) {
+                    let fields = keys.iter().map(|key| (key.to_string(), value.clone()));
+                    let mut schema = vec![
+                        ("type".to_owned(), Json::String("object".to_owned())),
+                        ("properties".to_owned(), Json::Object(fields.collect())),
+                    ];
+                    if !optional {
+                        let required = keys.iter().map(|key| Json::String(key.to_string()));
+                        schema.push(("required".to_owned(), Json::Array(required.collect())));
+                    }
+                    schema.push(("additionalProperties".to_owned(), Json::Bool(false)));
+                    return Ok(Json::Object(schema));
+                }
+
+                let mut schema = vec![("type".to_owned(), Json::String("object".to_owned()))];
+                // keys are always strings in JSON, so only references, like enums, are checked
+                if let TsType::Reference { .. } = **key {
+                    schema.push(("propertyNames".to_owned(), self.schema(key)?));
+                }
+                schema.push(("additionalProperties".to_owned(), value));
+                Json::Object(schema)
+            }
+            TsType::Union(variants) => {
+                let literals = variants
+                    .iter()
+                    .map(|variant| match variant {
+                        TsType::Literal(literal) => Some(Json::from(literal)),
+                        _ => None,
+                    })
+                    .collect::<Option<Vec<_>>>();
+
+                match literals {
+                    Some(literals) => object([("enum", Json::Array(literals))]),
+                    None => {
+                        let variants = variants
+                            .iter()
+                            .map(|variant| self.schema(variant))
+                            .collect::<Result<_, _>>()?;
+                        object([("anyOf", Json::Array(variants))])
+                    }
+                }
+            }
+            TsType::Intersection(members) => {
+                // brands only exist at compile time
+                let mut members = members
+                    .iter()
+                    .filter(|member| member.brand().is_none())
+                    .map(|member| self.schema(member))
+                    .collect::<Result<Vec<_>, _>>()?;
+                match members.len() {
+                    1 => members.remove(0),
+                    _ => object([("allOf", Json::Array(members))]),
+                }
+            }
+            // types given by `#[ts(type = "..")]` cannot be checked
+            TsType::Function { .. } | TsType::Raw(_) => Json::Bool(true),
+        })
+    }
+}
+
+fn typed(ty: &str) -> Json {
+    object([("type", Json::String(ty.to_owned()))])
+}
+
+fn object<const N: usize>(fields: [(&str, Json); N]) -> Json {
+    Json::Object(
+        fields
+            .into_iter()
+            .map(|(key, value)| (key.to_owned(), value))
+            .collect(),
+    )
+}
+
+/// The text of doc comments, without the space which usually follows `///`
+fn description(docs: &str) -> String {
+    let lines = docs
+        .lines()
+        .map(|line| line.strip_prefix(' ').unwrap_or(line));
+    lines.collect::<Vec<_>>().join("\n")
+}
+
+/// Adds a description to a schema
+fn describe(schema: Json, docs: &str) -> Json {
+    let description = ("description".to_owned(), Json::String(description(docs)));
+    match schema {
+        Json::Object(mut fields) => {
+            fields.push(description);
+            Json::Object(fields)
+        }
+        Json::Bool(true) => Json::Object(vec![description]),
+        schema => Json::Object(vec![
+            ("allOf".to_owned(), Json::Array(vec![schema])),
+            description,
+        ]),
+    }
+}
+
+fn string_literals(ty: &TsType) -> Option<Vec<&str>> {
+    match ty {
+        TsType::Literal(Literal::String(literal)) => Some(vec![literal]),
+        TsType::Union(variants) => variants
+            .iter()
+            .map(|variant| match variant {
+                TsType::Literal(Literal::String(literal)) => Some(literal.as_str()),
+                _ => None,
+            })
+            .collect(),
+        _ => None,
+    }
+}
+
+/// A JSON value, printed like `serde_json::to_string_pretty`
+#[derive(Clone, Debug, PartialEq)]
+enum Json {
+    Bool(bool),
+    Number(String),
+    String(String),
+    Array(Vec<Json>),
+    Object(Vec<(String, Json)>),
+}
+
+impl From<&Literal> for Json {
+    fn from(literal: &Literal) -> Self {
+        match literal {
+            Literal::String(string) => Self::String(string.clone()),
+            Literal::Number(number) => Self::Number(number.clone()),
+            Literal::Boolean(boolean) => Self::Bool(*boolean),
+        }
+    }
+}
+
+impl Json {
+    fn write(&self, f: &mut Formatter<'_>, indent: usize) -> fmt::Result {
+        let pad = "  ".repeat(indent + 1);
+        match self {
+            Self::Bool(boolean) => write!(f, "{boolean}"),
+            Self::Number(number) => f.write_str(number),
+            Self::String(string) => write_string(f, string),
+            Self::Array(items) if items.is_empty() => f.write_str("[]"),
+            Self::Array(items) => {
+                f.write_str("[")?;
+                for (i, item) in items.iter().enumerate() {
+                    f.write_str(if i == 0 { "\n" } else { ",\n" })?;
+                    f.write_str(&pad)?;
+                    item.write(f, indent + 1)?;
+                }
+                write!(f, "\n{}]", "  ".repeat(indent))
+            }
+            Self::Object(fields) if fields.is_empty() => f.write_str("{}"),
+            Self::Object(fields) => {
+                f.write_str("{")?;
+                for (i, (key, value)) in fields.iter().enumerate() {
+                    f.write_str(if i == 0 { "\n" } else { ",\n" })?;
+                    f.write_str(&pad)?;
+                    write_string(f, key)?;
+                    f.write_str(": ")?;
+                    value.write(f, indent + 1)?;
+                }
+                write!(f, "\n{}}}", "  ".repeat(indent))
+            }
+        }
+    }
+}
+
+impl Display for Json {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        self.write(f, 0)
+    }
+}
+
+fn write_string(f: &mut Formatter<'_>, string: &str) -> fmt::Result {
+    f.write_char('"')?;
+    for c in string.chars() {
+        match c {
+            '"' => f.write_str("\\\"")?,
+            '\\' => f.write_str("\\\\")?,
+            '\n' => f.write_str("\\n")?,
+            '\r' => f.write_str("\\r")?,
+            '\t' => f.write_str("\\t")?,
+            '\u{8}' => f.write_str("\\b")?,
+            '\u{c}' => f.write_str("\\f")?,
+            c if c.is_control() && (c as u32) < 0x20 => write!(f, "\\u{:04x}", c as u32)?,
+            c => f.write_char(c)?,
+        }
+    }
+    f.write_char('"')
+}
@@ -66,6 +66,7 @@
 //! - compatible with serde
 //! - generate necessary imports when exporting to multiple files
 //! - precise control over generated types
+//! - generate JSON Schemas from the same types using `TS::export_json_schema`
 //!
 //! If there's a type you're dealing with which doesn't implement `TS`, you can use either
 //! `#[ts(as = "..")]` or `#[ts(type = "..")]`, enable the appropriate cargo feature, or open a PR.
@@ -75,29 +76,57 @@
 //! The following environment variables may be set to configure *how* and *where*:   
 //! | Variable                 | Description                                                         | Default      |
 //! |--------------------------|---------------------------------------------------------------------|--------------|
+//! | `TS_RS_BRANDED_NEWTYPES` | Brand newtypes, see `Config::with_branded_newtypes`                 | *disabled*   |
+//! | `TS_RS_CHECK`            | Check bindings are up to date, see `Config::with_check_only`        | *disabled*   |
+//! | `TS_RS_DIRECTION`        | Direction of the generated bindings, see `Config::with_direction`   | `both`       |
+//! | `TS_RS_EXPORT_BARRELS`   | Generate `index.ts` barrels, see `Config::with_barrels`             | *disabled*   |
 //! | `TS_RS_EXPORT_DIR`       | Base directory into which bindings will be exported                 | `./bindings` |
 //! | `TS_RS_IMPORT_EXTENSION` | File extension used in `import` statements                          | *none*       |
+//! | `TS_RS_INTERFACES`       | Declare structs as interfaces, see `Config::with_interfaces`        | *disabled*   |
 //! | `TS_RS_LARGE_INT`        | Binding used for large integer types (`i64`, `u64`, `i128`, `u128`) | `bigint`     |
+//! | `TS_RS_LAYOUT`           | Layout of declarations, see `Config::with_layout`                   | `multi-line` |
+//! | `TS_RS_READONLY`         | Make all types read-only, see `Config::with_readonly`               | *disabled*   |
+//! | `TS_RS_SINGLE_FILE`      | Bundle all types into one file, see `Config::with_single_file`      | *none*       |
+//! | `TS_RS_TYPE_GUARDS`      | Export a type guard with every type, see `Config::with_type_guards` | *disabled*   |
+//! | `TS_RS_ZOD`              | Export Zod schemas, see `Config::with_zod` (`zod` feature)          | *disabled*   |
 //!
-//! We recommend putting this configuration in the project's [config.toml](https://doc.rust-lang.org/cargo/reference/config.html#env) to make it persistent:
+//! To make this configuration persistent, put it into a `ts-rs.toml` next to the crate's `Cargo.toml`, or into the
+//! `[package.metadata.ts-rs]` table of the `Cargo.toml` itself, and enable the `config-file` feature. Environment variables
+//! take precedence over the file.
+//! See `Config::from_file` for all options:
 //! ```toml
-//! # <project-root>/.cargo/config.toml
-//! [env]
-//! TS_RS_EXPORT_DIR = { value = "bindings", relative = true }
-//! TS_RS_LARGE_INT = "number"
+//! # <crate-root>/ts-rs.toml
+//! out-dir = "bindings"
+//! large-int = "number"
 //! ```
 //!
-//! To export bindings programmatically without the use of tests, `TS::export_all`, `TS::export`, and `TS::export_to_string` can be used instead.
+//! To export bindings programmatically without the use of tests, `TS::export_all`, `TS::export`, and `TS::export_to_string` can be used instead.\
+//! Using the `registry` feature, `export_registered` exports all types annotated with `#[ts(export)]` at once, e.g. from a binary.
+//!
+//! Such a binary, `src/bin/export-bindings.rs` by default, is run by the `cargo ts-rs` command of the `cargo-ts-rs` crate:
+//! ```sh
+//! cargo install cargo-ts-rs
+//! cargo ts-rs export --out-dir ../frontend/src/bindings
+//! cargo ts-rs check  # fails if the bindings on disk are out of date
+//! cargo ts-rs clean  # removes all generated bindings
+//! ```
 //!
 //! ## Serde Compatibility
 //! With the `serde-compat` feature (enabled by default), serde attributes are parsed for enums and structs.\
-//! Supported serde attributes: `rename`, `rename-all`, `rename-all-fields`, `tag`, `content`, `untagged`, `skip`, `skip_serializing`, `skip_serializing_if`, `flatten`, `default`
+//! Supported serde attributes: `rename`, `rename-all`, `rename-all-fields`, `tag`, `content`, `untagged`, `skip`, `skip_serializing`, `skip_serializing_if`, `flatten`, `default`, `transparent`, `into`, `from`, `try_from`, `skip_deserializing`, `alias`
 //!
 //! **Note**: `skip_serializing` and `skip_serializing_if` only have an effect when used together with
 //! `#[serde(default)]`. This ensures that the generated type is correct for both serialization and deserialization.
 //!
-//! **Note**: `skip_deserializing` is ignored. If you wish to exclude a field
-//! from the generated type, but cannot use `#[serde(skip)]`, use `#[ts(skip)]` instead.
+//! **Note**: A type annotated with `#[serde(into = "..")]` is exported like `#[ts(as = "..")]`, except when generating bindings for
+//! `Direction::Deserialize`. `#[serde(from = "..")]` and `#[serde(try_from = "..")]` are only used when generating bindings for
+//! `Direction::Deserialize`, see `Config::with_direction`.
+//!
+//! **Note**: By default, bindings are used in both directions. Then, `skip_deserializing` is ignored and
+//! `rename(serialize = "..", deserialize = "..")` uses the serialized name. When generating bindings for just one
+//! direction using `Config::with_direction` or `TS_RS_DIRECTION`, fields and variants are skipped and renamed like they are
+//! in that direction. `skip_serializing` and `default` then make a field optional on their own. `alias` is always ignored.
+//! If you wish to exclude a field from the generated type, but cannot use `#[serde(skip)]`, use `#[ts(skip)]` instead.
 //!
 //! When ts-rs encounters an unsupported serde attribute, a warning is emitted, unless the feature `no-serde-warnings` is enabled.\
 //! We are currently waiting for [#54140](https://github.com/rust-lang/rust/issues/54140), which will improve the ergonomics arund these diagnostics.
@@ -106,7 +135,7 @@
 //! | **Feature**        | **Description**                                                                                                                                     |
 //! |:-------------------|-----------------------------------------------------------------------------------------------------------------------------------------------------|
 //! | serde-compat       | **Enabled by default** <br/>See the *"serde compatibility"* section below for more information.                                                     |
-//! | format             | Enables formatting of the generated TypeScript bindings. <br/>Currently, this unfortunately adds quite a few dependencies.                          |
+//! | format             | Formats the generated TypeScript bindings, see `Config::with_formatting`. <br/>Currently, this unfortunately adds quite a few dependencies.         |
 //! | no-serde-warnings  | By default, warnings are printed during build if unsupported serde attributes are encountered. <br/>Enabling this feature silences these warnings.  |
 //! | serde-json-impl    | Implement `TS` for types from *serde_json*                                                                                                          |
 //! | chrono-impl        | Implement `TS` for types from *chrono*                                                                                                              |
@@ -123,6 +152,11 @@
 //! | tokio-impl         | Implement `TS` for types from *tokio*                                                                                                               |
 //! | jiff-impl          | Implement `TS` for types from *jiff*                                                                                                                |
 //! | arrayvec-impl      | Implement `TS` for types from *arrayvec*                                                                                                            |
+//! | zod                | Enables exporting [Zod](https://zod.dev) schemas, see `Config::with_zod`                                                                            |
+//! | values             | Enables exporting Rust values as TypeScript constants, see `TS::export_value` and `export_value!`                                                   |
+//! | testing            | Enables checking serialized values against the generated bindings, see `testing::assert_conforms`                                                   |
+//! | registry           | Enables exporting all types annotated with `#[ts(export)]` without running tests, see `export_registered`                                           |
+//! | config-file        | Enables reading the configuration from `ts-rs.toml` or `[package.metadata.ts-rs]`, see `Config::load`                                               |
 //!
 //! ## Contributing
 //! Contributions are always welcome!
@@ -146,17 +180,140 @@ use std::{
 
 pub use ts_rs_macros::TS;
 
-pub use crate::export::ExportError;
+/// Generates TypeScript bindings for a function, e.g. a command handler or an RPC method.
+///
+/// The function is kept as it is. [TS] is implemented for a struct of the same name, which is
+/// exported as the type of the function, taking its arguments as an object and returning a
+/// `Promise`. The types of the arguments and the return type must implement [TS], and are imported
+/// from their files when exported. For functions returning `Result<T, E>`, the promise resolves to
+/// `T`.
+/// ```
+/// # use ts_rs::TS;
+/// # #[derive(TS)] struct User { name: String }
+/// #[ts_rs::function]
+/// fn create_user(name: String, age: u32) -> Result<User, String> {
+///     # unimplemented!()
+/// }
+///
+/// assert_eq!(
+///     create_user::decl(&ts_rs::Config::new()),
+///     "type createUser = (args: { name: string, age: number, }) => Promise<User>;"
+/// );
+/// ```
+///
+/// ### attributes
+/// - **`#[ts_rs::function(export)]`** \
+///   Generates a test which exports the function to `<name>.ts`, just like `#[ts(export)]`
+///
+/// - **`#[ts_rs::function(export_to = "..")]`** \
+///   Specifies where the function should be exported to, just like `#[ts(export_to = "..")]`
+///
+/// - **`#[ts_rs::function(rename = "..")]`** \
+///   Sets the TypeScript name of the function, which defaults to its name in camelCase
+///
+/// - **`#[ts_rs::function(rename_all = "..")]`** \
+///   Renames all arguments. Valid values are the same as for `#[ts(rename_all = "..")]`
+///
+/// - **`#[ts_rs::function(declare)]`** \
+///   Declares the function using `declare function` instead of a type alias, e.g.
+///   `declare function createUser(args: { name: string, age: number, }): Promise<User>;`
+///
+/// - **`#[ts_rs::function(crate = "..")]`** \
+///   Generates code which references the module passed to it instead of defaulting to `::ts_rs`
+///
+/// Arguments accept the same `#[ts(..)]` attributes as struct fields, e.g. `#[ts(rename = "..")]`,
+/// `#[ts(type = "..")]` or `#[ts(optional)]`.
+/// Methods, generic functions and arguments which are patterns are not supported.
+pub use ts_rs_macros::function;
 
+/// Generates a TypeScript client interface for a trait, e.g. one describing the endpoints of an API.
+///
+/// The trait is kept as it is. [TS] is implemented for a struct named after the trait with a
+/// `Client` suffix, which is declared as an `interface` with a method for every method of the
+/// trait, returning a `Promise`. The types of the arguments and return types must implement [TS],
+/// and are imported from their files when exported. For methods returning `Result<T, E>`, the
+/// promise resolves to `T`. `async` methods and methods returning `impl Future<Output = T>` are
+/// supported as well.
+/// ```
+/// # use ts_rs::TS;
+/// # #[derive(TS)] struct User { name: String }
+/// #[ts_rs::service]
+/// trait UserService {
+///     async fn get_user(&self, id: u32) -> Result<User, String>;
+///     fn delete_user(&mut self, id: u32);
+/// }
+///
+/// assert_eq!(
+///     UserServiceClient::decl(&ts_rs::Config::new()),
+///     "interface UserService { getUser(id: number): Promise<User>, deleteUser(id: number): Promise<void>, }"
+/// );
+/// ```
+///
+/// ### attributes
+/// - **`#[ts_rs::service(export)]`** \
+///   Generates a test which exports the interface to `<name>.ts`, just like `#[ts(export)]`
+///
+/// - **`#[ts_rs::service(export_to = "..")]`** \
+///   Specifies where the interface should be exported to, just like `#[ts(export_to = "..")]`
+///
+/// - **`#[ts_rs::service(rename = "..")]`** \
+///   Sets the TypeScript name of the interface, which defaults to the name of the trait
+///
+/// - **`#[ts_rs::service(rename_all = "..")]`** \
+///   Renames all methods, which are converted to camelCase by default.
+///   Valid values are the same as for `#[ts(rename_all = "..")]`
+///
+/// - **`#[ts_rs::service(map)]`** or **`#[ts_rs::service(map = "..")]`** \
+///   Additionally implements [TS] for a struct with a `Map` suffix, declaring a type which maps
+///   every method to its request and response, e.g.
+///   `type UserServiceMap = { getUser: { req: { id: number, }, res: User }, };`.
+///   It is exported into the same file as the interface, and named `<name>Map` unless a name is
+///   given.
+///
+/// - **`#[ts_rs::service(crate = "..")]`** \
+///   Generates code which references the module passed to it instead of defaulting to `::ts_rs`
+///
+/// ### method attributes
+/// - **`#[ts(rename = "..")]`** \
+///   Sets the TypeScript name of the method
+///
+/// - **`#[ts(skip)]`** \
+///   Skips the method. Methods without a receiver and generic methods must be skipped.
+pub use ts_rs_macros::service;
+
+pub use crate::export::{check_files, remove_stale_files, stale_files, ExportError};
+#[cfg(feature = "format")]
+pub use crate::export::{FormatOptions, QuoteStyle};
+#[cfg(feature = "registry")]
+pub use crate::registry::export_registered;
+#[cfg(feature = "registry")]
+#[doc(hidden)]
+pub use crate::registry::Registration;
+#[cfg(feature = "registry")]
+#[doc(hidden)]
+pub use inventory as __inventory;
+
+pub mod ast;
 #[cfg(feature = "chrono-impl")]
 mod chrono;
+mod config;
 mod export;
+mod guard;
 #[cfg(feature = "jiff-impl")]
 mod jiff;
+mod json_schema;
+#[cfg(feature = "registry")]
+mod registry;
 #[cfg(feature = "serde-json-impl")]
 mod serde_json;
+#[cfg(feature = "testing")]
+pub mod testing;
 #[cfg(feature = "tokio-impl")]
 mod tokio;
+#[cfg(feature = "values")]
+mod value;
+#[cfg(feature = "zod")]
+mod zod;
 
 /// A type which can be represented in TypeScript.
 /// Most of the time, you'd want to derive this trait instead of implementing it manually.
@@ -177,6 +334,8 @@ mod tokio;
 ///
 /// If, for some reason, you need to do this during runtime or cannot use `#[ts(export)]`, bindings
 /// can be exported manually using [`TS::export_all`], [`TS::export`], or [`TS::export_to_string`].
+/// With the `registry` feature, all types annotated with `#[ts(export)]` can also be exported at
+/// once using `export_registered`, without running any tests.
 ///
 /// ### serde compatibility
 /// With the `serde-compat` feature enabled (default), ts-rs parses serde attributes and adjusts the generated typescript bindings accordingly.  
@@ -192,14 +351,13 @@ mod tokio;
 ///
 /// - **`#[ts(export)]`** \
 ///   Generates a test which will export the type, by default to `bindings/<name>.ts` when running
-///   `cargo test`. The default base directory can be overridden with the `TS_RS_EXPORT_DIR` environment variable.
-///   Adding the variable to a project's [config.toml](https://doc.rust-lang.org/cargo/reference/config.html#env) can
-///   make it easier to manage.
+///   `cargo test`. The default base directory can be overridden in the crate's `ts-rs.toml` or
+///   with the `TS_RS_EXPORT_DIR` environment variable, see [`Config::load`].
 ///   ```toml
-///   # <project-root>/.cargo/config.toml
-///   [env]
-///   TS_RS_EXPORT_DIR = { value = "<OVERRIDE_DIR>", relative = true }
+///   # <crate-root>/ts-rs.toml
+///   out-dir = "<OVERRIDE_DIR>"
 ///   ```
+///   With the `registry` feature, the type is also registered for `export_registered`.
 ///
 /// - **`#[ts(export_to = "..")]`** \
 ///   Specifies where the type should be exported to. Defaults to `<name>.ts`.
@@ -287,6 +445,22 @@ mod tokio;
 ///   If `#[ts(optional_fields)]` is present, `t?: T` is generated for every `Option<T>` field of the struct.
 ///   If `#[ts(optional_fields = nullable)]` is present, `t?: T | null` is generated for every `Option<T>` field of the struct.
 ///
+/// - **`#[ts(transparent)]`** \
+///   Represents the struct by the type of its only field, just like a newtype. \
+///   All other fields of the struct must be skipped.
+///
+/// - **`#[ts(interface)]`** \
+///   Declares the struct as an `interface` instead of a type alias, see [`Config::with_interfaces`].
+///
+/// - **`#[ts(readonly)]`** \
+///   Marks every field of the struct as `readonly`, and makes arrays and tuples within their
+///   types read-only. On a tuple struct, the tuple is read-only. See [`Config::with_readonly`].
+///
+/// - **`#[ts(brand)]`** \
+///   Brands a newtype or `transparent` struct, so that it is not interchangeable with its inner
+///   type, e.g. `type UserId = string & { readonly __brand: "UserId" }`.
+///   See [`Config::with_branded_newtypes`].
+///
 /// ### struct field attributes
 ///
 /// - **`#[ts(type = "..")]`** \
@@ -317,6 +491,10 @@ mod tokio;
 /// - **`#[ts(flatten)]`** \
 ///   Flatten this field, inlining all the keys of the field's type into its parent.
 ///
+/// - **`#[ts(readonly)]`** \
+///   Marks this field as `readonly`. Arrays and tuples within its type become
+///   `ReadonlyArray<T>` and `readonly [A, B]`, see [`Config::with_readonly`].
+///
 /// ### enum attributes
 ///
 /// - **`#[ts(tag = "..")]`** \
@@ -346,6 +524,13 @@ mod tokio;
 ///   If `#[ts(repr(enum = name))]` is used, all variants without a discriminant will be exported
 ///   as `VariantName = "VariantName"`
 ///
+/// - **`#[ts(export_values)]`** \
+///   Additionally exports an array containing all variants of a unit-only enum, e.g.
+///   `export const COLOR_VALUES = ["Red", "Green"] as const;`. Variants are renamed like in the
+///   type, and for `#[ts(repr(enum))]`, the members of the enum are used, e.g. `[Color.Red]`. \
+///   The constant is named after the enum, unless a name is given using
+///   `#[ts(export_values = "ALL_COLORS")]`.
+///
 /// ### enum variant attributes
 ///
 /// - **`#[ts(rename = "..")]`** \
@@ -428,9 +613,39 @@ pub trait TS {
         panic!("{} cannot be declared", Self::name(cfg))
     }
 
+    /// Returns whether this type is declared as an `interface`, given whether interfaces are
+    /// enabled using [`Config::with_interfaces`].
+    #[doc(hidden)]
+    fn is_interface(_: bool) -> bool {
+        false
+    }
+
+    /// The type of the `interface` declaring this type, if it is declared as one. It is either an
+    /// object, or the intersection of an object with the types the interface extends.
+    #[doc(hidden)]
+    fn interface_ast(_: &Config) -> Option<ast::TsType> {
+        None
+    }
+
+    /// The name of the constant declared for an enum annotated with `#[ts(export_values)]` and
+    /// its variants, e.g. `("COLOR_VALUES", ["\"Red\"", "\"Green\""])`.
+    #[doc(hidden)]
+    fn variant_values(_: &Config) -> Option<(String, Vec<String>)> {
+        None
+    }
+
     /// Name of this type in TypeScript, including generic parameters
     fn name(cfg: &Config) -> String;
 
+    /// The name of this type as a structured [`ast::TsType`], usually an
+    /// [`ast::TsType::Reference`]. See [`TS::name`].
+    ///
+    /// Derived and built-in implementations build it directly. For other implementations, it is
+    /// parsed from [`TS::name`] by default.
+    fn name_ast(cfg: &Config) -> ast::TsType {
+        ast::TsType::parse(&Self::name(cfg))
+    }
+
     /// Formats this types definition in TypeScript, e.g `{ user_id: number }`.
     /// This function will panic if the type cannot be inlined.
     fn inline(cfg: &Config) -> String;
@@ -441,6 +656,75 @@ pub trait TS {
         panic!("{} cannot be flattened", Self::name(cfg))
     }
 
+    /// The definition of this type as a structured [`ast::TsType`], e.g.
+    /// `TsType::Object(vec![Property { name: "user_id", .. }])`. See [`TS::inline`].
+    /// This function will panic if the type cannot be inlined.
+    ///
+    /// Derived and built-in implementations build it directly, and [`TS::inline`] is printed
+    /// from it. For other implementations, it is parsed from [`TS::inline`] by default. Anything
+    /// which cannot be parsed is kept as [`ast::TsType::Raw`].
+    fn inline_ast(cfg: &Config) -> ast::TsType {
+        ast::TsType::parse(&Self::inline(cfg))
+    }
+
+    /// The flattened definition of this type as a structured [`ast::TsType`].
+    /// See [`TS::inline_flattened`].
+    /// This function will panic if the type cannot be flattened.
+    ///
+    /// For implementations which are neither derived nor built-in, it is parsed from
+    /// [`TS::inline_flattened`] by default.
+    fn inline_flattened_ast(cfg: &Config) -> ast::TsType {
+        ast::TsType::parse(&Self::inline_flattened(cfg))
+    }
+
+    /// The declaration of this type as a structured [`ast::Declaration`], including its name,
+    /// generic parameters and docs. See [`TS::decl`].
+    /// This function will panic if the type cannot be declared.
+    ///
+    /// Derived implementations build it directly, and [`TS::decl`] is printed from it. For other
+    /// implementations, it is parsed from [`TS::decl`] and [`TS::docs`] by default.
+    fn decl_ast(cfg: &Config) -> ast::Declaration {
+        ast::Declaration::of::<Self>(cfg)
+    }
+
+    /// Type guard for this type, e.g.
+    /// `function isUser(value: unknown): value is User { return ...; }`.
+    /// This function will panic if the type has no declaration.
+    ///
+    /// The guard checks the structure of a value, including the tag of enum variants and optional
+    /// fields. For a dependency of this type, its own guard, e.g. `isRole`, is called. Generic
+    /// types receive a guard for every type parameter, e.g.
+    /// `function isPage<T>(value: unknown, isT: (value: unknown) => value is T): value is Page<T>`.
+    /// Types which are not understood, e.g. ones given by `#[ts(type = "..")]`, are not checked.
+    ///
+    /// Guards are only exported if enabled using [`Config::with_type_guards`].
+    fn guard(cfg: &Config) -> String
+    where
+        Self: 'static,
+    {
+        guard::guard::<Self>(cfg)
+    }
+
+    /// [Zod](https://zod.dev) schema of this type, e.g.
+    /// `const UserSchema = z.object({ user_id: z.number(), })`.
+    /// This function will panic if the type has no declaration.
+    ///
+    /// Dependencies are referenced lazily using `z.lazy(() => RoleSchema)`, so that schemas may
+    /// be declared in any order and recursive types are supported. Generic types are represented
+    /// by a function which receives a schema for every type parameter, e.g.
+    /// `const PageSchema = <T extends z.ZodTypeAny>(TSchema: T): z.ZodType<Page<z.infer<T>>, ..> => ..`.
+    /// Types which are not understood, e.g. ones given by `#[ts(type = "..")]`, are not validated.
+    /// The generated schemas use the API of Zod 3.
+    ///
+    /// Schemas are only exported if enabled using [`Config::with_zod`].
+    #[cfg(feature = "zod")]
+    fn zod(cfg: &Config) -> String
+    where
+        Self: 'static,
+    {
+        zod::schema::<Self>(cfg)
+    }
+
     /// Iterates over all dependency of this type.
     fn visit_dependencies(_: &mut impl TypeVisitor)
     where
@@ -468,6 +752,14 @@ pub trait TS {
                     deps.push(dep);
                 }
             }
+
+            fn direction(&self) -> Direction {
+                self.0.direction()
+            }
+
+            fn interfaces(&self) -> bool {
+                self.0.interfaces()
+            }
         }
 
         let mut deps: Vec<Dependency> = vec![];
@@ -489,10 +781,7 @@ pub trait TS {
     where
         Self: 'static,
     {
-        let relative_path = Self::output_path()
-            .ok_or_else(std::any::type_name::<Self>)
-            .map_err(ExportError::CannotBeExported)?;
-        let path = cfg.export_dir.join(relative_path);
+        let path = cfg.export_dir.join(export::output_path::<Self>(cfg)?);
 
         export::export_to::<Self, _>(cfg, path)
     }
@@ -528,6 +817,67 @@ pub trait TS {
         export::export_to_string::<Self>(cfg)
     }
 
+    /// Exports `value` as a TypeScript constant named `name` into the file of this type, e.g.
+    /// `export const DEFAULT_CONFIG: Config = { .. } as const;`.
+    /// The value is serialized using `serde_json`, and the types it uses are exported as well.
+    ///
+    /// To export a value of a type which cannot be exported itself, like `u64`, use
+    /// [`TS::export_value_to`] or [`export_value!`].
+    #[cfg(feature = "values")]
+    fn export_value(value: &Self, name: &str, cfg: &Config) -> Result<(), ExportError>
+    where
+        Self: serde::Serialize + 'static,
+    {
+        let path = export::output_path::<Self>(cfg)?;
+        export::export_value(value, name, &path, cfg)
+    }
+
+    /// Exports `value` as a TypeScript constant named `name` into the file `path`, relative to
+    /// the output directory. See [`TS::export_value`].
+    #[cfg(feature = "values")]
+    fn export_value_to(
+        value: &Self,
+        name: &str,
+        path: impl AsRef<Path>,
+        cfg: &Config,
+    ) -> Result<(), ExportError>
+    where
+        Self: serde::Serialize + 'static,
+    {
+        export::export_value(value, name, path.as_ref(), cfg)
+    }
+
+    /// Generates a [JSON Schema](https://json-schema.org/draft/2020-12) for this type,
+    /// returning a [`String`].
+    /// This function will fail if the type cannot be exported.
+    ///
+    /// The schema describes the same values as the TypeScript bindings, and respects renames,
+    /// tagging, `#[ts(flatten)]`, optional fields and docs. Exported dependencies are referenced
+    /// using a relative `$ref`, e.g. `"$ref": "./Role.schema.json"`. Since JSON Schema has no
+    /// generics, generic types with concrete type arguments are inlined, and type parameters
+    /// accept any value.
+    fn json_schema(cfg: &Config) -> Result<String, ExportError>
+    where
+        Self: 'static,
+    {
+        json_schema::json_schema::<Self>(cfg)
+    }
+
+    /// Manually export the JSON Schema of this type to the filesystem, together with the schemas
+    /// of all of its dependencies. See [`TS::json_schema`].
+    ///
+    /// Every schema is written to a `.schema.json` file named after the type, next to the file
+    /// its TypeScript bindings are exported to, e.g. `bindings/User.schema.json`.
+    /// Like the TypeScript bindings, schemas are only compared to the files on disk if
+    /// [`Config::with_check_only`] is used, and are removed by [`remove_stale_files`] once they
+    /// are no longer exported.
+    fn export_json_schema(cfg: &Config) -> Result<(), ExportError>
+    where
+        Self: 'static,
+    {
+        json_schema::export_all::<Self>(cfg)
+    }
+
     /// Returns the output path to where `T` should be exported, relative to the output directory.
     /// The returned path does _not_ include any base directory.
     ///
@@ -547,6 +897,18 @@ pub trait TS {
 /// or generic parameter respectively.
 pub trait TypeVisitor: Sized {
     fn visit<T: TS + 'static + ?Sized>(&mut self);
+
+    /// The [`Direction`] for which bindings are being generated. \
+    /// Dependencies which are not part of the bindings in this direction are not visited.
+    fn direction(&self) -> Direction {
+        Direction::Both
+    }
+
+    /// Whether structs are declared as interfaces, see [`Config::with_interfaces`]. \
+    /// Types flattened into an interface are extended by it, which makes them a dependency.
+    fn interfaces(&self) -> bool {
+        false
+    }
 }
 
 /// A typescript type which is depended upon by other types.
@@ -577,7 +939,126 @@ impl Dependency {
     }
 }
 
+/// Declares a constant or static and exports it as a TypeScript constant when `cargo test` is
+/// run, just like `#[ts(export)]` does for types. See [`TS::export_value`].
+///
+/// The value is exported to `<NAME>.ts`, unless another file is given using `export_to`.
+/// ```
+/// # use ts_rs::export_value;
+/// export_value!(pub MAX_UPLOAD: u64 = 1024);
+/// export_value!(static ALLOWED_EXTENSIONS: [&str; 2] = ["png", "jpg"], export_to = "limits.ts");
+/// ```
+#[cfg(feature = "values")]
+#[macro_export]
+macro_rules! export_value {
+    ($(#[$attr:meta])* $vis:vis static $name:ident: $ty:ty = $value:expr $(, export_to = $path:literal)? $(,)?) => {
+        $(#[$attr])*
+        $vis static $name: $ty = $value;
+        $crate::export_value!(@test $name: $ty $(, $path)?);
+    };
+    ($(#[$attr:meta])* $vis:vis $name:ident: $ty:ty = $value:expr $(, export_to = $path:literal)? $(,)?) => {
+        $(#[$attr])*
+        $vis const $name: $ty = $value;
+        $crate::export_value!(@test $name: $ty $(, $path)?);
+    };
+    (@test $name:ident: $ty:ty) => {
+        $crate::export_value!(@test $name: $ty, concat!(stringify!($name), ".ts"));
+    };
+    (@test $name:ident: $ty:ty, $path:expr) => {
+        $crate::__register!($crate::Registration {
+            path: || ::std::option::Option::Some(::std::path::PathBuf::from($path)),
+            name: |_| ::std::string::String::from(stringify!($name)),
+            export: |cfg, _| <$ty as $crate::TS>::export_value_to(&$name, stringify!($name), $path, cfg),
+        });
+
+        #[cfg(test)]
+        #[allow(non_snake_case, unused_imports)]
+        mod $name {
+            use super::*;
+
+            #[test]
+            fn export_value() -> ::std::result::Result<(), $crate::__TestError> {
+                let cfg = $crate::Config::load()
+                    .map_err($crate::__TestError::context("could not load the configuration"))?;
+                <$ty as $crate::TS>::export_value_to(&super::$name, stringify!($name), $path, &cfg)
+                    .map_err($crate::__TestError::context("could not export value"))
+            }
+        }
+    };
+}
+
+/// Registers a type or value for [`export_registered`] if the `registry` feature is enabled
+#[cfg(feature = "registry")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __register {
+    ($registration:expr) => {
+        $crate::__inventory::submit! { $registration }
+    };
+}
+
+/// Registers a type or value for `export_registered` if the `registry` feature is enabled
+#[cfg(not(feature = "registry"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __register {
+    ($registration:expr) => {};
+}
+
+/// The error returned by the tests generated for `#[ts(export)]` and `export_value!`, which is
+/// printed as a readable message if a test fails
+#[doc(hidden)]
+pub struct __TestError(String);
+
+impl __TestError {
+    /// Describes an [`ExportError`], e.g. `could not export type: ..`
+    pub fn context(context: &'static str) -> impl FnOnce(ExportError) -> Self {
+        move |error| Self(format!("{context}: {error}"))
+    }
+}
+
+impl std::fmt::Debug for __TestError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(&self.0)
+    }
+}
+
+/// The direction in which values cross the boundary between Rust and TypeScript.
+///
+/// Some serde attributes, like `#[serde(into = "..")]`, `#[serde(skip_deserializing)]` or
+/// `#[serde(rename(serialize = "..", deserialize = ".."))]`, cause a type to be serialized
+/// differently than it is deserialized. Using [`Config::with_direction`], bindings can be
+/// generated for just one of these directions. To get separate bindings for requests and
+/// responses, export them twice, using a different [`Config::with_out_dir`] for each direction.
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub enum Direction {
+    /// Bindings are used for values sent in both directions. \
+    /// Where the serialized and deserialized representation of a type differ, the serialized
+    /// representation is used.
+    #[default]
+    Both,
+    /// Bindings describe values serialized by Rust, e.g. responses sent to a frontend.
+    Serialize,
+    /// Bindings describe values deserialized by Rust, e.g. requests sent by a frontend.
+    Deserialize,
+}
+
+/// How the declarations of exported types are laid out, see [`Config::with_layout`].
+#[derive(Copy, Clone, Debug, Default, Eq, PartialEq, Hash)]
+pub enum Layout {
+    /// Every declaration is written on a single line, e.g.
+    /// `export type User = { id: number, name: string, };`
+    SingleLine,
+    /// The object declared by a type or interface is written with one property per line, with
+    /// the docs of every property above it. Other lists, like nested objects, tuples or
+    /// parameters, and the variants of unions are only broken into multiple lines if they do
+    /// not fit within the line width.
+    #[default]
+    MultiLine,
+}
+
 /// Configuration that affects the generation of TypeScript bindings and how they are exported.  
+#[derive(Clone)]
 pub struct Config {
     // TS_RS_LARGE_INT
     large_int_type: String,
@@ -588,6 +1069,32 @@ pub struct Config {
     // TS_RS_IMPORT_EXTENSION
     import_extension: Option<String>,
     array_tuple_limit: usize,
+    // TS_RS_DIRECTION
+    direction: Direction,
+    // TS_RS_TYPE_GUARDS
+    type_guards: bool,
+    // TS_RS_SINGLE_FILE
+    single_file: Option<PathBuf>,
+    // TS_RS_EXPORT_BARRELS
+    barrels: bool,
+    // TS_RS_CHECK
+    check_only: bool,
+    // TS_RS_INTERFACES
+    interfaces: bool,
+    // TS_RS_BRANDED_NEWTYPES
+    branded_newtypes: bool,
+    // TS_RS_READONLY
+    readonly: bool,
+    brand_constructors: bool,
+    // TS_RS_LAYOUT
+    layout: Layout,
+    indent: String,
+    line_width: usize,
+    // TS_RS_ZOD
+    #[cfg(feature = "zod")]
+    zod: bool,
+    #[cfg(feature = "format")]
+    formatting: FormatOptions,
 }
 
 impl Default for Config {
@@ -598,6 +1105,22 @@ impl Default for Config {
             export_dir: "./bindings".into(),
             import_extension: None,
             array_tuple_limit: 64,
+            direction: Direction::Both,
+            type_guards: false,
+            single_file: None,
+            barrels: false,
+            check_only: false,
+            interfaces: false,
+            branded_newtypes: false,
+            brand_constructors: false,
+            readonly: false,
+            layout: Layout::MultiLine,
+            indent: "  ".to_owned(),
+            line_width: 80,
+            #[cfg(feature = "zod")]
+            zod: false,
+            #[cfg(feature = "format")]
+            formatting: FormatOptions::default(),
         }
     }
 }
@@ -612,11 +1135,43 @@ impl Config {
     ///
     /// | Variable                 | Description                                                         | Default      |
     /// |--------------------------|---------------------------------------------------------------------|--------------|
+    /// | `TS_RS_BRANDED_NEWTYPES` | Brand newtypes, see `Config::with_branded_newtypes`                 | *disabled*   |
+    /// | `TS_RS_CHECK`            | Check bindings are up to date, see `Config::with_check_only`        | *disabled*   |
+    /// | `TS_RS_DIRECTION`        | Direction of the generated bindings, see `Config::with_direction`   | `both`       |
+    /// | `TS_RS_EXPORT_BARRELS`   | Generate `index.ts` barrels, see `Config::with_barrels`             | *disabled*   |
     /// | `TS_RS_EXPORT_DIR`       | Base directory into which bindings will be exported                 | `./bindings` |
     /// | `TS_RS_IMPORT_EXTENSION` | File extension used in `import` statements                          | *none*       |
+    /// | `TS_RS_INTERFACES`       | Declare structs as interfaces, see `Config::with_interfaces`        | *disabled*   |
     /// | `TS_RS_LARGE_INT`        | Binding used for large integer types (`i64`, `u64`, `i128`, `u128`) | `bigint`     |
+    /// | `TS_RS_LAYOUT`           | Layout of declarations, see `Config::with_layout`                   | `multi-line` |
+    /// | `TS_RS_READONLY`         | Make all types read-only, see `Config::with_readonly`               | *disabled*   |
+    /// | `TS_RS_SINGLE_FILE`      | Bundle all types into one file, see `Config::with_single_file`      | *none*       |
+    /// | `TS_RS_TYPE_GUARDS`      | Export a type guard with every type, see `Config::with_type_guards` | *disabled*   |
+    /// | `TS_RS_ZOD`              | Export Zod schemas, see `Config::with_zod` (`zod` feature)          | *disabled*   |
+    ///
+    /// Options which can be enabled or disabled accept `1`, `true`, `on` and `yes`, or `0`,
+    /// `false`, `off` and `no`. Empty variables are ignored.
+    ///
+    /// Variables with an invalid value are ignored with a warning. [`Config::try_from_env`] and
+    /// [`Config::load`], which also reads the configuration file of a crate, report them as
+    /// [`ExportError::InvalidConfig`] instead.
     pub fn from_env() -> Self {
-        let mut cfg = Self::default();
+        match Self::default().with_env(false) {
+            Ok(cfg) => cfg,
+            Err(_) => unreachable!("invalid variables are ignored"),
+        }
+    }
+
+    /// Creates a new `Config` with values read from environment variables, like
+    /// [`Config::from_env`], but fails if a variable has an invalid value.
+    pub fn try_from_env() -> Result<Self, ExportError> {
+        Self::default().with_env(true)
+    }
+
+    /// Overrides the options set by environment variables, see [`Config::from_env`].
+    /// Invalid values are reported if `strict`, and ignored with a warning otherwise.
+    fn with_env(self, strict: bool) -> Result<Self, ExportError> {
+        let mut cfg = self;
 
         if let Ok(ty) = std::env::var("TS_RS_LARGE_INT") {
             cfg = cfg.with_large_int(ty);
@@ -632,12 +1187,71 @@ impl Config {
             }
         }
 
-        #[allow(deprecated)]
-        if let Ok("1" | "true" | "on" | "yes") = std::env::var("TS_RS_USE_V11_HASHMAP").as_deref() {
-            cfg = cfg.with_v11_hashmap();
+        let direction = env_var("TS_RS_DIRECTION").map(|direction| match direction.as_str() {
+            "both" => Ok(Direction::Both),
+            "serialize" => Ok(Direction::Serialize),
+            "deserialize" => Ok(Direction::Deserialize),
+            _ => {
+                let expected = "expected `both`, `serialize` or `deserialize`";
+                Err(invalid_env_var("TS_RS_DIRECTION", &direction, expected))
+            }
+        });
+        if let Some(direction) = valid(direction.transpose(), strict)? {
+            cfg.direction = direction;
         }
 
-        cfg
+        if let Some(type_guards) = valid(env_bool("TS_RS_TYPE_GUARDS"), strict)? {
+            cfg.type_guards = type_guards;
+        }
+
+        if let Some(barrels) = valid(env_bool("TS_RS_EXPORT_BARRELS"), strict)? {
+            cfg.barrels = barrels;
+        }
+
+        if let Some(check_only) = valid(env_bool("TS_RS_CHECK"), strict)? {
+            cfg.check_only = check_only;
+        }
+
+        if let Some(interfaces) = valid(env_bool("TS_RS_INTERFACES"), strict)? {
+            cfg.interfaces = interfaces;
+        }
+
+        if let Some(branded_newtypes) = valid(env_bool("TS_RS_BRANDED_NEWTYPES"), strict)? {
+            cfg.branded_newtypes = branded_newtypes;
+        }
+
+        if let Some(readonly) = valid(env_bool("TS_RS_READONLY"), strict)? {
+            cfg.readonly = readonly;
+        }
+
+        let layout = env_var("TS_RS_LAYOUT").map(|layout| match layout.as_str() {
+            "single-line" => Ok(Layout::SingleLine),
+            "multi-line" => Ok(Layout::MultiLine),
+            _ => {
+                let expected = "expected `single-line` or `multi-line`";
+                Err(invalid_env_var("TS_RS_LAYOUT", &layout, expected))
+            }
+        });
+        if let Some(layout) = valid(layout.transpose(), strict)? {
+            cfg.layout = layout;
+        }
+
+        if let Ok(file) = std::env::var("TS_RS_SINGLE_FILE") {
+            if !file.trim().is_empty() {
+                cfg = cfg.with_single_file(file);
+            }
+        }
+
+        #[cfg(feature = "zod")]
+        if let Some(zod) = valid(env_bool("TS_RS_ZOD"), strict)? {
+            cfg.zod = zod;
+        }
+
+        if let Some(use_v11_hashmap) = valid(env_bool("TS_RS_USE_V11_HASHMAP"), strict)? {
+            cfg.use_v11_hashmap = use_v11_hashmap;
+        }
+
+        Ok(cfg)
     }
 
     /// Sets the TypeScript type used to represent large integers.
@@ -706,6 +1320,294 @@ impl Config {
     pub fn array_tuple_limit(&self) -> usize {
         self.array_tuple_limit
     }
+
+    /// Sets the [`Direction`] for which bindings are generated.  
+    /// This affects types which are serialized differently than they are deserialized, e.g.
+    /// because of `#[serde(into = "..")]`, `#[serde(from = "..")]`, `#[serde(skip_serializing)]`,
+    /// `#[serde(skip_deserializing)]` or `#[serde(rename(serialize = "..", deserialize = ".."))]`.
+    ///
+    /// Default: [`Direction::Both`]
+    pub fn with_direction(mut self, direction: Direction) -> Self {
+        self.direction = direction;
+        self
+    }
+
+    /// Returns the [`Direction`] for which bindings are generated.
+    pub fn direction(&self) -> Direction {
+        self.direction
+    }
+
+    /// When enabled, a type guard is exported together with every type, e.g.
+    /// `export function isUser(value: unknown): value is User { .. }`. See [`TS::guard`].
+    ///
+    /// Default: disabled
+    pub fn with_type_guards(mut self) -> Self {
+        self.type_guards = true;
+        self
+    }
+
+    /// Returns whether type guards are exported together with every type.
+    pub fn type_guards(&self) -> bool {
+        self.type_guards
+    }
+
+    /// Exports all types into a single file instead of the file given by
+    /// `#[ts(export_to = "..")]`. The path is relative to the output directory, e.g. `index.ts`.
+    ///
+    /// Since all declarations are placed in the same file, which is self-contained, no imports
+    /// are generated. Exporting one or more types using [`TS::export_all`] bundles them together
+    /// with all of their dependencies, sorted alphabetically. Types need to have distinct names.
+    ///
+    /// Default: `None`
+    pub fn with_single_file(mut self, file: impl Into<PathBuf>) -> Self {
+        self.single_file = Some(file.into());
+        self
+    }
+
+    /// Returns the file into which all types are exported, if any.
+    pub fn single_file(&self) -> Option<&Path> {
+        self.single_file.as_deref()
+    }
+
+    /// When enabled, an `index.ts` barrel is generated in every directory types are exported into,
+    /// re-exporting all of them, e.g. `export type { User } from "./User";`.
+    /// If type guards, Zod schemas or brand constructors are exported as well, they are re-exported
+    /// too.
+    ///
+    /// Barrels are merged with the contents written by other processes of the same run, so types
+    /// exported by other test binaries of one `cargo test` are kept. They are not generated for
+    /// directories containing types exported into `index.ts` itself.
+    ///
+    /// Default: disabled
+    pub fn with_barrels(mut self) -> Self {
+        self.barrels = true;
+        self
+    }
+
+    /// Returns whether `index.ts` barrels are generated.
+    pub fn barrels(&self) -> bool {
+        self.barrels
+    }
+
+    /// When enabled, exporting a type does not write anything to disk. Instead, the bindings
+    /// which would have been written are compared to the existing files, and
+    /// [`ExportError::OutOfDate`] is returned if they differ. This is useful to make sure the
+    /// bindings checked into a repository are up to date, e.g. in CI.
+    ///
+    /// Since other types exported into the same file might not have been checked yet, a file
+    /// may contain more than the bindings of the type being checked. Once all types are checked,
+    /// [`check_files`] compares the files exactly, and reports files which are no longer
+    /// generated, see [`stale_files`]. Barrels are not checked.
+    ///
+    /// Default: disabled
+    pub fn with_check_only(mut self) -> Self {
+        self.check_only = true;
+        self
+    }
+
+    /// Returns whether bindings are checked instead of written.
+    pub fn check_only(&self) -> bool {
+        self.check_only
+    }
+
+    /// When enabled, structs with named fields are declared as an `interface` instead of a type
+    /// alias, e.g. `export interface User { id: number, }`. To declare only some structs as
+    /// interfaces, use `#[ts(interface)]`.
+    ///
+    /// Flattened fields become `extends` clauses, e.g. `interface User extends Base { .. }`, if
+    /// the flattened type is an interface itself. Otherwise, for example if an enum is flattened,
+    /// the struct is still declared as a type alias.
+    ///
+    /// Default: disabled
+    pub fn with_interfaces(mut self) -> Self {
+        self.interfaces = true;
+        self
+    }
+
+    /// Returns whether structs are declared as interfaces.
+    pub fn interfaces(&self) -> bool {
+        self.interfaces
+    }
+
+    /// When enabled, every newtype, e.g. `struct UserId(Uuid)`, is branded, so that it is not
+    /// interchangeable with its inner type or other newtypes:
+    /// `type UserId = string & { readonly __brand: "UserId" }`. To brand only some types, use
+    /// `#[ts(brand)]`.
+    ///
+    /// Default: disabled
+    pub fn with_branded_newtypes(mut self) -> Self {
+        self.branded_newtypes = true;
+        self
+    }
+
+    /// Returns whether newtypes are branded.
+    pub fn branded_newtypes(&self) -> bool {
+        self.branded_newtypes
+    }
+
+    /// When enabled, a function casting a value to a branded type is exported together with the
+    /// type, e.g. `export const UserId = (value: string): UserId => value as UserId;`.
+    ///
+    /// Default: disabled
+    pub fn with_brand_constructors(mut self) -> Self {
+        self.brand_constructors = true;
+        self
+    }
+
+    /// Returns whether constructors are exported for branded types.
+    pub fn brand_constructors(&self) -> bool {
+        self.brand_constructors
+    }
+
+    /// When enabled, the generated types are read-only: every property is marked `readonly`,
+    /// arrays become `ReadonlyArray<T>` and tuples `readonly [A, B]`. This is useful if the
+    /// values are never mutated, e.g. in the state of a frontend. To make only some properties
+    /// read-only, use `#[ts(readonly)]`.
+    ///
+    /// Default: disabled
+    pub fn with_readonly(mut self) -> Self {
+        self.readonly = true;
+        self
+    }
+
+    /// Returns whether the generated types are read-only.
+    pub fn readonly(&self) -> bool {
+        self.readonly
+    }
+
+    /// Sets the [`Layout`] of exported declarations. By default, objects are written with one
+    /// property per line, and long unions with one variant per line:
+    /// ```ts
+    /// export type User = {
+    ///   id: number,
+    ///   role: "Admin" | "User",
+    /// };
+    /// ```
+    /// This only affects the files written when exporting types, not [`TS::decl`]. If the
+    /// `format` feature is enabled, declarations are laid out by the formatter instead.
+    ///
+    /// Default: [`Layout::MultiLine`]
+    pub fn with_layout(mut self, layout: Layout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    /// Returns the [`Layout`] of exported declarations.
+    pub fn layout(&self) -> Layout {
+        self.layout
+    }
+
+    /// Sets the string used to indent lines when declarations are laid out over multiple lines,
+    /// e.g. `"    "` or `"\t"`.
+    ///
+    /// Default: two spaces
+    pub fn with_indent(mut self, indent: impl Into<String>) -> Self {
+        self.indent = indent.into();
+        self
+    }
+
+    /// Returns the string used to indent lines.
+    pub fn indent(&self) -> &str {
+        &self.indent
+    }
+
+    /// Sets the width after which lists and unions within declarations are broken into multiple
+    /// lines, see [`Layout::MultiLine`].
+    ///
+    /// Default: `80`
+    pub fn with_line_width(mut self, width: usize) -> Self {
+        self.line_width = width;
+        self
+    }
+
+    /// Returns the width after which lists and unions are broken into multiple lines.
+    pub fn line_width(&self) -> usize {
+        self.line_width
+    }
+
+    /// Returns this configuration with [`Config::with_readonly`] enabled. Used for the types of
+    /// fields marked with `#[ts(readonly)]`.
+    #[doc(hidden)]
+    pub fn to_readonly(&self) -> std::borrow::Cow<'_, Self> {
+        match self.readonly {
+            true => std::borrow::Cow::Borrowed(self),
+            false => std::borrow::Cow::Owned(self.clone().with_readonly()),
+        }
+    }
+
+    /// When enabled, every type is exported as a [Zod](https://zod.dev) schema together with the
+    /// type inferred from it, e.g.
+    /// `export const UserSchema = z.object({ .. });` and
+    /// `export type User = z.infer<typeof UserSchema>;`. See [`TS::zod`].
+    ///
+    /// Default: disabled
+    #[cfg(feature = "zod")]
+    pub fn with_zod(mut self) -> Self {
+        self.zod = true;
+        self
+    }
+
+    /// Returns whether types are exported as Zod schemas.
+    #[cfg(feature = "zod")]
+    pub fn zod(&self) -> bool {
+        self.zod
+    }
+
+    /// Sets the options of the formatter used by the `format` feature, e.g. the indentation and
+    /// quote style. To use the settings of a project, read them using
+    /// [`FormatOptions::from_dprint_json`] or [`FormatOptions::from_editorconfig`].
+    ///
+    /// Default: the style of `deno fmt`, see [`FormatOptions`]
+    #[cfg(feature = "format")]
+    pub fn with_formatting(mut self, options: FormatOptions) -> Self {
+        self.formatting = options;
+        self
+    }
+
+    /// Returns the options of the formatter used by the `format` feature.
+    #[cfg(feature = "format")]
+    pub fn formatting(&self) -> &FormatOptions {
+        &self.formatting
+    }
+}
+
+/// Reads the environment variable `name`, treating an empty value like an unset variable
+fn env_var(name: &str) -> Option<String> {
+    std::env::var(name)
+        .ok()
+        .filter(|value| !value.trim().is_empty())
+}
+
+/// Reads a boolean from the environment variable `name`, e.g. `1`, `true`, `0` or `false`
+fn env_bool(name: &str) -> Result<Option<bool>, ExportError> {
+    let Some(value) = env_var(name) else {
+        return Ok(None);
+    };
+
+    match value.to_ascii_lowercase().as_str() {
+        "1" | "true" | "on" | "yes" => Ok(Some(true)),
+        "0" | "false" | "off" | "no" => Ok(Some(false)),
+        _ => Err(invalid_env_var(name, &value, "expected `true` or `false`")),
+    }
+}
+
+/// Passes on the value of an environment variable if it is valid. Otherwise, the error is
+/// returned if `strict`, and the variable is ignored with a warning if not.
+fn valid<T>(value: Result<Option<T>, ExportError>, strict: bool) -> Result<Option<T>, ExportError> {
+    match value {
+        Err(e) if !strict => {
+            eprintln!("warning: {e}, ignoring it");
+            Ok(None)
+        }
+        value => value,
+    }
+}
+
+fn invalid_env_var(name: &str, value: &str, expected: &str) -> ExportError {
+    ExportError::InvalidConfig {
+        path: None,
+        reason: format!("invalid value `{value}` for `{name}`, {expected}"),
+    }
 }
 
 #[doc(hidden)]
@@ -728,8 +1630,10 @@ macro_rules! impl_primitives {
         impl TS for $ty {
             type WithoutGenerics = Self;
             type OptionInnerType = Self;
-            fn name(_: &$crate::Config) -> String { String::from($l) }
+            fn name(cfg: &$crate::Config) -> String { <Self as $crate::TS>::name_ast(cfg).to_string() }
             fn inline(cfg: &$crate::Config) -> String { <Self as $crate::TS>::name(cfg) }
+            fn name_ast(_: &$crate::Config) -> $crate::ast::TsType { $crate::ast::TsType::parse($l) }
+            fn inline_ast(cfg: &$crate::Config) -> $crate::ast::TsType { <Self as $crate::TS>::name_ast(cfg) }
         }
     )*)* };
 }
@@ -742,6 +1646,8 @@ macro_rules! impl_large_integers {
             type OptionInnerType = Self;
             fn name(cfg: &$crate::Config) -> String { cfg.large_int_type.clone() }
             fn inline(cfg: &$crate::Config) -> String { <Self as $crate::TS>::name(cfg) }
+            fn name_ast(cfg: &$crate::Config) -> $crate::ast::TsType { $crate::ast::TsType::parse(&cfg.large_int_type) }
+            fn inline_ast(cfg: &$crate::Config) -> $crate::ast::TsType { <Self as $crate::TS>::name_ast(cfg) }
         }
     )* };
 }
@@ -753,11 +1659,15 @@ macro_rules! impl_tuples {
             type WithoutGenerics = (Dummy, );
             type OptionInnerType = Self;
             fn name(cfg: &$crate::Config) -> String {
-                format!("[{}]", [$(<$i as $crate::TS>::name(cfg)),*].join(", "))
+                <Self as $crate::TS>::name_ast(cfg).to_string()
             }
             fn inline(_: &$crate::Config) -> String {
                 panic!("tuple cannot be inlined!");
             }
+            fn name_ast(cfg: &$crate::Config) -> $crate::ast::TsType {
+                let elements = vec![$(<$i as $crate::TS>::name_ast(cfg)),*];
+                readonly(cfg, ast::TsType::Tuple(elements.into_iter().map(ast::Element::new).collect()))
+            }
             fn visit_generics(v: &mut impl TypeVisitor)
             where
                 Self: 'static
@@ -788,6 +1698,9 @@ macro_rules! impl_wrapper {
             fn name(cfg: &$crate::Config) -> String { <T as $crate::TS>::name(cfg) }
             fn inline(cfg: &$crate::Config) -> String { <T as $crate::TS>::inline(cfg) }
             fn inline_flattened(cfg: &$crate::Config) -> String { <T as $crate::TS>::inline_flattened(cfg) }
+            fn name_ast(cfg: &$crate::Config) -> $crate::ast::TsType { <T as $crate::TS>::name_ast(cfg) }
+            fn inline_ast(cfg: &$crate::Config) -> $crate::ast::TsType { <T as $crate::TS>::inline_ast(cfg) }
+            fn inline_flattened_ast(cfg: &$crate::Config) -> $crate::ast::TsType { <T as $crate::TS>::inline_flattened_ast(cfg) }
             fn visit_dependencies(v: &mut impl TypeVisitor)
             where
                 Self: 'static,
@@ -818,6 +1731,9 @@ macro_rules! impl_shadow {
             fn name(cfg: &$crate::Config) -> String { <$s as $crate::TS>::name(cfg) }
             fn inline(cfg: &$crate::Config) -> String { <$s as $crate::TS>::inline(cfg) }
             fn inline_flattened(cfg: &$crate::Config) -> String { <$s as $crate::TS>::inline_flattened(cfg) }
+            fn name_ast(cfg: &$crate::Config) -> $crate::ast::TsType { <$s as $crate::TS>::name_ast(cfg) }
+            fn inline_ast(cfg: &$crate::Config) -> $crate::ast::TsType { <$s as $crate::TS>::inline_ast(cfg) }
+            fn inline_flattened_ast(cfg: &$crate::Config) -> $crate::ast::TsType { <$s as $crate::TS>::inline_flattened_ast(cfg) }
             fn visit_dependencies(v: &mut impl $crate::TypeVisitor)
             where
                 Self: 'static,
@@ -832,22 +1748,47 @@ macro_rules! impl_shadow {
             }
             fn decl(cfg: &$crate::Config) -> String { <$s as $crate::TS>::decl(cfg) }
             fn decl_concrete(cfg: &$crate::Config) -> String { <$s as $crate::TS>::decl_concrete(cfg) }
+            fn decl_ast(cfg: &$crate::Config) -> $crate::ast::Declaration { <$s as $crate::TS>::decl_ast(cfg) }
+            fn is_interface(interfaces: bool) -> bool { <$s as $crate::TS>::is_interface(interfaces) }
+            fn interface_ast(cfg: &$crate::Config) -> Option<$crate::ast::TsType> { <$s as $crate::TS>::interface_ast(cfg) }
             fn output_path() -> Option<std::path::PathBuf> { <$s as $crate::TS>::output_path() }
         }
     };
 }
 
+/// Makes an array or tuple read-only if [`Config::with_readonly`] is enabled
+fn readonly(cfg: &Config, ty: ast::TsType) -> ast::TsType {
+    match cfg.readonly {
+        true => ast::TsType::Readonly(Box::new(ty)),
+        false => ty,
+    }
+}
+
 impl<T: TS> TS for Option<T> {
     type WithoutGenerics = Self;
     type OptionInnerType = T;
     const IS_OPTION: bool = true;
 
     fn name(cfg: &Config) -> String {
-        format!("{} | null", T::name(cfg))
+        Self::name_ast(cfg).to_string()
     }
 
     fn inline(cfg: &Config) -> String {
-        format!("{} | null", T::inline(cfg))
+        Self::inline_ast(cfg).to_string()
+    }
+
+    fn name_ast(cfg: &Config) -> ast::TsType {
+        ast::TsType::Union(vec![
+            T::name_ast(cfg),
+            ast::TsType::Keyword(ast::Keyword::Null),
+        ])
+    }
+
+    fn inline_ast(cfg: &Config) -> ast::TsType {
+        ast::TsType::Union(vec![
+            T::inline_ast(cfg),
+            ast::TsType::Keyword(ast::Keyword::Null),
+        ])
     }
 
     fn visit_dependencies(v: &mut impl TypeVisitor)
@@ -866,20 +1807,33 @@ impl<T: TS> TS for Option<T> {
     }
 }
 
+/// `{ Ok : T } | { Err : E }`
+fn result(ok: ast::TsType, err: ast::TsType) -> ast::TsType {
+    let variant = |name, ty| {
+        let property = ast::Property::with_style(name, ty, ast::PropertyStyle::Spaced);
+        ast::TsType::Object(vec![property])
+    };
+    ast::TsType::Union(vec![variant("Ok", ok), variant("Err", err)])
+}
+
 impl<T: TS, E: TS> TS for Result<T, E> {
     type WithoutGenerics = Result<Dummy, Dummy>;
     type OptionInnerType = Self;
 
     fn name(cfg: &Config) -> String {
-        format!("{{ Ok : {} }} | {{ Err : {} }}", T::name(cfg), E::name(cfg))
+        Self::name_ast(cfg).to_string()
     }
 
     fn inline(cfg: &Config) -> String {
-        format!(
-            "{{ Ok : {} }} | {{ Err : {} }}",
-            T::inline(cfg),
-            E::inline(cfg)
-        )
+        Self::inline_ast(cfg).to_string()
+    }
+
+    fn name_ast(cfg: &Config) -> ast::TsType {
+        result(T::name_ast(cfg), E::name_ast(cfg))
+    }
+
+    fn inline_ast(cfg: &Config) -> ast::TsType {
+        result(T::inline_ast(cfg), E::inline_ast(cfg))
     }
 
     fn visit_dependencies(v: &mut impl TypeVisitor)
@@ -905,16 +1859,27 @@ impl<T: TS> TS for Vec<T> {
     type WithoutGenerics = Vec<Dummy>;
     type OptionInnerType = Self;
 
-    fn ident(_: &Config) -> String {
-        "Array".to_owned()
+    fn ident(cfg: &Config) -> String {
+        match cfg.readonly {
+            true => "ReadonlyArray".to_owned(),
+            false => "Array".to_owned(),
+        }
     }
 
     fn name(cfg: &Config) -> String {
-        format!("Array<{}>", T::name(cfg))
+        Self::name_ast(cfg).to_string()
     }
 
     fn inline(cfg: &Config) -> String {
-        format!("Array<{}>", T::inline(cfg))
+        Self::inline_ast(cfg).to_string()
+    }
+
+    fn name_ast(cfg: &Config) -> ast::TsType {
+        readonly(cfg, ast::TsType::Array(Box::new(T::name_ast(cfg))))
+    }
+
+    fn inline_ast(cfg: &Config) -> ast::TsType {
+        readonly(cfg, ast::TsType::Array(Box::new(T::inline_ast(cfg))))
     }
 
     fn visit_dependencies(v: &mut impl TypeVisitor)
@@ -938,31 +1903,33 @@ impl<T: TS, const N: usize> TS for [T; N] {
     type OptionInnerType = Self;
 
     fn name(cfg: &Config) -> String {
+        Self::name_ast(cfg).to_string()
+    }
+
+    fn inline(cfg: &Config) -> String {
+        Self::inline_ast(cfg).to_string()
+    }
+
+    fn name_ast(cfg: &Config) -> ast::TsType {
         if N > cfg.array_tuple_limit() {
-            return <Vec<T> as crate::TS>::name(cfg);
+            return <Vec<T> as crate::TS>::name_ast(cfg);
         }
 
-        format!(
-            "[{}]",
-            (0..N)
-                .map(|_| T::name(cfg))
-                .collect::<Box<[_]>>()
-                .join(", ")
-        )
+        let elements = (0..N)
+            .map(|_| ast::Element::new(T::name_ast(cfg)))
+            .collect();
+        readonly(cfg, ast::TsType::Tuple(elements))
     }
 
-    fn inline(cfg: &Config) -> String {
+    fn inline_ast(cfg: &Config) -> ast::TsType {
         if N > cfg.array_tuple_limit() {
-            return <Vec<T> as crate::TS>::inline(cfg);
+            return <Vec<T> as crate::TS>::inline_ast(cfg);
         }
 
-        format!(
-            "[{}]",
-            (0..N)
-                .map(|_| T::inline(cfg))
-                .collect::<Box<[_]>>()
-                .join(", ")
-        )
+        let elements = (0..N)
+            .map(|_| ast::Element::new(T::inline_ast(cfg)))
+            .collect();
+        readonly(cfg, ast::TsType::Tuple(elements))
     }
 
     fn visit_dependencies(v: &mut impl TypeVisitor)
@@ -990,23 +1957,29 @@ impl<K: TS, V: TS, H> TS for HashMap<K, V, H> {
     }
 
     fn name(cfg: &Config) -> String {
-        let optional = K::IS_ENUM || cfg.use_v11_hashmap;
-        format!(
-            "{{ [key in {}]{}: {} }}",
-            K::name(cfg),
-            if optional { "?" } else { "" },
-            V::name(cfg),
-        )
+        Self::name_ast(cfg).to_string()
     }
 
     fn inline(cfg: &Config) -> String {
-        let optional = K::IS_ENUM || cfg.use_v11_hashmap;
-        format!(
-            "{{ [key in {}]{}: {} }}",
-            K::inline(cfg),
-            if optional { "?" } else { "" },
-            V::inline(cfg),
-        )
+        Self::inline_ast(cfg).to_string()
+    }
+
+    fn name_ast(cfg: &Config) -> ast::TsType {
+        ast::TsType::Record {
+            key: Box::new(K::name_ast(cfg)),
+            value: Box::new(V::name_ast(cfg)),
+            optional: K::IS_ENUM || cfg.use_v11_hashmap,
+            readonly: cfg.readonly,
+        }
+    }
+
+    fn inline_ast(cfg: &Config) -> ast::TsType {
+        ast::TsType::Record {
+            key: Box::new(K::inline_ast(cfg)),
+            value: Box::new(V::inline_ast(cfg)),
+            optional: K::IS_ENUM || cfg.use_v11_hashmap,
+            readonly: cfg.readonly,
+        }
     }
 
     fn visit_dependencies(v: &mut impl TypeVisitor)
@@ -1028,7 +2001,11 @@ impl<K: TS, V: TS, H> TS for HashMap<K, V, H> {
     }
 
     fn inline_flattened(cfg: &Config) -> String {
-        format!("({})", Self::inline(cfg))
+        Self::inline_flattened_ast(cfg).to_string()
+    }
+
+    fn inline_flattened_ast(cfg: &Config) -> ast::TsType {
+        Self::inline_ast(cfg)
     }
 }
 
@@ -1038,8 +2015,14 @@ impl<I: TS> TS for Range<I> {
     type OptionInnerType = Self;
 
     fn name(cfg: &Config) -> String {
-        let name = I::name(cfg);
-        format!("{{ start: {name}, end: {name}, }}")
+        Self::name_ast(cfg).to_string()
+    }
+
+    fn name_ast(cfg: &Config) -> ast::TsType {
+        ast::TsType::Object(vec![
+            ast::Property::new("start", I::name_ast(cfg)),
+            ast::Property::new("end", I::name_ast(cfg)),
+        ])
     }
 
     fn visit_dependencies(v: &mut impl TypeVisitor)
@@ -1176,11 +2159,29 @@ impl TS for Dummy {
         "Dummy".to_owned()
     }
 
+    fn name_ast(cfg: &Config) -> ast::TsType {
+        ast::TsType::reference(Self::name(cfg))
+    }
+
     fn inline(cfg: &Config) -> String {
         panic!("{} cannot be inlined", Self::name(cfg))
     }
 }
 
+/// Extracts the lines of rust doc comments, e.g. for [`ast::Property::docs`].
+/// Like [`format_docs`], it expects a `&[&str]` where each element corresponds to the value of
+/// one `#[doc]` attribute.
+#[doc(hidden)]
+pub fn docs_text(docs: &[&str]) -> Option<String> {
+    match docs {
+        [] => None,
+        // Multi-line block doc comment (/** ... */)
+        [doc] if doc.contains('\n') => Some(ast::parse_docs(doc)),
+        // Regular doc comment(s) (///), kept as they are
+        _ => Some(docs.join("\n")),
+    }
+}
+
 /// Formats rust doc comments, turning them into a JSDoc comments.
 /// Expects a `&[&str]` where each element corresponds to the value of one `#[doc]` attribute.
 /// This work is deferred to runtime, allowing expressions in `#[doc]`, e.g `#[doc = file!()]`.
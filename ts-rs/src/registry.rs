@@ -0,0 +1,87 @@
+//! A registry of every type annotated with `#[ts(export)]` and every value declared using
+//! `export_value!`, see [`export_registered`].
+//!
+//! Types and values are registered using [`inventory`](https://docs.rs/inventory), so that they
+//! can be exported from anywhere in the final binary, without generating and running a test for
+//! each of them.
+
+use std::{any::TypeId, collections::HashSet, path::PathBuf};
+
+use crate::{Config, ExportError, TS};
+
+/// Exports all types annotated with `#[ts(export)]`, together with their dependencies, and
+/// all values declared using `export_value!`. Requires the `registry` feature.
+///
+/// This allows exporting bindings without running `cargo test`, e.g. from a small binary:
+/// ```rust,ignore
+/// // src/bin/export-bindings.rs
+/// use my_crate as _;
+///
+/// fn main() -> Result<(), ts_rs::ExportError> {
+///     ts_rs::export_registered(&ts_rs::Config::load()?)
+/// }
+/// ```
+/// This binary is run by `cargo ts-rs export` and `cargo ts-rs check`, see the `cargo-ts-rs`
+/// crate. Only types within crates linked into the binary are registered, so the crates defining
+/// them need to be used, e.g. using `use my_crate as _;`.
+///
+/// Types are exported in the order of the files they are exported into, and every type is
+/// only exported once, so the output does not depend on the order in which the types were
+/// registered. Exporting stops at the first error. Afterwards, [`stale_files`] returns all
+/// files which are no longer generated. Using [`Config::with_check_only`], the files are
+/// checked instead, and [`check_files`] makes sure they contain nothing else and that no files
+/// are stale.
+///
+/// [`stale_files`]: crate::stale_files
+/// [`check_files`]: crate::check_files
+pub fn export_registered(cfg: &Config) -> Result<(), ExportError> {
+    let mut registered = inventory::iter::<Registration>
+        .into_iter()
+        .map(|registration| {
+            (
+                (registration.path)(),
+                (registration.name)(cfg),
+                registration,
+            )
+        })
+        .collect::<Vec<_>>();
+    registered.sort_by(|(a_path, a_name, _), (b_path, b_name, _)| {
+        (a_path, a_name).cmp(&(b_path, b_name))
+    });
+
+    let mut seen = HashSet::new();
+    for (.., registration) in registered {
+        (registration.export)(cfg, &mut seen)?;
+    }
+
+    // every type targeting a checked file has been checked now
+    if cfg.check_only() {
+        crate::check_files(cfg)?;
+    }
+
+    Ok(())
+}
+
+/// A type or value registered for [`export_registered`]
+#[doc(hidden)]
+pub struct Registration {
+    /// The file the bindings are exported into
+    pub path: fn() -> Option<PathBuf>,
+    /// The name of the type or value
+    pub name: fn(&Config) -> String,
+    /// Exports the bindings, skipping the types which were already exported
+    pub export: fn(&Config, &mut HashSet<TypeId>) -> Result<(), ExportError>,
+}
+
+impl Registration {
+    /// Registers the type `T`, which is exported together with its dependencies
+    pub const fn of<T: TS + ?Sized + 'static>() -> Self {
+        Self {
+            path: T::output_path,
+            name: T::ident,
+            export: crate::export::export_recursive::<T>,
+        }
+    }
+}
+
+inventory::collect!(Registration);
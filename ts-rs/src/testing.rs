@@ -0,0 +1,356 @@
+//! Checking that serialized values conform to the generated bindings.
+//!
+//! Custom `Serialize` implementations, `#[serde(with = "..")]` or a missing `#[ts(..)]` attribute
+//! may cause the TypeScript bindings of a type to disagree with what is actually sent over the
+//! wire. Within tests, [`assert_conforms`] checks a value serialized using `serde_json` against the
+//! bindings of its type, reporting the JSON path of the first mismatch.
+//! ```
+//! # use ts_rs::TS;
+//! # use serde::Serialize;
+//! #[derive(TS, Serialize)]
+//! struct User {
+//!     name: String,
+//!     #[serde(skip_serializing_if = "Option::is_none")]
+//!     #[ts(optional)]
+//!     email: Option<String>,
+//! }
+//!
+//! let user = User { name: "Alice".to_owned(), email: None };
+//! ts_rs::testing::assert_conforms::<User>(&serde_json::to_value(&user).unwrap());
+//! ```
+
+use std::fmt::{self, Display, Formatter};
+
+use ::serde_json::{Map, Value};
+
+use crate::{
+    ast::{is_identifier, Declarations, Keyword, Literal, Property, TsType},
+    Config, TS,
+};
+
+/// The largest integer which can be represented as a `number` without losing precision
+const MAX_SAFE_INTEGER: u64 = (1 << 53) - 1;
+
+/// A value which does not conform to the TypeScript bindings of its type, see [`conforms`].
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Mismatch {
+    /// JSON path of the value which does not conform to its type, e.g. `$.users[0].name`
+    pub path: String,
+    /// The TypeScript type the value was expected to conform to
+    pub expected: String,
+    /// What was found instead
+    pub found: String,
+}
+
+impl Display for Mismatch {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "value at `{}` does not conform to `{}`: found {}",
+            self.path, self.expected, self.found
+        )
+    }
+}
+
+impl std::error::Error for Mismatch {}
+
+/// Panics if `value` does not conform to the TypeScript bindings of `T`, which are generated using
+/// [`Config::load`]. See [`conforms`].
+#[track_caller]
+pub fn assert_conforms<T: TS + ?Sized + 'static>(value: &Value) {
+    let cfg = Config::load().unwrap_or_else(|e| panic!("could not load the configuration: {e}"));
+    if let Err(mismatch) = conforms::<T>(value, &cfg) {
+        panic!("{mismatch}");
+    }
+}
+
+/// Checks that `value` conforms to the TypeScript bindings of `T`, returning the first
+/// [`Mismatch`] otherwise.
+///
+/// The value is checked structurally against the declarations of `T` and its dependencies,
+/// including tags, optional and flattened fields and the type used for large integers.
+/// Properties which are not part of an object type are reported as well, unless the object
+/// contains a flattened map. Types which ts-rs cannot interpret, e.g. given by
+/// `#[ts(type = "..")]`, accept any value.
+pub fn conforms<T: TS + ?Sized + 'static>(value: &Value, cfg: &Config) -> Result<(), Mismatch> {
+    let checker = Checker {
+        types: Declarations::of::<T>(cfg),
+    };
+    let ty = T::name_ast(cfg);
+    checker.check(value, &ty, &mut "$".to_owned(), false)
+}
+
+struct Checker<'a> {
+    types: Declarations<'a>,
+}
+
+impl Checker<'_> {
+    /// Checks `value`, located at the JSON path `path`, against `ty`.
+    /// If `open` is `true`, objects may contain properties which are not part of their type, e.g.
+    /// because they belong to another type flattened into the same object.
+    fn check(
+        &self,
+        value: &Value,
+        ty: &TsType,
+        path: &mut String,
+        open: bool,
+    ) -> Result<(), Mismatch> {
+        let resolved = self.types.resolve(ty);
+        let mismatch = |path: &str| {
+            Err(Mismatch {
+                path: path.to_owned(),
+                expected: ty.to_string(),
+                found: describe(value),
+            })
+        };
+
+        match (value, &resolved) {
+            (_, TsType::Keyword(Keyword::Unknown | Keyword::Any)) => Ok(()),
+            // unresolved references are generic parameters or types we know nothing about
+            (_, TsType::Reference { .. } | TsType::Function { .. } | TsType::Raw(_)) => Ok(()),
+            (_, TsType::Union(variants)) => self.check_union(value, ty, variants, path, open),
+            (Value::Object(map), TsType::Object(_) | TsType::Intersection(_)) => {
+                self.check_object(map, ty, &resolved, path, open)
+            }
+            (_, TsType::Intersection(members)) => members
+                .iter()
+                .filter(|member| member.brand().is_none())
+                .try_for_each(|member| self.check(value, member, path, open)),
+            (Value::Null, TsType::Keyword(Keyword::Null | Keyword::Undefined | Keyword::Void)) => {
+                Ok(())
+            }
+            (Value::Bool(_), TsType::Keyword(Keyword::Boolean)) => Ok(()),
+            (Value::Bool(value), TsType::Literal(Literal::Boolean(literal)))
+                if value == literal =>
+            {
+                Ok(())
+            }
+            (Value::Number(number), TsType::Keyword(Keyword::Number)) => {
+                // larger integers lose precision when they are parsed in JavaScript
+                match number.as_u64().or(number.as_i64().map(i64::unsigned_abs)) {
+                    Some(int) if int > MAX_SAFE_INTEGER => mismatch(path),
+                    _ => Ok(()),
+                }
+            }
+            (Value::Number(number), TsType::Keyword(Keyword::Bigint)) if !number.is_f64() => Ok(()),
+            (Value::Number(value), TsType::Literal(Literal::Number(literal)))
+                if value.to_string() == *literal =>
+            {
+                Ok(())
+            }
+            (Value::String(_), TsType::Keyword(Keyword::String)) => Ok(()),
+            (Value::String(value), TsType::Literal(Literal::String(literal)))
+                if value == literal =>
+            {
+                Ok(())
+            }
+            (Value::Array(items), TsType::Array(element)) => items
+                .iter()
+                .enumerate()
+                .try_for_each(|(i, item)| self.check_at(item, element, path, &format!("[{i}]"))),
+            (Value::Array(items), TsType::Tuple(elements)) => {
+                let required = elements.iter().filter(|e| !e.optional).count();
+                if !(required..=elements.len()).contains(&items.len()) {
+                    return mismatch(path);
+                }
+                items
+                    .iter()
+                    .zip(elements)
+                    .enumerate()
+                    .try_for_each(|(i, (item, element))| {
+                        self.check_at(item, &element.ty, path, &format!("[{i}]"))
+                    })
+            }
+            (Value::Object(_), TsType::Keyword(Keyword::Object)) => Ok(()),
+            (Value::Object(map), TsType::Record { key, value, .. }) => {
+                map.iter().try_for_each(|(name, item)| {
+                    self.check_key(name, key, path)?;
+                    self.check_at(item, value, path, &segment(name))
+                })
+            }
+            _ => mismatch(path),
+        }
+    }
+
+    /// Checks `value` against the variant of a union it conforms to. If there is none, the
+    /// mismatch which was found the deepest within `value` is reported, since it most likely
+    /// belongs to the variant `value` was meant to be.
+    fn check_union(
+        &self,
+        value: &Value,
+        ty: &TsType,
+        variants: &[TsType],
+        path: &mut String,
+        open: bool,
+    ) -> Result<(), Mismatch> {
+        let mut deepest: Option<Mismatch> = None;
+        for variant in variants {
+            let mismatch = match self.check(value, variant, path, open) {
+                Ok(()) => return Ok(()),
+                Err(mismatch) => mismatch,
+            };
+            let deeper = match &deepest {
+                Some(deepest) => mismatch.path.len() > deepest.path.len(),
+                None => true,
+            };
+            if deeper {
+                deepest = Some(mismatch);
+            }
+        }
+
+        Err(match deepest {
+            Some(mismatch) if mismatch.path.len() > path.len() => mismatch,
+            _ => Mismatch {
+                path: path.clone(),
+                expected: ty.to_string(),
+                found: describe(value),
+            },
+        })
+    }
+
+    /// Checks an object against an object type, which may be the intersection of the types
+    /// flattened into it
+    fn check_object(
+        &self,
+        map: &Map<String, Value>,
+        ty: &TsType,
+        resolved: &TsType,
+        path: &mut String,
+        open: bool,
+    ) -> Result<(), Mismatch> {
+        let mut shape = Shape {
+            open,
+            ..Shape::default()
+        };
+        self.shape(resolved, &mut shape);
+
+        for property in &shape.properties {
+            match map.get(&property.name) {
+                Some(value) => {
+                    self.check_at(value, &property.ty, path, &segment(&property.name))?
+                }
+                None if property.optional => (),
+                None => {
+                    return Err(Mismatch {
+                        path: format!("{path}{}", segment(&property.name)),
+                        expected: property.ty.to_string(),
+                        found: "nothing".to_owned(),
+                    })
+                }
+            }
+        }
+
+        // members of the intersection which are not objects, e.g. unions of tagged variants,
+        // are checked against the whole object
+        for member in &shape.others {
+            self.check(&Value::Object(map.clone()), member, path, true)?;
+        }
+
+        let unknown = map
+            .iter()
+            .filter(|(key, _)| !shape.properties.iter().any(|p| p.name == **key));
+        for (key, value) in unknown {
+            match &shape.record {
+                // the remaining properties belong to a flattened map
+                Some((key_ty, value_ty)) => {
+                    self.check_key(key, key_ty, path)?;
+                    self.check_at(value, value_ty, path, &segment(key))?;
+                }
+                None if shape.open => (),
+                None => {
+                    return Err(Mismatch {
+                        path: format!("{path}{}", segment(key)),
+                        expected: ty.to_string(),
+                        found: "a property which is not part of the type".to_owned(),
+                    })
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Collects the properties of an object type, including the ones of flattened types
+    fn shape(&self, ty: &TsType, shape: &mut Shape) {
+        match self.types.resolve(ty) {
+            TsType::Object(properties) => shape.properties.extend(properties),
+            TsType::Intersection(members) => {
+                for member in members.iter().filter(|member| member.brand().is_none()) {
+                    self.shape(member, shape);
+                }
+            }
+            TsType::Record { key, value, .. } => shape.record = Some((*key, *value)),
+            other => {
+                // the properties of unions and unknown types are not known up front
+                shape.open = true;
+                shape.others.push(other);
+            }
+        }
+    }
+
+    /// Checks a key of an object against the key type of a record
+    fn check_key(&self, name: &str, key: &TsType, path: &str) -> Result<(), Mismatch> {
+        let conforms = match self.types.resolve(key) {
+            TsType::Keyword(Keyword::String | Keyword::Any | Keyword::Unknown) => true,
+            TsType::Keyword(Keyword::Number | Keyword::Bigint) => name.parse::<f64>().is_ok(),
+            // keys like enum variants are checked like strings
+            key => {
+                let name = Value::String(name.to_owned());
+                self.check(&name, &key, &mut path.to_owned(), false).is_ok()
+            }
+        };
+
+        match conforms {
+            true => Ok(()),
+            false => Err(Mismatch {
+                path: format!("{path}{}", segment(name)),
+                expected: key.to_string(),
+                found: format!("the key {}", Value::String(name.to_owned())),
+            }),
+        }
+    }
+
+    /// Checks a value nested within the value at `path`, e.g. a property or an element
+    fn check_at(
+        &self,
+        value: &Value,
+        ty: &TsType,
+        path: &mut String,
+        segment: &str,
+    ) -> Result<(), Mismatch> {
+        let len = path.len();
+        path.push_str(segment);
+        let result = self.check(value, ty, path, false);
+        path.truncate(len);
+        result
+    }
+}
+
+/// The properties of an object type
+#[derive(Default)]
+struct Shape {
+    properties: Vec<Property>,
+    // the types of the keys and values of a flattened map
+    record: Option<(TsType, TsType)>,
+    // types the whole object is checked against, like flattened unions
+    others: Vec<TsType>,
+    // whether the object may contain properties which are not in `properties`
+    open: bool,
+}
+
+/// Returns the segment of a JSON path accessing the property `name`, e.g. `.name` or `["a b"]`
+fn segment(name: &str) -> String {
+    match is_identifier(name) {
+        true => format!(".{name}"),
+        false => format!("[{}]", Value::String(name.to_owned())),
+    }
+}
+
+/// Describes a value for a [`Mismatch`], abbreviating arrays and objects
+fn describe(value: &Value) -> String {
+    match value {
+        Value::Array(items) => format!("an array of {} elements", items.len()),
+        Value::Object(_) => "an object".to_owned(),
+        value => value.to_string(),
+    }
+}
@@ -0,0 +1,195 @@
+//! Exporting Rust values as TypeScript constants, see [`TS::export_value`].
+//!
+//! Values are serialized using `serde_json` and printed as TypeScript literals. To print them
+//! correctly, e.g. to add the `n` suffix to `bigint`s, the literal is generated together with the
+//! type of the value, following references to the declarations of its dependencies.
+
+use ::serde_json::{Map, Value};
+use serde::Serialize;
+
+use crate::{
+    ast::{is_identifier, Declarations, Keyword, Literal, TsType},
+    Config, Dependency, Direction, ExportError, TypeVisitor, TS,
+};
+
+/// Generates the declaration of a constant, e.g. `const MAX_UPLOAD: number = 1024 as const;`
+pub(crate) fn declaration<T: TS + Serialize + ?Sized + 'static>(
+    value: &T,
+    name: &str,
+    cfg: &Config,
+) -> Result<String, ExportError> {
+    let value = ::serde_json::to_value(value)?;
+
+    let ty = T::name_ast(cfg);
+    let printer = Printer {
+        types: Declarations::of::<T>(cfg),
+    };
+    let literal = printer.literal(&value, Some(&ty));
+
+    // `null` cannot be asserted to be `const`, and read-only arrays can only be assigned to
+    // mutable arrays if the generated types are read-only as well
+    let as_const = match value {
+        Value::Null => false,
+        ref value => cfg.readonly() || !contains_array(value),
+    };
+    let as_const = if as_const { " as const" } else { "" };
+
+    Ok(format!("const {name}: {ty} = {literal}{as_const};"))
+}
+
+/// Returns the types referenced by the declaration of a value of type `T`, which are `T` itself
+/// and the types it is generic over.
+pub(crate) fn dependencies<T: TS + ?Sized + 'static>(cfg: &Config) -> Vec<Dependency> {
+    struct Visit<'a>(&'a Config, Vec<Dependency>);
+    impl TypeVisitor for Visit<'_> {
+        fn visit<T: TS + 'static + ?Sized>(&mut self) {
+            if let Some(dep) = Dependency::from_ty::<T>(self.0) {
+                self.1.push(dep);
+            }
+        }
+
+        fn direction(&self) -> Direction {
+            self.0.direction()
+        }
+
+        fn interfaces(&self) -> bool {
+            self.0.interfaces()
+        }
+    }
+
+    let mut visitor = Visit(cfg, vec![]);
+    visitor.visit::<T>();
+    T::visit_generics(&mut visitor);
+    visitor.1
+}
+
+struct Printer<'a> {
+    types: Declarations<'a>,
+}
+
+impl Printer<'_> {
+    /// Prints `value` as a TypeScript literal of the type `ty`, if it is known
+    fn literal(&self, value: &Value, ty: Option<&TsType>) -> String {
+        let ty = match ty.map(|ty| self.types.resolve(ty)) {
+            // the variant of a union is only known once the value is looked at
+            Some(TsType::Union(variants)) => variants
+                .iter()
+                .map(|variant| self.types.resolve(variant))
+                .find(|variant| self.matches(value, variant)),
+            ty => ty,
+        };
+
+        match value {
+            Value::Number(number) if ty == Some(TsType::Keyword(Keyword::Bigint)) => {
+                format!("{number}n")
+            }
+            Value::Null | Value::Bool(_) | Value::Number(_) | Value::String(_) => {
+                value.to_string()
+            }
+            Value::Array(items) => {
+                let items = items.iter().enumerate().map(|(i, item)| {
+                    let ty = match &ty {
+                        Some(TsType::Array(element)) => Some(&**element),
+                        Some(TsType::Tuple(elements)) => elements.get(i).map(|e| &e.ty),
+                        _ => None,
+                    };
+                    self.literal(item, ty)
+                });
+                format!("[{}]", items.collect::<Vec<_>>().join(", "))
+            }
+            Value::Object(map) if map.is_empty() => "{}".to_owned(),
+            Value::Object(map) => self.object(map, ty.as_ref()),
+        }
+    }
+
+    /// Prints an object, keeping the order of the properties of its type
+    fn object(&self, map: &Map<String, Value>, ty: Option<&TsType>) -> String {
+        let mut properties = vec![];
+        let mut record = None;
+        if let Some(ty) = ty {
+            self.properties(ty, &mut properties, &mut record);
+        }
+
+        let known = properties
+            .iter()
+            .filter_map(|(name, ty)| Some((name.as_str(), map.get(name)?, Some(ty))));
+        let other = map
+            .iter()
+            .filter(|(key, _)| !properties.iter().any(|(name, _)| name == *key))
+            .map(|(key, value)| (key.as_str(), value, record.as_ref()));
+
+        let properties = known.chain(other).map(|(key, value, ty)| {
+            let value = self.literal(value, ty);
+            match is_identifier(key) {
+                true => format!("{key}: {value}"),
+                false => format!("{}: {value}", Value::String(key.to_owned())),
+            }
+        });
+        format!("{{ {} }}", properties.collect::<Vec<_>>().join(", "))
+    }
+
+    /// Collects the properties of an object type, and the type of the values of a record
+    fn properties(
+        &self,
+        ty: &TsType,
+        properties: &mut Vec<(String, TsType)>,
+        record: &mut Option<TsType>,
+    ) {
+        match self.types.resolve(ty) {
+            TsType::Object(object) => {
+                properties.extend(object.into_iter().map(|p| (p.name, p.ty)));
+            }
+            TsType::Intersection(members) => {
+                for member in &members {
+                    self.properties(member, properties, record);
+                }
+            }
+            TsType::Record { value, .. } => *record = Some(*value),
+            _ => (),
+        }
+    }
+
+    /// Returns whether `value` might be of the (resolved) type `ty`
+    fn matches(&self, value: &Value, ty: &TsType) -> bool {
+        match (value, ty) {
+            (_, TsType::Keyword(Keyword::Unknown | Keyword::Any)) => true,
+            (_, TsType::Reference { .. } | TsType::Function { .. } | TsType::Raw(_)) => true,
+            (_, TsType::Union(variants)) => variants
+                .iter()
+                .any(|variant| self.matches(value, &self.types.resolve(variant))),
+            (_, TsType::Intersection(members)) => members.iter().all(|member| {
+                member.brand().is_some() || self.matches(value, &self.types.resolve(member))
+            }),
+            (Value::Null, TsType::Keyword(Keyword::Null | Keyword::Undefined)) => true,
+            (Value::Bool(_), TsType::Keyword(Keyword::Boolean)) => true,
+            (Value::Bool(value), TsType::Literal(Literal::Boolean(literal))) => value == literal,
+            (Value::Number(_), TsType::Keyword(Keyword::Number | Keyword::Bigint)) => true,
+            (Value::Number(value), TsType::Literal(Literal::Number(literal))) => {
+                value.to_string() == *literal
+            }
+            (Value::String(_), TsType::Keyword(Keyword::String)) => true,
+            (Value::String(value), TsType::Literal(Literal::String(literal))) => value == literal,
+            (Value::Array(_), TsType::Array(_)) => true,
+            (Value::Array(items), TsType::Tuple(elements)) => {
+                let required = elements.iter().filter(|e| !e.optional).count();
+                (required..=elements.len()).contains(&items.len())
+            }
+            (Value::Object(_), TsType::Keyword(Keyword::Object) | TsType::Record { .. }) => true,
+            (Value::Object(map), TsType::Object(properties)) => {
+                properties.iter().all(|property| match map.get(&property.name) {
+                    Some(value) => self.matches(value, &self.types.resolve(&property.ty)),
+                    None => property.optional,
+                })
+            }
+            _ => false,
+        }
+    }
+}
+
+fn contains_array(value: &Value) -> bool {
+    match value {
+        Value::Array(_) => true,
+        Value::Object(map) => map.values().any(contains_array),
+        _ => false,
+    }
+}
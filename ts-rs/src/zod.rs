@@ -0,0 +1,296 @@
+use std::{any::TypeId, collections::HashSet, path::PathBuf};
+
+use crate::{
+    ast::{is_identifier, DeclarationKind, Keyword, Literal, TsType},
+    Config, Direction, TypeVisitor, TS,
+};
+
+/// How the TypeScript type of a schema is declared
+enum Kind {
+    /// `export type User = z.infer<typeof UserSchema>;`
+    Inferred,
+    /// The type is declared like it is without Zod, since it cannot be inferred from its schema.
+    /// This is the case for generic and recursive types, as well as `#[ts(repr(enum))]`.
+    Declared,
+}
+
+/// Generates the Zod schema of `T`, see [`TS::zod`].
+pub(crate) fn schema<T: TS + ?Sized + 'static>(cfg: &Config) -> String {
+    generate::<T>(cfg).0
+}
+
+/// Generates the exported Zod schema of `T` together with its TypeScript type
+pub(crate) fn declarations<T: TS + ?Sized + 'static>(cfg: &Config) -> String {
+    let (schema, kind) = generate::<T>(cfg);
+    let docs = T::docs().unwrap_or_default();
+
+    match kind {
+        Kind::Inferred => {
+            let name = T::ident(cfg);
+            format!("export {schema}\n{docs}export type {name} = z.infer<typeof {name}Schema>;")
+        }
+        Kind::Declared => format!("{docs}export {}\nexport {schema}", T::decl(cfg)),
+    }
+}
+
+fn generate<T: TS + ?Sized + 'static>(cfg: &Config) -> (String, Kind) {
+    let decl = T::decl_ast(cfg);
+    let name = &decl.name;
+
+    if let DeclarationKind::Enum(_) = decl.kind {
+        let schema = format!("const {name}Schema = z.nativeEnum({name});");
+        return (schema, Kind::Declared);
+    }
+
+    // schemas can only be referenced for types which are exported together with `T`
+    let mut declared = <T::WithoutGenerics as TS>::dependencies(cfg)
+        .into_iter()
+        .map(|dep| dep.ts_name)
+        .collect::<HashSet<_>>();
+    declared.insert(name.clone());
+
+    let generator = Generator {
+        generics: decl.generics.iter().map(|g| g.name.clone()).collect(),
+        declared,
+    };
+    let schema = generator.schema(&decl.ty);
+
+    match decl.generics.as_slice() {
+        [] if is_recursive::<T>(cfg) => (
+            format!("const {name}Schema: z.ZodType<{name}> = {schema};"),
+            Kind::Declared,
+        ),
+        [] => (format!("const {name}Schema = {schema};"), Kind::Inferred),
+        generics => {
+            let params = generics
+                .iter()
+                .map(|g| format!("{} extends z.ZodTypeAny", g.name));
+            let args = generics
+                .iter()
+                .map(|g| format!("{}Schema: {}", g.name, g.name));
+            let inferred = generics.iter().map(|g| format!("z.infer<{}>", g.name));
+
+            let schema = format!(
+                "const {name}Schema = <{}>({}): z.ZodType<{name}<{}>, z.ZodTypeDef, unknown> => {schema};",
+                params.collect::<Vec<_>>().join(", "),
+                args.collect::<Vec<_>>().join(", "),
+                inferred.collect::<Vec<_>>().join(", "),
+            );
+            (schema, Kind::Declared)
+        }
+    }
+}
+
+/// Returns `true` if `T` depends on itself, directly or through other types.
+/// The type of a recursive schema cannot be inferred by TypeScript.
+fn is_recursive<T: TS + ?Sized + 'static>(cfg: &Config) -> bool {
+    struct Visit<'a> {
+        cfg: &'a Config,
+        target: (String, Option<PathBuf>),
+        seen: HashSet<TypeId>,
+        found: bool,
+    }
+
+    impl TypeVisitor for Visit<'_> {
+        fn visit<T: TS + 'static + ?Sized>(&mut self) {
+            if self.found || T::output_path().is_none() || !self.seen.insert(TypeId::of::<T>()) {
+                return;
+            }
+
+            self.found = (T::ident(self.cfg), T::output_path()) == self.target;
+            T::visit_dependencies(self);
+        }
+
+        fn direction(&self) -> Direction {
+            self.cfg.direction()
+        }
+
+        fn interfaces(&self) -> bool {
+            self.cfg.interfaces()
+        }
+    }
+
+    let mut visit = Visit {
+        cfg,
+        target: (T::ident(cfg), T::output_path()),
+        seen: HashSet::new(),
+        found: false,
+    };
+    T::visit_dependencies(&mut visit);
+    visit.found
+}
+
+struct Generator {
+    // generic parameters of the declaration, whose schemas are passed as an argument
+    generics: HashSet<String>,
+    // types for which a schema `<Name>Schema` is exported
+    declared: HashSet<String>,
+}
+
+impl Generator {
+    fn schema(&self, ty: &TsType) -> String {
+        match ty {
+            TsType::Keyword(keyword) => match keyword {
+                Keyword::String => "z.string()",
+                Keyword::Number => "z.number()",
+                Keyword::Bigint => "z.bigint()",
+                Keyword::Boolean => "z.boolean()",
+                Keyword::Null => "z.null()",
+                Keyword::Undefined => "z.undefined()",
+                Keyword::Void => "z.void()",
+                Keyword::Unknown => "z.unknown()",
+                Keyword::Any => "z.any()",
+                Keyword::Never => "z.never()",
+                Keyword::Object => "z.record(z.string(), z.unknown())",
+                Keyword::Symbol => "z.symbol()",
+            }
+            .to_owned(),
+            TsType::Literal(literal) => format!("z.literal({literal})"),
+            TsType::Reference { name, .. } if self.generics.contains(name) => {
+                format!("{name}Schema")
+            }
+            // schemas are referenced lazily, so that they may be declared in any order and
+            // depend on each other
+            TsType::Reference { name, generics } if self.declared.contains(name) => {
+                match generics.as_slice() {
+                    [] => format!("z.lazy(() => {name}Schema)"),
+                    generics => {
+                        let args = generics.iter().map(|g| self.schema(g));
+                        let args = args.collect::<Vec<_>>().join(", ");
+                        format!("z.lazy(() => {name}Schema({args}))")
+                    }
+                }
+            }
+            // values of types which are not exported by ts-rs are not validated
+            TsType::Reference { .. } | TsType::Function { .. } | TsType::Raw(_) => {
+                format!("z.custom<{ty}>()")
+            }
+            TsType::Array(element) => format!("z.array({})", self.schema(element)),
+            TsType::Readonly(ty) => format!("{}.readonly()", self.schema(ty)),
+            TsType::Tuple(elements) => {
+                let elements = elements.iter().map(|element| match element.optional {
+                    true => format!("{}.optional()", self.schema(&element.ty)),
+                    false => self.schema(&element.ty),
+                });
+                format!("z.tuple([{}])", elements.collect::<Vec<_>>().join(", "))
+            }
+            TsType::Object(properties) => {
+                let mut object = String::from("z.object({ ");
+                for property in properties {
+                    if let Some(docs) = &property.docs {
+                        object.push('\n');
+                        object.push_str(&crate::ast::jsdoc(docs));
+                    }
+
+                    object.push_str(&key(&property.name));
+                    object.push_str(": ");
+                    object.push_str(&self.schema(&property.ty));
+                    if property.optional {
+                        object.push_str(".optional()");
+                    }
+                    object.push_str(", ");
+                }
+                object.push_str("})");
+
+                // zod can only make all properties of an object read-only
+                if !properties.is_empty() && properties.iter().all(|p| p.readonly) {
+                    object.push_str(".readonly()");
+                }
+                object
+            }
+            TsType::Record {
+                key: key_ty,
+                value,
+                optional,
+                readonly,
+            } => {
+                let value = self.schema(value);
+                let value = match optional {
+                    true => format!("{value}.optional()"),
+                    false => value,
+                };
+
+                // a record with literal keys is an object with a property for every key
+                if let Some(keys) = string_literals(key_ty) {
+                    let properties = keys.iter().map(|k| format!("{}: {value}, ", key(k)));
+                    let object = format!("z.object({{ {}}})", properties.collect::<String>());
+                    return match readonly {
+                        true => format!("{object}.readonly()"),
+                        false => object,
+                    };
+                }
+
+                let key = match &**key_ty {
+                    // keys are always strings in JSON
+                    TsType::Keyword(Keyword::String | Keyword::Number | Keyword::Bigint) => {
+                        "z.string()".to_owned()
+                    }
+                    key => self.schema(key),
+                };
+                match readonly {
+                    true => format!("z.record({key}, {value}).readonly()"),
+                    false => format!("z.record({key}, {value})"),
+                }
+            }
+            TsType::Union(variants) => {
+                if let Some(literals) = string_literals(ty) {
+                    let literals = literals.iter().map(|l| Literal::String(l.to_string()));
+                    let literals = literals.map(|l| l.to_string()).collect::<Vec<_>>();
+                    return format!("z.enum([{}])", literals.join(", "));
+                }
+
+                let nullable = variants.contains(&TsType::Keyword(Keyword::Null));
+                let variants = variants
+                    .iter()
+                    .filter(|variant| **variant != TsType::Keyword(Keyword::Null))
+                    .map(|variant| self.schema(variant))
+                    .collect::<Vec<_>>();
+
+                let schema = match variants.as_slice() {
+                    [] => "z.null()".to_owned(),
+                    [variant] => variant.clone(),
+                    variants => format!("z.union([{}])", variants.join(", ")),
+                };
+                match nullable && !variants.is_empty() {
+                    true => format!("{schema}.nullable()"),
+                    false => schema,
+                }
+            }
+            TsType::Intersection(members) => {
+                let brand = members.iter().find_map(TsType::brand);
+                let mut members = members
+                    .iter()
+                    .filter(|member| member.brand().is_none())
+                    .map(|member| self.schema(member));
+                let first = members.next().unwrap_or_default();
+                let schema = members.fold(first, |schema, member| format!("{schema}.and({member})"));
+                match brand {
+                    Some(brand) => format!("{schema}.brand<{}>()", Literal::String(brand.to_owned())),
+                    None => schema,
+                }
+            }
+        }
+    }
+}
+
+/// Formats the key of a property, only quoting it if necessary
+fn key(name: &str) -> String {
+    match is_identifier(name) {
+        true => name.to_owned(),
+        false => Literal::String(name.to_owned()).to_string(),
+    }
+}
+
+fn string_literals(ty: &TsType) -> Option<Vec<&str>> {
+    match ty {
+        TsType::Literal(Literal::String(literal)) => Some(vec![literal]),
+        TsType::Union(variants) => variants
+            .iter()
+            .map(|variant| match variant {
+                TsType::Literal(Literal::String(literal)) => Some(literal.as_str()),
+                _ => None,
+            })
+            .collect(),
+        _ => None,
+    }
+}
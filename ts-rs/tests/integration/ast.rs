@@ -0,0 +1,256 @@
+#![allow(dead_code)]
+
+use ts_rs::{
+    ast::{
+        Declaration, DeclarationKind, Element, EnumMember, Generic, Keyword, Literal, Property,
+        PropertyStyle, TsType,
+    },
+    Config, TS,
+};
+
+#[derive(TS)]
+#[ts(export_to = "ast/")]
+/// A page of items
+struct Page<T> {
+    items: Vec<T>,
+    #[ts(optional)]
+    next: Option<String>,
+}
+
+#[derive(TS)]
+#[ts(export_to = "ast/", tag = "kind")]
+enum Shape {
+    Circle { radius: f64 },
+    Line(Point),
+}
+
+#[derive(TS)]
+#[ts(export_to = "ast/")]
+struct Point(i32, i32);
+
+#[derive(TS)]
+#[ts(export_to = "ast/")]
+struct Import {
+    #[ts(type = "typeof import('./x')")]
+    module: (),
+}
+
+#[derive(TS)]
+#[ts(export_to = "ast/")]
+#[repr(u8)]
+#[ts(repr(enum))]
+enum Level {
+    Low = 1,
+    High,
+}
+
+fn reference(name: &str) -> TsType {
+    TsType::reference(name)
+}
+
+fn property(name: &str, ty: TsType) -> Property {
+    Property::new(name, ty)
+}
+
+#[test]
+fn inline() {
+    let cfg = Config::new();
+    assert_eq!(
+        Point::inline_ast(&cfg),
+        TsType::Tuple(vec![
+            Element {
+                ty: TsType::Keyword(Keyword::Number),
+                optional: false,
+            };
+            2
+        ])
+    );
+
+    let TsType::Union(variants) = Shape::inline_ast(&cfg) else {
+        panic!("expected a union");
+    };
+    assert_eq!(
+        variants[0],
+        TsType::Object(vec![
+            Property::with_style(
+                "kind",
+                TsType::Literal(Literal::String("Circle".to_owned())),
+                PropertyStyle::Tag
+            ),
+            property("radius", TsType::Keyword(Keyword::Number)),
+        ])
+    );
+    assert_eq!(
+        variants[1],
+        TsType::Intersection(vec![
+            TsType::Object(vec![Property::with_style(
+                "kind",
+                TsType::Literal(Literal::String("Line".to_owned())),
+                PropertyStyle::Variant
+            )]),
+            reference("Point"),
+        ])
+    );
+}
+
+#[test]
+fn decl() {
+    let cfg = Config::new();
+    assert_eq!(
+        Page::<Point>::decl_ast(&cfg),
+        Declaration {
+            name: "Page".to_owned(),
+            generics: vec![Generic {
+                name: "T".to_owned(),
+                default: None,
+            }],
+            ty: TsType::Object(vec![
+                property(
+                    "items",
+                    TsType::Array(Box::new(TsType::Reference {
+                        name: "T".to_owned(),
+                        generics: vec![],
+                    }))
+                ),
+                Property {
+                    optional: true,
+                    ..property("next", TsType::Keyword(Keyword::String))
+                },
+            ]),
+            docs: Some(" A page of items".to_owned()),
+            kind: DeclarationKind::Type,
+        }
+    );
+
+    assert_eq!(
+        Level::decl_ast(&cfg),
+        Declaration {
+            name: "Level".to_owned(),
+            generics: vec![],
+            ty: TsType::Union(vec![
+                TsType::Literal(Literal::Number("1".to_owned())),
+                TsType::Literal(Literal::Number("2".to_owned())),
+            ]),
+            docs: None,
+            kind: DeclarationKind::Enum(vec![
+                EnumMember {
+                    name: "Low".to_owned(),
+                    value: Some(Literal::Number("1".to_owned())),
+                },
+                EnumMember {
+                    name: "High".to_owned(),
+                    value: None,
+                },
+            ]),
+        }
+    );
+}
+
+#[test]
+fn print() {
+    let cfg = Config::new();
+    assert_eq!(
+        Page::<Point>::inline_ast(&cfg).to_string(),
+        Page::<Point>::inline(&cfg)
+    );
+    assert_eq!(Point::inline_ast(&cfg).to_string(), Point::inline(&cfg));
+    assert_eq!(
+        Shape::inline_ast(&cfg).to_string(),
+        r#"{ "kind": "Circle", radius: number, } | { "kind": "Line" } & Point"#
+    );
+}
+
+#[test]
+fn print_decl() {
+    let cfg = Config::new();
+    assert_eq!(
+        Page::<Point>::decl_ast(&cfg).to_string(),
+        Page::<Point>::decl(&cfg)
+    );
+    assert_eq!(Level::decl(&cfg), r#"enum Level { "Low" = 1, "High" }"#);
+    assert_eq!(
+        Page::<Point>::decl(&cfg.clone().with_interfaces()),
+        "interface Page<T> { items: Array<T>, next?: string, }"
+    );
+
+    let function = Declaration {
+        name: "getUser".to_owned(),
+        generics: vec![],
+        ty: TsType::Function {
+            params: vec![ts_rs::ast::Param {
+                name: "id".to_owned(),
+                ty: TsType::Keyword(Keyword::Number),
+            }],
+            returns: Box::new(reference("User")),
+        },
+        docs: None,
+        kind: DeclarationKind::Function,
+    };
+    assert_eq!(
+        function.to_string(),
+        "declare function getUser(id: number): User;"
+    );
+
+    let interface = Declaration {
+        name: "Admin".to_owned(),
+        generics: vec![],
+        ty: TsType::Intersection(vec![
+            TsType::Object(vec![property("level", TsType::Keyword(Keyword::Number))]),
+            reference("User"),
+        ]),
+        docs: None,
+        kind: DeclarationKind::Interface,
+    };
+    assert_eq!(
+        interface.to_string(),
+        "interface Admin extends User { level: number, }"
+    );
+}
+
+#[test]
+fn simplify() {
+    let object =
+        |name: &str, ty: Keyword| TsType::Object(vec![property(name, TsType::Keyword(ty))]);
+
+    let ty = TsType::Intersection(vec![
+        object("a", Keyword::Number),
+        TsType::Intersection(vec![
+            object("b", Keyword::String),
+            object("c", Keyword::Boolean),
+        ]),
+        reference("Base"),
+    ]);
+    assert_eq!(
+        ty.simplify().to_string(),
+        "{ a: number, b: string, c: boolean, } & Base"
+    );
+
+    let branded = TsType::Intersection(vec![
+        object("a", Keyword::Number),
+        TsType::Object(vec![Property {
+            readonly: true,
+            ..property("__brand", TsType::Literal(Literal::String("Id".to_owned())))
+        }]),
+    ]);
+    assert_eq!(branded.clone().simplify(), branded);
+
+    let nested = TsType::Array(Box::new(TsType::Intersection(vec![
+        object("a", Keyword::Number),
+        object("b", Keyword::Number),
+    ])));
+    assert_eq!(
+        nested.simplify().to_string(),
+        "Array<{ a: number, b: number, }>"
+    );
+}
+
+#[test]
+fn raw() {
+    let TsType::Object(properties) = Import::inline_ast(&Config::new()) else {
+        panic!("expected an object");
+    };
+    assert_eq!(
+        properties[0].ty,
+        TsType::Raw("typeof import('./x')".to_owned())
+    );
+}
@@ -0,0 +1,98 @@
+#![allow(dead_code)]
+
+use std::{
+    fs,
+    process::{Command, Stdio},
+};
+
+use ts_rs::{Config, TS};
+
+#[derive(TS)]
+#[ts(export_to = "atomic_writes/")]
+struct Dependency {
+    name: String,
+}
+
+#[derive(TS)]
+#[ts(export_to = "atomic_writes/shared.ts")]
+struct First {
+    dependency: Dependency,
+}
+
+#[derive(TS)]
+#[ts(export_to = "atomic_writes/shared.ts")]
+struct Second {
+    id: u32,
+}
+
+#[test]
+fn merged_file_is_truncated() {
+    let cfg = Config::from_env();
+    First::export_all(&cfg).unwrap();
+
+    // another process wrote to the file in the meantime, duplicating its imports
+    let path = cfg.out_dir().join(First::output_path().unwrap());
+    let contents = fs::read_to_string(&path).unwrap();
+    let import = contents.lines().find(|l| l.starts_with("import")).unwrap();
+    let duplicated = contents.replace(import, &format!("{import}\n").repeat(20));
+    fs::write(&path, duplicated.replacen("\n\n\n", "\n\n", 1)).unwrap();
+
+    // the merged contents are shorter than the file
+    Second::export(&cfg).unwrap();
+
+    let contents = fs::read_to_string(&path).unwrap();
+    assert_eq!(contents.matches(import).count(), 1);
+    assert_eq!(contents.matches("export type").count(), 2);
+    assert!(contents.trim_end().ends_with(['}', ';']));
+
+    let lock = path.with_file_name("shared.ts.lock");
+    assert!(!lock.exists());
+}
+
+#[derive(TS)]
+#[ts(export_to = "atomic_writes/processes.ts")]
+struct FromFirstProcess {
+    id: u32,
+}
+
+#[derive(TS)]
+#[ts(export_to = "atomic_writes/processes.ts")]
+struct FromSecondProcess {
+    name: String,
+}
+
+#[test]
+fn processes() {
+    let cfg = Config::from_env();
+    let path = cfg.out_dir().join("atomic_writes/processes.ts");
+    let _ = fs::remove_file(&path);
+
+    // every process exports into the file for the first time, while the other may already have
+    let children = ["first", "second"].map(|ty| {
+        Command::new(std::env::current_exe().unwrap())
+            .args(["atomic_writes::export_in_process", "--exact", "--ignored"])
+            .env("TS_RS_TEST_EXPORT", ty)
+            .stdout(Stdio::null())
+            .spawn()
+            .unwrap()
+    });
+    for mut child in children {
+        assert!(child.wait().unwrap().success());
+    }
+
+    let contents = fs::read_to_string(&path).unwrap();
+    assert!(contents.contains("export type FromFirstProcess"));
+    assert!(contents.contains("export type FromSecondProcess"));
+}
+
+// run by `processes` in separate processes
+#[test]
+#[ignore]
+fn export_in_process() {
+    let cfg = Config::from_env();
+    match std::env::var("TS_RS_TEST_EXPORT").as_deref() {
+        Ok("first") => FromFirstProcess::export(&cfg).unwrap(),
+        Ok("second") => FromSecondProcess::export(&cfg).unwrap(),
+        _ => (),
+    }
+}
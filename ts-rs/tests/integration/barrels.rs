@@ -0,0 +1,171 @@
+#![allow(dead_code)]
+
+use std::fmt::Write;
+
+use ts_rs::{Config, TS};
+
+#[derive(TS)]
+#[ts(export_to = "barrels/")]
+struct User {
+    id: u32,
+    role: Role,
+    address: Address,
+}
+
+#[derive(TS)]
+#[ts(export_to = "barrels/")]
+enum Role {
+    Admin,
+    Guest,
+}
+
+#[derive(TS)]
+#[ts(export_to = "barrels/shared.ts")]
+struct Address {
+    street: String,
+}
+
+#[derive(TS)]
+#[ts(export_to = "barrels/shared.ts")]
+struct Coordinates {
+    lat: f64,
+    lng: f64,
+}
+
+#[derive(TS)]
+#[ts(export_to = "barrels/nested/")]
+struct Nested {
+    user: User,
+}
+
+#[test]
+fn barrels() {
+    let cfg = Config::from_env().with_barrels();
+    Nested::export_all(&cfg).unwrap();
+    Coordinates::export(&cfg).unwrap();
+
+    let ext = cfg
+        .import_extension()
+        .map(|ext| format!(".{ext}"))
+        .unwrap_or_default();
+
+    let mut expected = String::new();
+    writeln!(expected, "// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.").unwrap();
+    writeln!(expected, r#"export type {{ Role }} from "./Role{ext}";"#).unwrap();
+    writeln!(expected, r#"export type {{ User }} from "./User{ext}";"#).unwrap();
+    writeln!(
+        expected,
+        r#"export type {{ Address, Coordinates }} from "./shared{ext}";"#
+    )
+    .unwrap();
+
+    let barrel = cfg.out_dir().join("barrels/index.ts");
+    assert_eq!(std::fs::read_to_string(barrel).unwrap(), expected);
+
+    let mut expected = String::new();
+    writeln!(expected, "// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.").unwrap();
+    writeln!(
+        expected,
+        r#"export type {{ Nested }} from "./Nested{ext}";"#
+    )
+    .unwrap();
+
+    let barrel = cfg.out_dir().join("barrels/nested/index.ts");
+    assert_eq!(std::fs::read_to_string(barrel).unwrap(), expected);
+}
+
+#[derive(TS)]
+#[ts(export_to = "barrels/branded/", brand)]
+struct UserId(String);
+
+#[derive(TS)]
+#[ts(export_to = "barrels/branded/")]
+struct Member {
+    id: UserId,
+}
+
+#[test]
+fn brand_constructors() {
+    let cfg = Config::from_env().with_barrels().with_brand_constructors();
+    Member::export_all(&cfg).unwrap();
+
+    let ext = cfg
+        .import_extension()
+        .map(|ext| format!(".{ext}"))
+        .unwrap_or_default();
+
+    // re-exporting the constructor re-exports the type of the same name as well
+    let mut expected = String::new();
+    writeln!(expected, "// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.").unwrap();
+    writeln!(
+        expected,
+        r#"export type {{ Member }} from "./Member{ext}";"#
+    )
+    .unwrap();
+    writeln!(expected, r#"export {{ UserId }} from "./UserId{ext}";"#).unwrap();
+
+    let barrel = cfg.out_dir().join("barrels/branded/index.ts");
+    assert_eq!(std::fs::read_to_string(barrel).unwrap(), expected);
+}
+
+#[derive(TS)]
+#[ts(export_to = "barrels/enums/", repr(enum))]
+enum Status {
+    Active,
+    Banned,
+}
+
+#[derive(TS)]
+#[ts(export_to = "barrels/enums/")]
+struct Account {
+    status: Status,
+}
+
+#[test]
+fn enums() {
+    let cfg = Config::from_env().with_barrels();
+    Account::export_all(&cfg).unwrap();
+
+    let ext = cfg
+        .import_extension()
+        .map(|ext| format!(".{ext}"))
+        .unwrap_or_default();
+
+    // a TypeScript enum is a value, which `export type` could not be used as
+    let mut expected = String::new();
+    writeln!(expected, "// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.").unwrap();
+    writeln!(
+        expected,
+        r#"export type {{ Account }} from "./Account{ext}";"#
+    )
+    .unwrap();
+    writeln!(expected, r#"export {{ Status }} from "./Status{ext}";"#).unwrap();
+
+    let barrel = cfg.out_dir().join("barrels/enums/index.ts");
+    assert_eq!(std::fs::read_to_string(barrel).unwrap(), expected);
+}
+
+#[derive(TS)]
+#[ts(export_to = "barrels/renamed/")]
+struct Renamed {
+    id: u32,
+}
+
+#[test]
+fn previous_run() {
+    let cfg = Config::from_env().with_barrels();
+    let barrel = cfg.out_dir().join("barrels/renamed/index.ts");
+    std::fs::create_dir_all(barrel.parent().unwrap()).unwrap();
+
+    // left behind by a previous run, in which `Renamed` had another name
+    let mut previous = String::new();
+    writeln!(previous, "// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.").unwrap();
+    writeln!(previous, r#"export type {{ Removed }} from "./Removed";"#).unwrap();
+    std::fs::write(&barrel, previous).unwrap();
+
+    Renamed::export(&cfg).unwrap();
+
+    let contents = std::fs::read_to_string(barrel).unwrap();
+    assert!(contents.contains("{ Renamed }"));
+    assert!(!contents.contains("Removed"));
+}
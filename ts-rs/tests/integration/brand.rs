@@ -0,0 +1,118 @@
+#![allow(dead_code)]
+
+use ts_rs::{Config, TS};
+
+#[derive(TS)]
+#[ts(export_to = "brand/", brand)]
+struct UserId(String);
+
+#[derive(TS)]
+#[ts(export_to = "brand/")]
+struct OrderId(i32);
+
+#[derive(TS)]
+#[ts(export_to = "brand/", transparent)]
+struct Name {
+    value: String,
+}
+
+#[derive(TS)]
+#[ts(export_to = "brand/", brand)]
+struct Nickname(Option<String>);
+
+#[derive(TS)]
+#[ts(export_to = "brand/", brand)]
+struct Id<T>(T);
+
+#[derive(TS)]
+#[ts(export_to = "brand/", brand)]
+struct Email(#[ts(type = "string")] String);
+
+#[derive(TS)]
+#[ts(export_to = "brand/", brand)]
+struct Code(#[ts(type = "number | string")] String);
+
+#[derive(TS)]
+#[ts(export_to = "brand/", brand)]
+struct Key(#[ts(type = "keyof User")] String);
+
+#[derive(TS)]
+#[ts(export_to = "brand/")]
+struct User {
+    id: UserId,
+    name: Name,
+}
+
+#[test]
+fn attribute() {
+    let cfg = Config::new();
+    assert_eq!(
+        UserId::decl(&cfg),
+        r#"type UserId = string & { readonly __brand: "UserId" };"#
+    );
+    assert_eq!(OrderId::decl(&cfg), "type OrderId = number;");
+    assert_eq!(
+        Nickname::decl(&cfg),
+        r#"type Nickname = (string | null) & { readonly __brand: "Nickname" };"#
+    );
+}
+
+#[test]
+fn type_override() {
+    let cfg = Config::new();
+    assert_eq!(
+        Email::decl(&cfg),
+        r#"type Email = string & { readonly __brand: "Email" };"#
+    );
+    assert_eq!(
+        Code::decl(&cfg),
+        r#"type Code = (number | string) & { readonly __brand: "Code" };"#
+    );
+    // TypeScript which is not understood is always enclosed
+    assert_eq!(
+        Key::decl(&cfg),
+        r#"type Key = (keyof User) & { readonly __brand: "Key" };"#
+    );
+}
+
+#[test]
+fn branded_newtypes() {
+    let cfg = Config::new().with_branded_newtypes();
+    assert_eq!(
+        OrderId::decl(&cfg),
+        r#"type OrderId = number & { readonly __brand: "OrderId" };"#
+    );
+    // `transparent` structs are only branded if requested
+    assert_eq!(Name::decl(&cfg), "type Name = string;");
+}
+
+#[test]
+fn constructor() {
+    let cfg = Config::new().with_brand_constructors();
+    let exported = UserId::export_to_string(&cfg).unwrap();
+    assert!(exported.contains("export const UserId = (value: string): UserId => value as UserId;"));
+
+    let exported = Id::<()>::export_to_string(&cfg).unwrap();
+    assert!(exported.contains("export const Id = <T>(value: T): Id<T> => value as Id<T>;"));
+
+    let exported = OrderId::export_to_string(&cfg).unwrap();
+    assert!(!exported.contains("export const"));
+
+    // dependencies are exported with their constructors, but only their types are used
+    let cfg = Config::from_env().with_brand_constructors();
+    User::export_all(&cfg).unwrap();
+
+    let user = std::fs::read_to_string(cfg.out_dir().join("brand/User.ts")).unwrap();
+    assert!(user.contains(r#"import type { UserId } from "./UserId"#));
+    let user_id = std::fs::read_to_string(cfg.out_dir().join("brand/UserId.ts")).unwrap();
+    assert!(user_id.contains("export const UserId = (value: string): UserId => value as UserId;"));
+}
+
+#[test]
+fn type_guard() {
+    let guard = UserId::guard(&Config::new());
+    assert_eq!(
+        guard,
+        r#"function isUserId(value: unknown): value is UserId { return typeof value === "string"; }"#
+    );
+}
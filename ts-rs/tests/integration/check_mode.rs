@@ -0,0 +1,186 @@
+#![allow(dead_code)]
+
+use std::fs;
+
+use ts_rs::{Config, ExportError, TS};
+
+#[derive(TS)]
+#[ts(export_to = "check_mode/")]
+struct Checked {
+    id: u32,
+    dependency: CheckedDependency,
+}
+
+#[derive(TS)]
+#[ts(export_to = "check_mode/")]
+struct CheckedDependency {
+    name: String,
+}
+
+#[derive(TS)]
+#[ts(export_to = "check_mode/")]
+struct Modified {
+    name: String,
+}
+
+#[derive(TS)]
+#[ts(export_to = "check_mode/")]
+struct Missing {
+    name: String,
+}
+
+#[derive(TS)]
+#[ts(export_to = "stale_files/")]
+struct Fresh {
+    name: String,
+}
+
+const NOTE: &str = "// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.\n";
+
+#[test]
+fn up_to_date() {
+    let cfg = Config::from_env();
+    Checked::export_all(&cfg).unwrap();
+
+    Checked::export_all(&Config::from_env().with_check_only()).unwrap();
+}
+
+#[test]
+fn out_of_date() {
+    let cfg = Config::from_env();
+    Modified::export(&cfg).unwrap();
+
+    let path = cfg.out_dir().join(Modified::output_path().unwrap());
+    let contents = fs::read_to_string(&path).unwrap();
+    fs::write(&path, contents.replace("name: string", "name: number")).unwrap();
+
+    let Err(ExportError::OutOfDate {
+        path: out_of_date,
+        diff,
+    }) = Modified::export(&Config::from_env().with_check_only())
+    else {
+        panic!("expected the bindings to be out of date");
+    };
+    assert!(out_of_date.ends_with(Modified::output_path().unwrap()));
+    assert!(diff
+        .lines()
+        .any(|line| line.starts_with('-') && line.contains("name: number")));
+    assert!(diff
+        .lines()
+        .any(|line| line.starts_with('+') && line.contains("name: string")));
+
+    // nothing is written in check mode
+    let contents = fs::read_to_string(&path).unwrap();
+    assert!(contents.contains("name: number"));
+}
+
+#[test]
+fn missing() {
+    let cfg = Config::from_env().with_check_only();
+    let path = cfg.out_dir().join(Missing::output_path().unwrap());
+    let _ = fs::remove_file(&path);
+
+    let result = Missing::export(&cfg);
+    assert!(matches!(result, Err(ExportError::OutOfDate { .. })));
+    assert!(!path.exists());
+}
+
+#[test]
+fn stale_files() {
+    let cfg = Config::from_env();
+    let dir = cfg.out_dir().join("stale_files");
+    fs::create_dir_all(&dir).unwrap();
+    fs::write(
+        dir.join("Orphan.ts"),
+        format!("{NOTE}\nexport type Orphan = string;\n"),
+    )
+    .unwrap();
+    fs::write(
+        dir.join("Handwritten.ts"),
+        "export type Handwritten = string;\n",
+    )
+    .unwrap();
+
+    Fresh::export(&cfg).unwrap();
+
+    let cfg = Config::from_env().with_out_dir(&dir);
+    let stale = ts_rs::stale_files(&cfg).unwrap();
+    assert_eq!(stale.len(), 1);
+    assert!(stale[0].ends_with("stale_files/Orphan.ts"));
+
+    let removed = ts_rs::remove_stale_files(&cfg).unwrap();
+    assert_eq!(removed, stale);
+    assert!(!dir.join("Orphan.ts").exists());
+    assert!(dir.join("Handwritten.ts").exists());
+    assert!(dir.join("Fresh.ts").exists());
+}
+
+#[derive(TS)]
+#[ts(export_to = "check_stale/")]
+struct Kept {
+    name: String,
+}
+
+#[test]
+fn check_stale_files() {
+    let cfg = Config::from_env();
+    let dir = cfg.out_dir().join("check_stale");
+    let _ = fs::remove_dir_all(&dir);
+    Kept::export(&cfg).unwrap();
+
+    // only the files in this directory, since other tests check files as well
+    let check = Config::from_env().with_check_only().with_out_dir(&dir);
+    Kept::export(&Config::from_env().with_check_only()).unwrap();
+    ts_rs::check_files(&check).unwrap();
+
+    // e.g. the bindings of a type which was removed
+    fs::write(
+        dir.join("Removed.ts"),
+        format!("{NOTE}\nexport type Removed = string;\n"),
+    )
+    .unwrap();
+    let Err(ExportError::Stale { paths }) = ts_rs::check_files(&check) else {
+        panic!("expected the file of `Removed` to be reported");
+    };
+    assert_eq!(paths.len(), 1);
+    assert!(paths[0].ends_with("check_stale/Removed.ts"));
+}
+
+#[derive(TS)]
+#[ts(export_to = "check_files/Shared.ts")]
+struct First {
+    name: String,
+}
+
+#[derive(TS)]
+#[ts(export_to = "check_files/Shared.ts")]
+struct Second {
+    name: String,
+}
+
+#[test]
+fn check_files() {
+    let cfg = Config::from_env();
+    let _ = fs::remove_file(cfg.out_dir().join("check_files/Shared.ts"));
+    First::export(&cfg).unwrap();
+    Second::export(&cfg).unwrap();
+
+    let check = Config::from_env().with_check_only();
+    // only the files in this directory, since other tests check files as well
+    let dir = Config::from_env()
+        .with_check_only()
+        .with_out_dir(cfg.out_dir().join("check_files"));
+    First::export(&check).unwrap();
+
+    // `Second` is part of the file, but has not been checked
+    let Err(ExportError::OutOfDate { path, diff }) = ts_rs::check_files(&dir) else {
+        panic!("expected the declaration of `Second` to be reported");
+    };
+    assert!(path.ends_with("check_files/Shared.ts"));
+    assert!(diff
+        .lines()
+        .any(|line| line.starts_with('-') && line.contains("type Second")));
+
+    Second::export(&check).unwrap();
+    ts_rs::check_files(&dir).unwrap();
+}
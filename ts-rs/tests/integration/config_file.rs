@@ -0,0 +1,255 @@
+#![cfg(feature = "config-file")]
+
+use std::{fs, path::PathBuf, process::Command};
+
+use ts_rs::{Config, Direction, ExportError, Layout};
+
+fn dir(name: &str) -> PathBuf {
+    let dir = Config::from_env().out_dir().join("config_file").join(name);
+    fs::create_dir_all(&dir).unwrap();
+    dir
+}
+
+fn invalid(contents: &str) -> String {
+    let path = dir("invalid").join("ts-rs.toml");
+    fs::write(&path, contents).unwrap();
+    match Config::from_file(&path) {
+        Err(ExportError::InvalidConfig { reason, .. }) => reason,
+        _ => panic!("`{contents}` should be rejected"),
+    }
+}
+
+#[test]
+fn from_file() {
+    let dir = dir("from_file");
+    let path = dir.join("ts-rs.toml");
+    fs::write(
+        &path,
+        r#"
+        out-dir = "generated"
+        large-int = "number"
+        import-extension = "js"
+        array-tuple-limit = 8
+        direction = "serialize"
+        interfaces = true
+        readonly = false
+        layout = "single-line"
+        indent = "\t"
+        line-width = 100
+        "#,
+    )
+    .unwrap();
+
+    let cfg = Config::from_file(&path).unwrap();
+    assert_eq!(cfg.out_dir(), dir.join("generated"));
+    assert_eq!(cfg.large_int(), "number");
+    assert_eq!(cfg.import_extension(), Some("js"));
+    assert_eq!(cfg.array_tuple_limit(), 8);
+    assert_eq!(cfg.direction(), Direction::Serialize);
+    assert!(cfg.interfaces());
+    assert!(!cfg.readonly());
+    assert_eq!(cfg.layout(), Layout::SingleLine);
+    assert_eq!(cfg.indent(), "\t");
+    assert_eq!(cfg.line_width(), 100);
+}
+
+#[test]
+fn manifest_metadata() {
+    let dir = dir("manifest_metadata");
+    fs::write(
+        dir.join("Cargo.toml"),
+        r#"
+        [package]
+        name = "app"
+
+        [package.metadata.ts-rs]
+        large-int = "string"
+        type-guards = true
+        "#,
+    )
+    .unwrap();
+
+    let cfg = Config::load_from(&dir).unwrap();
+    assert_eq!(cfg.large_int(), "string");
+    assert!(cfg.type_guards());
+
+    // `ts-rs.toml` takes precedence over the manifest
+    fs::write(dir.join("ts-rs.toml"), "large-int = \"number\"").unwrap();
+    let cfg = Config::load_from(&dir).unwrap();
+    assert_eq!(cfg.large_int(), "number");
+    assert!(!cfg.type_guards());
+    fs::remove_file(dir.join("ts-rs.toml")).unwrap();
+
+    fs::write(
+        dir.join("Cargo.toml"),
+        "[package.metadata.ts-rs]\nlarge_int = \"string\"",
+    )
+    .unwrap();
+    let Err(ExportError::InvalidConfig { reason, .. }) = Config::load_from(&dir) else {
+        panic!("an unknown key should be rejected");
+    };
+    assert_eq!(reason, "unknown key `package.metadata.ts-rs.large_int`");
+}
+
+#[test]
+fn without_file() {
+    let dir = dir("without_file");
+    let cfg = Config::load_from(&dir).unwrap();
+    assert_eq!(cfg.out_dir(), Config::from_env().out_dir());
+    assert_eq!(cfg.large_int(), Config::from_env().large_int());
+}
+
+#[test]
+fn errors() {
+    assert_eq!(invalid("out_dir = \"bindings\""), "unknown key `out_dir`");
+    assert_eq!(
+        invalid("import-extension = \"mjs\""),
+        r#"invalid value `"mjs"` for `import-extension`, expected "js" or "ts""#
+    );
+    assert_eq!(
+        invalid("direction = \"both-ways\""),
+        r#"invalid value `"both-ways"` for `direction`, expected "both", "serialize" or "deserialize""#
+    );
+    assert_eq!(
+        invalid("readonly = \"yes\""),
+        r#"invalid value `"yes"` for `readonly`, expected `true` or `false`"#
+    );
+    assert_eq!(
+        invalid("array-tuple-limit = -1"),
+        "invalid value `-1` for `array-tuple-limit`, expected an integer in the range of `usize`"
+    );
+    assert!(invalid("large-int = ").starts_with("TOML parse error at line 1, column 13"));
+
+    #[cfg(not(feature = "zod"))]
+    assert_eq!(
+        invalid("zod = true"),
+        "`zod` requires the `zod` feature of ts-rs"
+    );
+}
+
+#[test]
+fn env() {
+    let dir = dir("env");
+    fs::write(dir.join("ts-rs.toml"), "interfaces = true\nreadonly = true").unwrap();
+
+    // environment variables are set in a separate process, so that other tests are not affected
+    let load = |vars: &[(&str, &str)]| {
+        let output = Command::new(std::env::current_exe().unwrap())
+            .args(["config_file::load_env", "--exact", "--ignored", "--nocapture"])
+            .env("TS_RS_TEST_CONFIG_DIR", &dir)
+            .env_remove("TS_RS_INTERFACES")
+            .env_remove("TS_RS_READONLY")
+            .envs(vars.iter().copied())
+            .output()
+            .unwrap();
+        String::from_utf8(output.stdout).unwrap()
+    };
+
+    assert!(load(&[]).contains("interfaces: true, readonly: true"));
+    assert!(load(&[("TS_RS_INTERFACES", "0"), ("TS_RS_READONLY", "false")])
+        .contains("interfaces: false, readonly: false"));
+    assert!(load(&[("TS_RS_INTERFACES", "")]).contains("interfaces: true"));
+    assert!(load(&[("TS_RS_DIRECTION", "foo")]).contains(
+        "the configuration is invalid: invalid value `foo` for `TS_RS_DIRECTION`, \
+         expected `both`, `serialize` or `deserialize`"
+    ));
+    assert!(load(&[("TS_RS_LAYOUT", "pretty")]).contains(
+        "the configuration is invalid: invalid value `pretty` for `TS_RS_LAYOUT`, \
+         expected `single-line` or `multi-line`"
+    ));
+    assert!(load(&[("TS_RS_CHECK", "maybe")]).contains(
+        "the configuration is invalid: invalid value `maybe` for `TS_RS_CHECK`, \
+         expected `true` or `false`"
+    ));
+}
+
+#[test]
+fn from_env() {
+    let read = |vars: &[(&str, &str)]| {
+        let output = Command::new(std::env::current_exe().unwrap())
+            .args(["config_file::read_env", "--exact", "--ignored", "--nocapture"])
+            .env("TS_RS_TEST_READ_ENV", "1")
+            .env_remove("TS_RS_INTERFACES")
+            .envs(vars.iter().copied())
+            .output()
+            .unwrap();
+        let stdout = String::from_utf8(output.stdout).unwrap();
+        let stderr = String::from_utf8(output.stderr).unwrap();
+        (stdout, stderr)
+    };
+
+    // invalid values are ignored with a warning, other variables are still read
+    let (stdout, stderr) = read(&[("TS_RS_USE_V11_HASHMAP", "2"), ("TS_RS_INTERFACES", "1")]);
+    assert!(stdout.contains("interfaces: true"));
+    assert!(stderr.contains(
+        "warning: the configuration is invalid: invalid value `2` for `TS_RS_USE_V11_HASHMAP`, \
+         expected `true` or `false`, ignoring it"
+    ));
+    assert!(stdout.contains(
+        "the configuration is invalid: invalid value `2` for `TS_RS_USE_V11_HASHMAP`, \
+         expected `true` or `false`"
+    ));
+
+    let (stdout, stderr) = read(&[("TS_RS_INTERFACES", "1")]);
+    assert!(stdout.contains("interfaces: true\ninterfaces: true"));
+    assert!(!stderr.contains("warning"));
+}
+
+// run by `from_env` in a separate process, printing the results of `Config::from_env` and
+// `Config::try_from_env`
+#[test]
+#[ignore]
+fn read_env() {
+    if std::env::var_os("TS_RS_TEST_READ_ENV").is_none() {
+        return;
+    }
+
+    println!("interfaces: {}", Config::from_env().interfaces());
+    match Config::try_from_env() {
+        Ok(cfg) => println!("interfaces: {}", cfg.interfaces()),
+        Err(e) => println!("{e}"),
+    }
+}
+
+// run by `env` in a separate process
+#[test]
+#[ignore]
+fn load_env() {
+    let Some(dir) = std::env::var_os("TS_RS_TEST_CONFIG_DIR") else {
+        return;
+    };
+
+    match Config::load_from(dir) {
+        Ok(cfg) => println!(
+            "interfaces: {}, readonly: {}",
+            cfg.interfaces(),
+            cfg.readonly()
+        ),
+        Err(e) => println!("{e}"),
+    }
+}
+
+#[test]
+#[cfg(feature = "format")]
+fn format() {
+    use ts_rs::{FormatOptions, QuoteStyle};
+
+    let path = dir("format").join("ts-rs.toml");
+    fs::write(
+        &path,
+        "[format]\nindent-width = 4\nquote-style = \"single\"\nsemicolons = false",
+    )
+    .unwrap();
+    assert_eq!(
+        Config::from_file(&path).unwrap().formatting(),
+        &FormatOptions::new()
+            .with_indent_width(4)
+            .with_quote_style(QuoteStyle::Single)
+            .with_semicolons(false)
+    );
+
+    assert_eq!(
+        invalid("[format]\nindent-width = 300"),
+        "invalid value `300` for `format.indent-width`, expected an integer in the range of `u8`"
+    );
+}
@@ -145,14 +145,14 @@ fn export_a() {
             " *\n",
             " * Testing\n",
             " */\n",
-            "export type A = { \n",
-            "/**\n",
-            " * Doc of field\n",
-            " *\n",
-            " * Testing\n",
-            " */\n",
-            "name: string, };",
-            "\n",
+            "export type A = {\n",
+            "  /**\n",
+            "   * Doc of field\n",
+            "   *\n",
+            "   * Testing\n",
+            "   */\n",
+            "  name: string,\n",
+            "};\n",
         )
     };
 
@@ -192,14 +192,14 @@ fn export_b() {
             " *\n",
             " * Testing\n",
             " */\n",
-            "export type B = { \n",
-            "/**\n",
-            " * Doc of field\n",
-            " *\n",
-            " * Testing\n",
-            " */\n",
-            "name: string, };",
-            "\n",
+            "export type B = {\n",
+            "  /**\n",
+            "   * Doc of field\n",
+            "   *\n",
+            "   * Testing\n",
+            "   */\n",
+            "  name: string,\n",
+            "};\n",
         )
     };
 
@@ -301,14 +301,19 @@ fn export_f() {
             " *\n",
             " * Testing\n",
             " */\n",
-            "export type F = \"VarA\" | { \"VarB\": never[] } | { \"VarC\": { \n",
-            "/**\n",
-            " * Doc of field of variant\n",
-            " *\n",
-            " * Testing\n",
-            " */\n",
-            "variant_field: number, } };",
-            "\n",
+            "export type F =\n",
+            "  | \"VarA\"\n",
+            "  | { \"VarB\": never[] }\n",
+            "  | {\n",
+            "      \"VarC\": {\n",
+            "        /**\n",
+            "         * Doc of field of variant\n",
+            "         *\n",
+            "         * Testing\n",
+            "         */\n",
+            "        variant_field: number,\n",
+            "      }\n",
+            "    };\n",
         )
     };
 
@@ -344,20 +349,26 @@ fn export_g() {
         )
     } else {
         concat!(
-            "// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.\n",
-            "\n",
-            "export type G = { \n",
-            "/**\n",
-            " * Docs\n",
-            " */\n",
-            "some_other_field: number, } & (\"VarA\" | { \"VarB\": never[] } | { \"VarC\": { \n",
-            "/**\n",
-            " * Doc of field of variant\n",
-            " *\n",
-            " * Testing\n",
-            " */\n",
-            "variant_field: number, } });",
-            "\n",
+            "// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.\n\n",
+            "export type G = {\n",
+            "  /**\n",
+            "   * Docs\n",
+            "   */\n",
+            "  some_other_field: number,\n",
+            "} & (\n",
+            "  | \"VarA\"\n",
+            "  | { \"VarB\": never[] }\n",
+            "  | {\n",
+            "      \"VarC\": {\n",
+            "        /**\n",
+            "         * Doc of field of variant\n",
+            "         *\n",
+            "         * Testing\n",
+            "         */\n",
+            "        variant_field: number,\n",
+            "      }\n",
+            "    }\n",
+            ");\n",
         )
     };
 
@@ -388,7 +399,9 @@ fn export_h() {
             " *\n",
             " * works\n",
             " */\n",
-            "export type H = { foo: number, };\n",
+            "export type H = {\n",
+            "  foo: number,\n",
+            "};\n",
         )
     };
 
@@ -420,19 +433,20 @@ fn export_i() {
         )
     } else {
         concat!(
-        "// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.\n\n",
-        "/**\n",
-        " *line 107\n",
-        " */\n",
-        "export type I = { \n",
-        "/**\n",
-        " *column 32\n",
-        " */\n",
-        "a: number, \n",
-        "/**\n",
-        " *path integration::docs\n",
-        " */\n",
-        "b: number, };\n",
+            "// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.\n\n",
+            "/**\n",
+            " *line 107\n",
+            " */\n",
+            "export type I = {\n",
+            "  /**\n",
+            "   *column 32\n",
+            "   */\n",
+            "  a: number,\n",
+            "  /**\n",
+            "   *path integration::docs\n",
+            "   */\n",
+            "  b: number,\n",
+            "};\n",
         )
     };
 
@@ -32,9 +32,12 @@ fn export_manually() {
         )
     } else {
         concat!(
-            "// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.\n",
-            "\nexport type User = { name: string, age: number, active: boolean, };",
-            "\n",
+            "// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.\n\n",
+            "export type User = {\n",
+            "  name: string,\n",
+            "  age: number,\n",
+            "  active: boolean,\n",
+            "};\n",
         )
     };
 
@@ -54,9 +57,12 @@ fn export_manually_dir() {
         )
     } else {
         concat!(
-            "// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.\n",
-            "\nexport type UserDir = { name: string, age: number, active: boolean, };",
-            "\n",
+            "// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.\n\n",
+            "export type UserDir = {\n",
+            "  name: string,\n",
+            "  age: number,\n",
+            "  active: boolean,\n",
+            "};\n",
         )
     };
 
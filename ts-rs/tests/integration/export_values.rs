@@ -0,0 +1,146 @@
+#![allow(dead_code)]
+
+use ts_rs::{Config, TS};
+
+#[derive(TS)]
+#[ts(export_to = "export_values/", export_values)]
+enum Color {
+    Red,
+    Green,
+    #[ts(rename = "light-blue")]
+    LightBlue,
+}
+
+#[derive(TS)]
+#[ts(export_to = "export_values/", export_values, rename_all = "snake_case")]
+enum HttpMethod {
+    Get,
+    PostForm,
+    #[ts(skip)]
+    Trace,
+}
+
+#[derive(TS)]
+#[ts(
+    export_to = "export_values/",
+    export_values = "ALL_ROLES",
+    rename = "UserRole"
+)]
+enum Role {
+    Admin,
+    Guest,
+}
+
+#[derive(TS)]
+#[ts(export_to = "export_values/", export_values, repr(enum))]
+enum Priority {
+    Low = 1,
+    High = 10,
+    #[ts(rename = "very high")]
+    VeryHigh = 100,
+}
+
+#[derive(TS)]
+#[ts(export_to = "export_values/", export_values, repr(enum = name))]
+enum Direction {
+    Up,
+    Down,
+}
+
+#[test]
+fn variant_values() {
+    let cfg = Config::new();
+    assert_eq!(
+        Color::variant_values(&cfg),
+        Some((
+            "COLOR_VALUES".to_owned(),
+            vec![
+                r#""Red""#.to_owned(),
+                r#""Green""#.to_owned(),
+                r#""light-blue""#.to_owned()
+            ]
+        ))
+    );
+}
+
+#[test]
+fn union() {
+    let cfg = Config::new();
+    let exported = Color::export_to_string(&cfg).unwrap();
+    assert!(exported.contains(r#"export type Color = "Red" | "Green" | "light-blue";"#));
+    assert!(exported
+        .contains(r#"export const COLOR_VALUES = ["Red", "Green", "light-blue"] as const;"#));
+
+    let exported = HttpMethod::export_to_string(&cfg).unwrap();
+    assert!(
+        exported.contains(r#"export const HTTP_METHOD_VALUES = ["get", "post_form"] as const;"#)
+    );
+}
+
+#[test]
+fn name() {
+    let cfg = Config::new();
+    let exported = Role::export_to_string(&cfg).unwrap();
+    assert!(exported.contains(r#"export const ALL_ROLES = ["Admin", "Guest"] as const;"#));
+}
+
+#[test]
+fn repr_enum() {
+    let cfg = Config::new();
+    let exported = Priority::export_to_string(&cfg).unwrap();
+    assert!(exported.contains(
+        r#"export const PRIORITY_VALUES = [Priority.Low, Priority.High, Priority["very high"]] as const;"#
+    ));
+
+    let exported = Direction::export_to_string(&cfg).unwrap();
+    assert!(exported
+        .contains("export const DIRECTION_VALUES = [Direction.Up, Direction.Down] as const;"));
+}
+
+#[test]
+fn not_exported() {
+    #[derive(TS)]
+    enum Plain {
+        A,
+    }
+
+    assert_eq!(Plain::variant_values(&Config::new()), None);
+}
+
+#[derive(TS)]
+#[ts(export_to = "export_values/barrel/shapes.ts", export_values)]
+enum Shape {
+    Circle,
+    Square,
+}
+
+#[derive(TS)]
+#[ts(export_to = "export_values/barrel/shapes.ts")]
+enum Size {
+    Small,
+    Large,
+}
+
+#[test]
+fn barrel() {
+    let cfg = Config::from_env().with_barrels();
+    Shape::export(&cfg).unwrap();
+    Size::export(&cfg).unwrap();
+
+    let ext = cfg
+        .import_extension()
+        .map(|ext| format!(".{ext}"))
+        .unwrap_or_default();
+
+    let shapes = std::fs::read_to_string(cfg.out_dir().join("export_values/barrel/shapes.ts"));
+    assert!(shapes.unwrap().contains("SHAPE_VALUES"));
+
+    let barrel = std::fs::read_to_string(cfg.out_dir().join("export_values/barrel/index.ts"));
+    let barrel = barrel.unwrap();
+    assert!(barrel.contains(&format!(
+        r#"export type {{ Shape, Size }} from "./shapes{ext}";"#
+    )));
+    assert!(barrel.contains(&format!(
+        r#"export {{ SHAPE_VALUES }} from "./shapes{ext}";"#
+    )));
+}
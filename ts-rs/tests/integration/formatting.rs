@@ -0,0 +1,160 @@
+#![cfg(feature = "format")]
+#![allow(dead_code)]
+
+use std::fs;
+
+use ts_rs::{Config, ExportError, FormatOptions, QuoteStyle, TS};
+
+#[derive(TS)]
+#[ts(export_to = "formatting/")]
+struct Formatted {
+    name: String,
+    dependency: FormattedDependency,
+}
+
+#[derive(TS)]
+#[ts(export_to = "formatting/")]
+struct FormattedDependency {
+    tag: char,
+}
+
+#[derive(TS)]
+#[ts(export_to = "formatting/merged.ts")]
+struct MergedA {
+    dependency: FormattedDependency,
+}
+
+#[derive(TS)]
+#[ts(export_to = "formatting/merged.ts")]
+struct MergedB {
+    dependency: FormattedDependency,
+}
+
+const NOTE: &str = "// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.\n";
+
+fn options() -> FormatOptions {
+    FormatOptions::new()
+        .with_indent_width(4)
+        .with_quote_style(QuoteStyle::Single)
+        .with_semicolons(false)
+        .with_line_width(40)
+}
+
+#[test]
+fn custom_options() {
+    let cfg = Config::from_env().with_formatting(options());
+    Formatted::export(&cfg).unwrap();
+
+    let contents = fs::read_to_string(cfg.out_dir().join("formatting/Formatted.ts")).unwrap();
+    assert_eq!(
+        contents,
+        format!(
+            "{NOTE}\
+            import type {{ FormattedDependency }} from './FormattedDependency'\n\
+            \n\
+            export type Formatted = {{\n    \
+                name: string\n    \
+                dependency: FormattedDependency\n\
+            }}\n"
+        )
+    );
+}
+
+#[test]
+fn merged_without_semicolons() {
+    let cfg = Config::from_env().with_formatting(options());
+    MergedA::export(&cfg).unwrap();
+    MergedB::export(&cfg).unwrap();
+
+    let contents = fs::read_to_string(cfg.out_dir().join("formatting/merged.ts")).unwrap();
+    assert_eq!(
+        contents,
+        format!(
+            "{NOTE}\
+            import type {{ FormattedDependency }} from './FormattedDependency'\n\
+            \n\
+            export type MergedA = {{\n    \
+                dependency: FormattedDependency\n\
+            }}\n\
+            \n\
+            export type MergedB = {{\n    \
+                dependency: FormattedDependency\n\
+            }}\n"
+        )
+    );
+
+    let cfg = Config::from_env()
+        .with_formatting(options())
+        .with_check_only();
+    MergedB::export(&cfg).unwrap();
+}
+
+#[test]
+fn dprint_json() {
+    let dir = Config::from_env().out_dir().join("formatting");
+    fs::create_dir_all(&dir).unwrap();
+    let path = dir.join("dprint.json");
+    fs::write(
+        &path,
+        r#"{
+            // shared by all plugins
+            "indentWidth": 4,
+            "lineWidth": 40,
+            "typescript": {
+                "quoteStyle": "alwaysSingle", /* ASI */
+                "semiColons": "asi"
+            },
+            "excludes": ["**/node_modules"]
+        }"#,
+    )
+    .unwrap();
+
+    assert_eq!(FormatOptions::from_dprint_json(&path).unwrap(), options());
+
+    fs::write(&path, r#"{ "typescript": { "quoteStyle": "backtick" } }"#).unwrap();
+    let Err(ExportError::InvalidConfig { reason, .. }) = FormatOptions::from_dprint_json(&path)
+    else {
+        panic!("an invalid quote style should be rejected");
+    };
+    assert_eq!(reason, r#"invalid value `"backtick"` for `quoteStyle`"#);
+}
+
+#[test]
+fn editorconfig() {
+    let dir = Config::from_env().out_dir().join("formatting");
+    fs::create_dir_all(&dir).unwrap();
+    let path = dir.join(".editorconfig");
+    fs::write(
+        &path,
+        "root = true\n\
+        \n\
+        [*]\n\
+        indent_style = space\n\
+        indent_size = 2\n\
+        \n\
+        # TypeScript\n\
+        [*.{js,ts}]\n\
+        indent_size = 4\n\
+        max_line_length = 40\n\
+        quote_type = single\n\
+        \n\
+        [*.md]\n\
+        indent_size = 8\n",
+    )
+    .unwrap();
+
+    assert_eq!(
+        FormatOptions::from_editorconfig(&path).unwrap(),
+        FormatOptions::new()
+            .with_indent_width(4)
+            .with_line_width(40)
+            .with_quote_style(QuoteStyle::Single)
+    );
+
+    fs::write(&path, "[*]\nindent_size = wide\n").unwrap();
+    let Err(ExportError::InvalidConfig { reason, .. }) = FormatOptions::from_editorconfig(&path)
+    else {
+        panic!("an invalid indent size should be rejected");
+    };
+    assert_eq!(reason, "invalid value `wide` for `indent_size`");
+}
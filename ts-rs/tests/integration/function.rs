@@ -0,0 +1,87 @@
+#![allow(dead_code, unused_variables)]
+
+use ts_rs::{Config, TS};
+
+#[derive(TS)]
+#[ts(export_to = "function/")]
+struct User {
+    name: String,
+}
+
+#[derive(TS)]
+#[ts(export_to = "function/")]
+enum Role {
+    Admin,
+    Guest,
+}
+
+/// Creates a new user
+#[ts_rs::function(export, export_to = "function/")]
+fn create_user(name: String, age: u32, role: Role) -> Result<User, String> {
+    unimplemented!()
+}
+
+#[ts_rs::function(export_to = "function/", declare)]
+fn delete_user(name: &str) {}
+
+#[ts_rs::function(
+    export_to = "function/",
+    rename = "listUsers",
+    rename_all = "camelCase"
+)]
+fn users(page_size: Option<u32>, #[ts(optional)] role_filter: Option<Role>) -> Vec<User> {
+    unimplemented!()
+}
+
+#[ts_rs::function(export_to = "function/")]
+fn ping() {}
+
+#[test]
+fn signature() {
+    let cfg = Config::new();
+    assert_eq!(create_user::ident(&cfg), "createUser");
+    assert_eq!(
+        create_user::decl(&cfg),
+        "type createUser = (args: { name: string, age: number, role: Role, }) => Promise<User>;"
+    );
+    assert_eq!(
+        users::decl(&cfg),
+        "type listUsers = (args: { pageSize: number | null, roleFilter?: Role, }) => Promise<Array<User>>;"
+    );
+    assert_eq!(ping::decl(&cfg), "type ping = () => Promise<void>;");
+}
+
+#[test]
+fn declare() {
+    let cfg = Config::new();
+    assert_eq!(
+        delete_user::decl(&cfg),
+        "declare function deleteUser(args: { name: string, }): Promise<void>;"
+    );
+}
+
+#[test]
+fn dependencies() {
+    let cfg = Config::new();
+    let deps = create_user::dependencies(&cfg);
+    assert_eq!(deps.len(), 2);
+    assert!(deps.iter().any(|dep| dep.ts_name == "User"));
+    assert!(deps.iter().any(|dep| dep.ts_name == "Role"));
+}
+
+#[test]
+#[cfg(not(feature = "format"))]
+fn export() {
+    let cfg = Config::from_env();
+    let content = create_user::export_to_string(&cfg).unwrap();
+    let ext = cfg
+        .import_extension()
+        .map(|ext| format!(".{ext}"))
+        .unwrap_or_default();
+
+    assert!(content.contains(&format!(r#"import type {{ Role }} from "./Role{ext}";"#)));
+    assert!(content.contains(&format!(r#"import type {{ User }} from "./User{ext}";"#)));
+    assert!(content.contains(
+        "/**\n * Creates a new user\n */\nexport type createUser = (args: { name: string, age: number, role: Role, }) => Promise<User>;"
+    ));
+}
@@ -51,8 +51,10 @@ fn test_def() {
         ))
     } else {
         expected.push_str(concat!(
-            "export type TestEnum = { \"C\": { value: TestTypeB<number>, } } | { \"A1\": { value: TestTypeA<number>, } } | { \"A2\": { value: TestTypeA<number>, } };",
-            "\n",
+            "export type TestEnum =\n",
+            "  | { \"C\": { value: TestTypeB<number>, } }\n",
+            "  | { \"A1\": { value: TestTypeA<number>, } }\n",
+            "  | { \"A2\": { value: TestTypeA<number>, } };\n",
         ))
     };
 
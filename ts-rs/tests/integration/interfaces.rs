@@ -0,0 +1,126 @@
+#![allow(dead_code)]
+
+use ts_rs::{Config, TS};
+
+#[derive(TS)]
+#[ts(export_to = "interfaces/")]
+struct Base {
+    id: u32,
+}
+
+#[derive(TS)]
+#[ts(export_to = "interfaces/")]
+struct Timestamps {
+    created_at: String,
+}
+
+#[derive(TS)]
+#[ts(export_to = "interfaces/")]
+struct User {
+    name: String,
+    #[ts(flatten)]
+    base: Base,
+    #[ts(flatten)]
+    timestamps: Timestamps,
+}
+
+#[derive(TS)]
+#[ts(export_to = "interfaces/")]
+struct Page<T> {
+    items: Vec<T>,
+    #[ts(flatten)]
+    base: Base,
+}
+
+#[derive(TS)]
+#[ts(export_to = "interfaces/")]
+enum Shape {
+    Circle { radius: f64 },
+    Square { side: f64 },
+}
+
+#[derive(TS)]
+#[ts(export_to = "interfaces/")]
+struct Drawing {
+    name: String,
+    #[ts(flatten)]
+    shape: Shape,
+}
+
+#[derive(TS)]
+#[ts(export_to = "interfaces/", interface)]
+struct Forced {
+    value: i32,
+}
+
+#[derive(TS)]
+#[ts(export_to = "interfaces/")]
+struct Point(i32, i32);
+
+#[test]
+fn disabled() {
+    let cfg = Config::new();
+    assert_eq!(
+        User::decl(&cfg),
+        "type User = { name: string, id: number, created_at: string, };"
+    );
+}
+
+#[test]
+fn interfaces() {
+    let cfg = Config::new().with_interfaces();
+    assert_eq!(Base::decl(&cfg), "interface Base { id: number, }");
+    assert_eq!(
+        User::decl(&cfg),
+        "interface User extends Base, Timestamps { name: string, }"
+    );
+    assert_eq!(
+        Page::<()>::decl(&cfg),
+        "interface Page<T> extends Base { items: Array<T>, }"
+    );
+    assert_eq!(Point::decl(&cfg), "type Point = [number, number];");
+}
+
+#[test]
+fn flattened_union() {
+    let cfg = Config::new().with_interfaces();
+    assert_eq!(
+        Drawing::decl(&cfg),
+        "type Drawing = { name: string, } & ({ \"Circle\": { radius: number, } } | { \"Square\": { side: number, } });"
+    );
+}
+
+#[test]
+fn attribute() {
+    assert_eq!(
+        Forced::decl(&Config::new()),
+        "interface Forced { value: number, }"
+    );
+}
+
+#[test]
+fn imports() {
+    let cfg = Config::from_env().with_interfaces();
+    let ext = cfg
+        .import_extension()
+        .map(|ext| format!(".{ext}"))
+        .unwrap_or_default();
+
+    let exported = User::export_to_string(&cfg).unwrap();
+    assert!(exported.contains(&format!("import type {{ Base }} from \"./Base{ext}\";")));
+    assert!(exported.contains("export interface User extends Base, Timestamps {"));
+
+    // without interfaces, the fields of flattened types are inlined
+    let exported = User::export_to_string(&Config::new()).unwrap();
+    assert!(!exported.contains("import"));
+
+    User::export_all(&cfg).unwrap();
+}
+
+#[test]
+fn type_guard() {
+    let cfg = Config::new().with_interfaces();
+    let guard = User::guard(&cfg);
+    assert!(guard.contains("isBase("));
+    assert!(guard.contains("isTimestamps("));
+}
@@ -2,7 +2,7 @@
 
 use std::collections::HashMap;
 
-use ts_rs::{Config, TS};
+use ts_rs::{Config, Layout, TS};
 
 #[derive(TS)]
 #[ts(export, export_to = "issue_168/")]
@@ -33,7 +33,7 @@ struct Baz {
 
 #[test]
 fn issue_168() {
-    let cfg = Config::from_env();
+    let cfg = Config::from_env().with_layout(Layout::SingleLine);
     let extension = cfg
         .import_extension()
         .map(|ext| format!(".{ext}"))
@@ -1,6 +1,6 @@
 #![allow(unused)]
 
-use ts_rs::{Config, TS};
+use ts_rs::{Config, Layout, TS};
 
 #[derive(TS)]
 #[ts(export, export_to = "issue_232/")]
@@ -43,7 +43,7 @@ enum Enum {
 
 #[test]
 fn issue_232() {
-    let cfg = Config::from_env();
+    let cfg = Config::from_env().with_layout(Layout::SingleLine);
     let extension = cfg
         .import_extension()
         .map(|ext| format!(".{ext}"))
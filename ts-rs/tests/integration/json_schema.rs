@@ -0,0 +1,209 @@
+#![allow(dead_code)]
+
+use std::{collections::HashMap, fs};
+
+use serde_json::{json, Value};
+use ts_rs::{Config, ExportError, TS};
+
+/// A registered user
+#[derive(TS)]
+#[ts(export_to = "json_schema/", rename_all = "camelCase")]
+struct User {
+    user_id: u32,
+    /// The name of the user
+    display_name: String,
+    #[ts(optional)]
+    email: Option<String>,
+    role: Role,
+    #[ts(flatten)]
+    meta: Meta,
+    tags: HashMap<Role, Vec<String>>,
+    page: Page<Role>,
+    pair: (i32, Option<bool>),
+    next: Option<Box<User>>,
+}
+
+#[derive(TS)]
+#[ts(export_to = "json_schema/")]
+struct Meta {
+    created: i64,
+}
+
+#[derive(TS, Hash, PartialEq, Eq)]
+#[ts(export_to = "json_schema/")]
+enum Role {
+    Admin,
+    Guest,
+}
+
+#[derive(TS)]
+#[ts(export_to = "json_schema/", tag = "kind")]
+enum Event {
+    Login { user: User },
+    Logout,
+}
+
+#[derive(TS)]
+#[ts(export_to = "json_schema/")]
+struct Page<T> {
+    items: Vec<T>,
+}
+
+const NOTE: &str = "This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.";
+
+fn schema<T: TS + 'static>() -> Value {
+    let schema = T::json_schema(&Config::from_env()).unwrap();
+    serde_json::from_str(&schema).unwrap()
+}
+
+#[test]
+fn object() {
+    assert_eq!(
+        schema::<User>(),
+        json!({
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "$comment": NOTE,
+            "title": "User",
+            "description": "A registered user",
+            "type": "object",
+            "properties": {
+                "userId": { "type": "number" },
+                "displayName": { "type": "string", "description": "The name of the user" },
+                "email": { "type": "string" },
+                "role": { "$ref": "./Role.schema.json" },
+                "tags": {
+                    "type": "object",
+                    "propertyNames": { "$ref": "./Role.schema.json" },
+                    "additionalProperties": { "type": "array", "items": { "type": "string" } }
+                },
+                "page": {
+                    "type": "object",
+                    "properties": {
+                        "items": { "type": "array", "items": { "$ref": "./Role.schema.json" } }
+                    },
+                    "required": ["items"]
+                },
+                "pair": {
+                    "type": "array",
+                    "prefixItems": [
+                        { "type": "number" },
+                        { "anyOf": [{ "type": "boolean" }, { "type": "null" }] }
+                    ],
+                    "minItems": 2,
+                    "maxItems": 2
+                },
+                "next": { "anyOf": [{ "$ref": "#" }, { "type": "null" }] },
+                "created": { "type": "integer" }
+            },
+            "required": ["userId", "displayName", "role", "tags", "page", "pair", "next", "created"]
+        })
+    );
+}
+
+#[test]
+fn enums() {
+    assert_eq!(
+        schema::<Role>(),
+        json!({
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "$comment": NOTE,
+            "title": "Role",
+            "enum": ["Admin", "Guest"]
+        })
+    );
+    assert_eq!(
+        schema::<Event>(),
+        json!({
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "$comment": NOTE,
+            "title": "Event",
+            "anyOf": [
+                {
+                    "type": "object",
+                    "properties": {
+                        "kind": { "const": "Login" },
+                        "user": { "$ref": "./User.schema.json" }
+                    },
+                    "required": ["kind", "user"]
+                },
+                {
+                    "type": "object",
+                    "properties": { "kind": { "const": "Logout" } },
+                    "required": ["kind"]
+                }
+            ]
+        })
+    );
+}
+
+#[test]
+fn generics() {
+    assert_eq!(
+        schema::<Page<Role>>(),
+        json!({
+            "$schema": "https://json-schema.org/draft/2020-12/schema",
+            "$comment": NOTE,
+            "title": "Page",
+            "type": "object",
+            "properties": { "items": { "type": "array", "items": true } },
+            "required": ["items"]
+        })
+    );
+}
+
+#[test]
+fn export() {
+    let cfg = Config::from_env();
+    Event::export_json_schema(&cfg).unwrap();
+
+    let dir = cfg.out_dir().join("json_schema");
+    for ty in ["Event", "User", "Role", "Page"] {
+        let path = dir.join(format!("{ty}.schema.json"));
+        let schema = std::fs::read_to_string(&path).unwrap();
+        let schema: Value = serde_json::from_str(&schema).unwrap();
+        assert_eq!(schema["title"], ty);
+    }
+}
+
+#[derive(TS)]
+#[ts(export_to = "json_schema_check/")]
+struct Checked {
+    name: String,
+}
+
+#[test]
+fn check_mode() {
+    let cfg = Config::from_env();
+    Checked::export_json_schema(&cfg).unwrap();
+    Checked::export_json_schema(&Config::from_env().with_check_only()).unwrap();
+
+    let path = cfg.out_dir().join("json_schema_check/Checked.schema.json");
+    let contents = fs::read_to_string(&path).unwrap();
+    fs::write(&path, contents.replace("\"string\"", "\"number\"")).unwrap();
+
+    let result = Checked::export_json_schema(&Config::from_env().with_check_only());
+    assert!(matches!(result, Err(ExportError::OutOfDate { .. })));
+    assert!(fs::read_to_string(&path).unwrap().contains("\"number\""));
+}
+
+#[derive(TS)]
+#[ts(export_to = "json_schema_stale/")]
+struct Fresh {
+    name: String,
+}
+
+#[test]
+fn stale_files() {
+    let cfg = Config::from_env();
+    let dir = cfg.out_dir().join("json_schema_stale");
+    fs::create_dir_all(&dir).unwrap();
+    let orphan = json!({ "$schema": "https://json-schema.org/draft/2020-12/schema", "$comment": NOTE });
+    fs::write(dir.join("Orphan.schema.json"), format!("{orphan:#}\n")).unwrap();
+    fs::write(dir.join("Handwritten.schema.json"), "{}\n").unwrap();
+
+    Fresh::export_json_schema(&cfg).unwrap();
+
+    let stale = ts_rs::stale_files(&Config::from_env().with_out_dir(&dir)).unwrap();
+    assert_eq!(stale.len(), 1);
+    assert!(stale[0].ends_with("json_schema_stale/Orphan.schema.json"));
+}
@@ -0,0 +1,197 @@
+#![cfg(not(feature = "format"))]
+#![allow(dead_code)]
+
+use std::collections::HashMap;
+
+use ts_rs::{Config, Layout, TS};
+
+#[derive(TS)]
+#[ts(export_to = "layout/")]
+struct Profile {
+    /// Shown next to every post
+    name: String,
+    links: HashMap<String, Vec<(String, u32)>>,
+    status: Status,
+    #[ts(inline)]
+    settings: Settings,
+}
+
+#[derive(TS)]
+#[ts(export_to = "layout/")]
+struct Settings {
+    notifications_enabled: bool,
+    theme: String,
+    separators: String,
+}
+
+#[derive(TS)]
+#[ts(export_to = "layout/")]
+enum Status {
+    Online,
+    Offline { last_seen_at_unix_timestamp: u32 },
+    Away { reason: String, until: Option<u32> },
+}
+
+#[derive(TS)]
+#[ts(export_to = "layout/")]
+enum Short {
+    A,
+    B,
+}
+
+#[derive(TS)]
+#[ts(export_to = "layout/", interface)]
+struct Quoted {
+    #[ts(type = r#""{ not: an, object }" | "(a | b)""#)]
+    quoted: String,
+}
+
+#[derive(TS)]
+#[ts(export_to = "layout/", repr(enum))]
+enum Color {
+    Red,
+    Green,
+}
+
+#[derive(TS)]
+#[ts(export_to = "layout/")]
+struct Entry(String, #[ts(inline)] Settings);
+
+fn decl<T: TS + 'static>(cfg: &Config) -> String {
+    let exported = T::export_to_string(cfg).unwrap();
+    let (_, decl) = exported.split_once("\n\n").unwrap();
+    decl.to_owned()
+}
+
+#[test]
+fn objects() {
+    let cfg = Config::new();
+    assert_eq!(
+        decl::<Profile>(&cfg),
+        "export type Profile = {\n  \
+           /**\n   \
+            * Shown next to every post\n   \
+            */\n  \
+           name: string,\n  \
+           links: { [key in string]: Array<[string, number]> },\n  \
+           status: Status,\n  \
+           settings: {\n    \
+             notifications_enabled: boolean,\n    \
+             theme: string,\n    \
+             separators: string,\n  \
+           },\n\
+         };\n"
+    );
+}
+
+#[test]
+fn unions() {
+    let cfg = Config::new();
+    assert_eq!(
+        decl::<Status>(&cfg),
+        "export type Status =\n  \
+           | \"Online\"\n  \
+           | { \"Offline\": { last_seen_at_unix_timestamp: number, } }\n  \
+           | { \"Away\": { reason: string, until: number | null, } };\n"
+    );
+
+    // unions which fit on a line are kept as they are
+    assert_eq!(decl::<Short>(&cfg), "export type Short = \"A\" | \"B\";\n");
+}
+
+#[test]
+fn line_width() {
+    let cfg = Config::new().with_line_width(50);
+    assert_eq!(
+        decl::<Status>(&cfg),
+        "export type Status =\n  \
+           | \"Online\"\n  \
+           | {\n      \
+               \"Offline\": {\n        \
+                 last_seen_at_unix_timestamp: number,\n      \
+               }\n    \
+             }\n  \
+           | {\n      \
+               \"Away\": {\n        \
+                 reason: string,\n        \
+                 until: number | null,\n      \
+               }\n    \
+             };\n"
+    );
+}
+
+#[test]
+fn indent() {
+    let cfg = Config::new().with_indent("\t");
+    assert_eq!(
+        decl::<Settings>(&cfg),
+        "export type Settings = {\n\
+         \tnotifications_enabled: boolean,\n\
+         \ttheme: string,\n\
+         \tseparators: string,\n\
+         };\n"
+    );
+}
+
+#[test]
+fn strings() {
+    let cfg = Config::new().with_line_width(20);
+    assert_eq!(
+        decl::<Quoted>(&cfg),
+        "export interface Quoted {\n  \
+           quoted: \"{ not: an, object }\" | \"(a | b)\",\n\
+         }\n"
+    );
+}
+
+#[test]
+fn enums() {
+    let cfg = Config::new();
+    assert_eq!(
+        decl::<Color>(&cfg),
+        "export enum Color {\n  \
+           \"Red\",\n  \
+           \"Green\"\n\
+         }\n"
+    );
+}
+
+#[test]
+fn single_line() {
+    let cfg = Config::new().with_layout(Layout::SingleLine);
+    assert_eq!(
+        decl::<Settings>(&cfg),
+        "export type Settings = { notifications_enabled: boolean, theme: string, separators: string, };\n"
+    );
+    assert_eq!(
+        decl::<Settings>(&cfg),
+        format!("export {}\n", Settings::decl(&cfg))
+    );
+}
+
+#[test]
+fn tuples() {
+    let cfg = Config::new().with_line_width(40);
+    assert_eq!(
+        decl::<Entry>(&cfg),
+        "export type Entry = [\n  \
+           string,\n  \
+           {\n    \
+             notifications_enabled: boolean,\n    \
+             theme: string,\n    \
+             separators: string,\n  \
+           }\n\
+         ];\n"
+    );
+}
+
+#[test]
+fn only_exported_files() {
+    // `TS::decl` is always written on a single line
+    let cfg = Config::new();
+    assert_eq!(cfg.layout(), Layout::MultiLine);
+    assert_eq!(
+        Settings::decl(&cfg),
+        "type Settings = { notifications_enabled: boolean, theme: string, separators: string, };"
+    );
+}
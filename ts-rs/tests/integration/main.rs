@@ -1,4 +1,4 @@
-#![allow(dead_code, unused)]
+#![allow(dead_code, unused, clippy::assertions_on_constants, clippy::incompatible_msrv)]
 
 use std::path::PathBuf;
 
@@ -6,20 +6,29 @@ use ts_rs::{Config, TS};
 
 mod arrays;
 mod arrayvec;
+mod ast;
+mod atomic_writes;
+mod barrels;
 mod bound;
+mod brand;
 mod bson;
+mod check_mode;
 mod chrono;
 mod complex_flattened_type;
 mod concrete_generic;
+mod config_file;
 mod docs;
 mod enum_flattening;
 mod enum_flattening_nested;
 mod enum_struct_rename_all;
 mod enum_variant_annotation;
 mod export_manually;
+mod export_values;
 mod export_to;
 mod field_rename;
 mod flatten;
+mod formatting;
+mod function;
 mod generic_fields;
 mod generic_without_import;
 mod generics;
@@ -30,6 +39,7 @@ mod impl_primitive;
 mod imports;
 mod indexmap;
 mod infer_as;
+mod interfaces;
 mod issue_168;
 mod issue_232;
 mod issue_308;
@@ -40,35 +50,47 @@ mod issue_415;
 mod issue_70;
 mod issue_80;
 mod jiff;
+mod json_schema;
+mod layout;
 mod leading_colon;
 mod lifetimes;
 mod list;
+mod merge_declarations;
 mod merge_same_file_imports;
 mod nested;
 mod optional_field;
 mod path_bug;
 mod ranges;
 mod raw_idents;
+mod readonly;
 mod recursion_limit;
 mod references;
+mod registry;
 mod repr_enum;
 mod same_file_export;
 mod self_referential;
 mod semver;
+mod serde_direction;
+mod serde_from_into;
 mod serde_json;
 mod serde_skip_serializing;
 mod serde_skip_with_default;
 mod serde_with;
+mod service;
 mod simple;
+mod single_file;
 mod skip;
 mod slices;
 mod struct_rename;
 mod struct_tag;
+mod testing;
 mod tokio;
 mod top_level_type_as;
 mod top_level_type_override;
+mod transparent;
 mod tuple;
 mod type_as;
+mod type_guards;
 mod type_override;
 mod union;
 mod union_named_serde_skip;
@@ -79,6 +101,8 @@ mod union_with_data;
 mod union_with_internal_tag;
 mod unit;
 mod r#unsized;
+mod values;
+mod zod;
 
 // Returns the path to the file into which `T` is exported
 fn target_file<T: TS>(cfg: &Config) -> PathBuf {
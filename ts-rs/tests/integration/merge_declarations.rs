@@ -0,0 +1,110 @@
+#![allow(dead_code)]
+
+use std::fs;
+
+use ts_rs::{Config, ExportError, TS};
+
+/// A color.
+///
+/// Colors are exported as an `enum`.
+#[derive(TS)]
+#[ts(export_to = "merge_declarations/shared.ts", repr(enum))]
+enum Color {
+    Red,
+    Green,
+}
+
+/// A shape, with `{ unbalanced braces` and "quotes; in its docs
+#[derive(TS)]
+#[ts(export_to = "merge_declarations/shared.ts")]
+struct Shape {
+    color: Color,
+    /// The name of the shape.
+    ///
+    /// export type NotADeclaration = string;
+    name: String,
+}
+
+#[derive(TS)]
+#[ts(export_to = "merge_declarations/shared.ts")]
+struct Area {
+    shape: Shape,
+    size: f64,
+}
+
+#[derive(TS)]
+#[ts(export_to = "merge_declarations/broken.ts")]
+struct Valid {
+    a: i32,
+}
+
+#[derive(TS)]
+#[ts(export_to = "merge_declarations/broken.ts")]
+struct Other {
+    b: i32,
+}
+
+#[test]
+fn merge_declarations() {
+    let cfg = Config::from_env();
+    Shape::export_all(&cfg).unwrap();
+    Area::export(&cfg).unwrap();
+
+    let path = cfg.out_dir().join(Shape::output_path().unwrap());
+    let contents = fs::read_to_string(path).unwrap();
+
+    let area = contents.find("export type Area").unwrap();
+    let color = contents.find("export enum Color").unwrap();
+    let shape = contents.find("export type Shape").unwrap();
+    assert!(area < color && color < shape);
+
+    assert!(contents.contains("Colors are exported as an `enum`."));
+    assert!(contents.contains("A shape, with `{ unbalanced braces` and \"quotes; in its docs"));
+    assert!(contents.contains("export type NotADeclaration = string;"));
+    let declarations = contents.lines().filter(|l| l.starts_with("export "));
+    assert_eq!(declarations.count(), 3);
+}
+
+#[test]
+fn invalid_file() {
+    let cfg = Config::from_env();
+    Valid::export(&cfg).unwrap();
+
+    let path = cfg.out_dir().join(Valid::output_path().unwrap());
+    fs::write(&path, "export type Valid = { a: number;\n").unwrap();
+
+    let result = Other::export(&cfg);
+    assert!(matches!(result, Err(ExportError::Merge { .. })));
+    fs::remove_file(path).unwrap();
+}
+
+#[derive(TS)]
+#[ts(export_to = "merge_declarations/previous.ts")]
+struct Rewritten {
+    name: String,
+}
+
+#[test]
+fn previous_contents() {
+    let cfg = Config::from_env();
+    let path = cfg.out_dir().join(Rewritten::output_path().unwrap());
+    fs::create_dir_all(path.parent().unwrap()).unwrap();
+
+    // left behind by a previous run, which also exported `Kept` into the file
+    let note = "// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.\n";
+    let previous = [
+        "import type { Shape } from \"./shared\";\n",
+        "\nexport type Kept = string;\n",
+        "\nexport type Rewritten = { shape: Shape, };\n",
+    ];
+    fs::write(&path, format!("{note}{}", previous.concat())).unwrap();
+
+    Rewritten::export(&cfg).unwrap();
+
+    let contents = fs::read_to_string(&path).unwrap();
+    assert!(!contents.contains("Kept"));
+    assert!(contents.contains("name: string"));
+    assert!(!contents.contains("shape: Shape"));
+    // the import was only used by the previous declaration of `Rewritten`
+    assert!(!contents.contains("import"));
+}
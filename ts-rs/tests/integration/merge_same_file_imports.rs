@@ -73,7 +73,13 @@ fn merge_same_file_imports() {
         writeln!(expected, "  extra: Extra;").unwrap();
         writeln!(expected, "}};").unwrap();
     } else {
-        writeln!(expected, "export {}", EditProfile::decl(&cfg)).unwrap();
+        writeln!(expected, "export type EditProfile = {{").unwrap();
+        writeln!(expected, "  name: string | null,").unwrap();
+        writeln!(expected, "  game_version: string | null,").unwrap();
+        writeln!(expected, "  loader: ModLoader | null,").unwrap();
+        writeln!(expected, "  hooks: Hooks | null,").unwrap();
+        writeln!(expected, "  extra: Extra,").unwrap();
+        writeln!(expected, "}};").unwrap();
     }
 
     assert_eq!(text, expected)
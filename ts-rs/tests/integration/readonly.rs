@@ -0,0 +1,82 @@
+#![allow(dead_code)]
+
+use std::collections::HashMap;
+
+use ts_rs::{Config, TS};
+
+#[derive(TS)]
+#[ts(export_to = "readonly/")]
+struct State {
+    #[ts(readonly)]
+    id: u32,
+    items: Vec<String>,
+    pair: (i32, bool),
+    position: [f64; 2],
+    counts: HashMap<String, u32>,
+    #[ts(readonly)]
+    history: Vec<(i32, bool)>,
+}
+
+#[derive(TS)]
+#[ts(export_to = "readonly/", readonly)]
+struct Frozen {
+    name: String,
+    tags: Vec<String>,
+}
+
+#[derive(TS)]
+#[ts(export_to = "readonly/", readonly)]
+struct Point(i32, i32);
+
+#[derive(TS)]
+#[ts(export_to = "readonly/", tag = "type")]
+enum Event {
+    Click { x: i32 },
+    Key(String),
+}
+
+#[test]
+fn attribute() {
+    let cfg = Config::new();
+    assert_eq!(
+        State::decl(&cfg),
+        "type State = { readonly id: number, items: Array<string>, pair: [number, boolean], \
+         position: [number, number], counts: { [key in string]: number }, \
+         readonly history: ReadonlyArray<readonly [number, boolean]>, };"
+    );
+    assert_eq!(
+        Frozen::decl(&cfg),
+        "type Frozen = { readonly name: string, readonly tags: ReadonlyArray<string>, };"
+    );
+    assert_eq!(Point::decl(&cfg), "type Point = readonly [number, number];");
+}
+
+#[test]
+fn config() {
+    let cfg = Config::new().with_readonly();
+    assert_eq!(
+        State::decl(&cfg),
+        "type State = { readonly id: number, readonly items: ReadonlyArray<string>, \
+         readonly pair: readonly [number, boolean], readonly position: readonly [number, number], \
+         readonly counts: { readonly [key in string]: number }, \
+         readonly history: ReadonlyArray<readonly [number, boolean]>, };"
+    );
+    assert_eq!(
+        Event::decl(&cfg),
+        r#"type Event = { readonly "type": "Click", readonly x: number, } | { readonly "type": "Key" } & string;"#
+    );
+}
+
+#[test]
+fn type_guard() {
+    let cfg = Config::new().with_readonly();
+    let guard = State::guard(&cfg);
+    assert!(guard.contains("Array.isArray"));
+    assert!(!guard.contains("isReadonlyArray"));
+}
+
+#[test]
+fn export() {
+    Frozen::export_all(&Config::from_env().with_readonly()).unwrap();
+    State::export_all(&Config::from_env().with_readonly()).unwrap();
+}
@@ -0,0 +1,50 @@
+#![cfg(feature = "registry")]
+#![allow(dead_code)]
+
+use std::fs;
+
+use ts_rs::{Config, TS};
+
+#[derive(TS)]
+#[ts(export, export_to = "registry/")]
+struct Registered {
+    dependency: RegisteredDependency,
+}
+
+// not exported itself, but as a dependency of `Registered`
+#[derive(TS)]
+#[ts(export_to = "registry/")]
+struct RegisteredDependency {
+    name: String,
+}
+
+#[derive(TS)]
+#[ts(export_to = "registry/")]
+struct Unregistered {
+    name: String,
+}
+
+#[test]
+fn export_registered() {
+    let base = Config::from_env().out_dir().to_owned();
+    let cfg = Config::from_env().with_out_dir(base.join("registry"));
+    ts_rs::export_registered(&cfg).unwrap();
+
+    // the same files are written when exporting the type manually
+    let manual = Config::from_env().with_out_dir(base.join("registry_manual"));
+    Registered::export_all(&manual).unwrap();
+
+    for file in ["Registered.ts", "RegisteredDependency.ts"] {
+        assert_eq!(
+            fs::read_to_string(cfg.out_dir().join("registry").join(file)).unwrap(),
+            fs::read_to_string(manual.out_dir().join("registry").join(file)).unwrap(),
+        );
+    }
+    assert!(!cfg.out_dir().join("registry/Unregistered.ts").exists());
+
+    // every file in the output directory was exported by this process
+    assert_eq!(
+        ts_rs::stale_files(&cfg).unwrap(),
+        Vec::<std::path::PathBuf>::new()
+    );
+}
@@ -0,0 +1,188 @@
+#![cfg(feature = "serde-compat")]
+#![allow(dead_code)]
+
+use serde::{Deserialize, Serialize};
+use ts_rs::{Config, Direction, TS};
+
+#[derive(Serialize, Deserialize, TS)]
+#[ts(export, export_to = "serde_direction/")]
+struct Secret(String);
+
+#[derive(Serialize, Deserialize, TS)]
+#[ts(export, export_to = "serde_direction/")]
+struct User {
+    #[serde(rename(serialize = "userId", deserialize = "user_id"))]
+    id: u32,
+    #[serde(rename(deserialize = "login"), alias = "username")]
+    name: String,
+    #[serde(skip_deserializing)]
+    created: u64,
+    #[serde(skip_serializing)]
+    password: Secret,
+}
+
+fn cfg(direction: Direction) -> Config {
+    Config::from_env().with_direction(direction)
+}
+
+#[test]
+fn named() {
+    assert_eq!(
+        User::inline(&cfg(Direction::Both)),
+        "{ userId: number, name: string, created: bigint, password: Secret, }"
+    );
+    assert_eq!(
+        User::inline(&cfg(Direction::Serialize)),
+        "{ userId: number, name: string, created: bigint, }"
+    );
+    assert_eq!(
+        User::inline(&cfg(Direction::Deserialize)),
+        "{ user_id: number, login: string, password: Secret, }"
+    );
+}
+
+#[test]
+fn dependencies() {
+    let deps = User::dependencies(&cfg(Direction::Deserialize));
+    assert_eq!(deps.len(), 1);
+    assert_eq!(deps[0].ts_name, "Secret");
+
+    assert!(User::dependencies(&cfg(Direction::Serialize)).is_empty());
+}
+
+#[derive(Serialize, Deserialize, TS)]
+#[ts(export, export_to = "serde_direction/")]
+struct Optional {
+    #[serde(default)]
+    a: i32,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    b: Option<i32>,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    c: Option<i32>,
+}
+
+#[test]
+fn optional() {
+    assert_eq!(
+        Optional::inline(&cfg(Direction::Both)),
+        "{ a: number, b: number | null, c?: number | null, }"
+    );
+    assert_eq!(
+        Optional::inline(&cfg(Direction::Serialize)),
+        "{ a: number, b?: number | null, c?: number | null, }"
+    );
+    assert_eq!(
+        Optional::inline(&cfg(Direction::Deserialize)),
+        "{ a?: number, b: number | null, c?: number | null, }"
+    );
+}
+
+#[derive(Serialize, Deserialize, TS)]
+#[ts(export, export_to = "serde_direction/")]
+#[serde(default)]
+struct Defaults {
+    a: i32,
+    #[serde(skip_serializing_if = "String::is_empty")]
+    b: String,
+}
+
+impl Default for Defaults {
+    fn default() -> Self {
+        Self {
+            a: 42,
+            b: String::new(),
+        }
+    }
+}
+
+#[test]
+fn container_default() {
+    assert_eq!(
+        Defaults::inline(&cfg(Direction::Both)),
+        "{ a: number, b?: string, }"
+    );
+    assert_eq!(
+        Defaults::inline(&cfg(Direction::Serialize)),
+        "{ a: number, b?: string, }"
+    );
+    assert_eq!(
+        Defaults::inline(&cfg(Direction::Deserialize)),
+        "{ a?: number, b?: string, }"
+    );
+}
+
+#[derive(Serialize, Deserialize, TS)]
+#[ts(export, export_to = "serde_direction/")]
+struct Tuple(i32, #[serde(skip_deserializing)] String, bool);
+
+#[test]
+fn tuple() {
+    assert_eq!(
+        Tuple::inline(&cfg(Direction::Both)),
+        "[number, string, boolean]"
+    );
+    assert_eq!(
+        Tuple::inline(&cfg(Direction::Deserialize)),
+        "[number, boolean]"
+    );
+}
+
+#[derive(Serialize, Deserialize, TS)]
+#[ts(export, export_to = "serde_direction/")]
+#[serde(tag = "type")]
+enum Event {
+    #[serde(rename(serialize = "created", deserialize = "create"))]
+    Created { id: u32 },
+    #[serde(skip_deserializing)]
+    Deleted { id: u32 },
+    #[serde(skip_serializing)]
+    Restore(Secret),
+}
+
+#[test]
+fn variants() {
+    assert_eq!(
+        Event::inline(&cfg(Direction::Both)),
+        r#"{ "type": "created", id: number, } | { "type": "Deleted", id: number, } | { "type": "Restore" } & Secret"#
+    );
+    assert_eq!(
+        Event::inline(&cfg(Direction::Serialize)),
+        r#"{ "type": "created", id: number, } | { "type": "Deleted", id: number, }"#
+    );
+    assert_eq!(
+        Event::inline(&cfg(Direction::Deserialize)),
+        r#"{ "type": "create", id: number, } | { "type": "Restore" } & Secret"#
+    );
+
+    assert!(Event::dependencies(&cfg(Direction::Serialize)).is_empty());
+}
+
+#[derive(Serialize, Deserialize, TS)]
+#[ts(export, export_to = "serde_direction/")]
+enum Outgoing {
+    #[serde(skip_deserializing)]
+    A,
+    #[serde(skip_deserializing)]
+    B,
+}
+
+#[test]
+fn all_variants_skipped() {
+    assert_eq!(Outgoing::inline(&cfg(Direction::Serialize)), r#""A" | "B""#);
+    assert_eq!(Outgoing::inline(&cfg(Direction::Deserialize)), "never");
+}
+
+#[derive(Serialize, Deserialize, TS)]
+#[ts(export, export_to = "serde_direction/")]
+struct Renamed {
+    #[ts(rename = "value")]
+    #[serde(rename(serialize = "a", deserialize = "b"))]
+    field: i32,
+}
+
+#[test]
+fn ts_rename_takes_precedence() {
+    for direction in [Direction::Both, Direction::Serialize, Direction::Deserialize] {
+        assert_eq!(Renamed::inline(&cfg(direction)), "{ value: number, }");
+    }
+}
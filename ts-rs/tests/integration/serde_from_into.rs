@@ -0,0 +1,244 @@
+#![cfg(feature = "serde-compat")]
+#![allow(dead_code)]
+
+use serde::{Deserialize, Serialize};
+use ts_rs::{Config, Direction, TS};
+
+#[derive(Clone, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "serde_from_into/")]
+struct Id(u32);
+
+#[derive(Serialize, Deserialize, TS)]
+#[ts(export, export_to = "serde_from_into/")]
+struct UserResponse {
+    id: Id,
+    name: String,
+}
+
+#[derive(Serialize, Deserialize, TS)]
+#[ts(export, export_to = "serde_from_into/")]
+struct UserRequest {
+    name: String,
+}
+
+// Serialized through `UserResponse`, but deserialized using its own fields
+#[derive(Clone, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "serde_from_into/")]
+#[serde(into = "UserResponse")]
+struct User {
+    id: Id,
+    name: String,
+    password: String,
+}
+
+impl From<User> for UserResponse {
+    fn from(user: User) -> Self {
+        Self {
+            id: user.id,
+            name: user.name,
+        }
+    }
+}
+
+#[test]
+fn into() {
+    let cfg = Config::from_env();
+    let response = "{ id: Id, name: string, }";
+
+    assert_eq!(User::inline(&cfg), response);
+    assert_eq!(User::decl(&cfg), format!("type User = {response};"));
+
+    let cfg = Config::from_env().with_direction(Direction::Serialize);
+    assert_eq!(User::inline(&cfg), response);
+
+    let cfg = Config::from_env().with_direction(Direction::Deserialize);
+    assert_eq!(
+        User::inline(&cfg),
+        "{ id: Id, name: string, password: string, }"
+    );
+}
+
+// Serialized through `UserResponse`, but deserialized from `UserRequest`
+#[derive(Clone, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "serde_from_into/")]
+#[serde(into = "UserResponse", try_from = "UserRequest")]
+struct Account {
+    id: Id,
+    name: String,
+}
+
+impl From<Account> for UserResponse {
+    fn from(account: Account) -> Self {
+        Self {
+            id: account.id,
+            name: account.name,
+        }
+    }
+}
+
+impl TryFrom<UserRequest> for Account {
+    type Error = &'static str;
+
+    fn try_from(request: UserRequest) -> Result<Self, Self::Error> {
+        Ok(Self {
+            id: Id(0),
+            name: request.name,
+        })
+    }
+}
+
+#[test]
+fn from_and_into() {
+    let response = "{ id: Id, name: string, }";
+    let request = "{ name: string, }";
+
+    let cfg = Config::from_env();
+    assert_eq!(Account::inline(&cfg), response);
+
+    let cfg = Config::from_env().with_direction(Direction::Serialize);
+    assert_eq!(Account::inline(&cfg), response);
+    assert_eq!(Account::decl(&cfg), format!("type Account = {response};"));
+
+    let cfg = Config::from_env().with_direction(Direction::Deserialize);
+    assert_eq!(Account::inline(&cfg), request);
+    assert_eq!(Account::decl(&cfg), format!("type Account = {request};"));
+}
+
+#[test]
+fn dependencies_follow_direction() {
+    let cfg = Config::from_env().with_direction(Direction::Serialize);
+    let deps = Account::dependencies(&cfg);
+    assert_eq!(deps.len(), 1);
+    assert_eq!(deps[0].ts_name, "Id");
+
+    let cfg = Config::from_env().with_direction(Direction::Deserialize);
+    assert!(Account::dependencies(&cfg).is_empty());
+}
+
+// Deserialized from a plain string, but serialized using its fields
+#[derive(Serialize, Deserialize, TS)]
+#[ts(export, export_to = "serde_from_into/")]
+#[serde(from = "String")]
+struct Name {
+    first: String,
+    last: Option<String>,
+}
+
+impl From<String> for Name {
+    fn from(name: String) -> Self {
+        Self {
+            first: name,
+            last: None,
+        }
+    }
+}
+
+#[test]
+fn from() {
+    let fields = "{ first: string, last: string | null, }";
+
+    let cfg = Config::from_env();
+    assert_eq!(Name::inline(&cfg), fields);
+
+    let cfg = Config::from_env().with_direction(Direction::Serialize);
+    assert_eq!(Name::inline(&cfg), fields);
+
+    let cfg = Config::from_env().with_direction(Direction::Deserialize);
+    assert_eq!(Name::inline(&cfg), "string");
+}
+
+#[derive(Clone, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "serde_from_into/")]
+#[serde(tag = "kind", from = "u8", into = "u8")]
+enum Level {
+    Low,
+    High,
+}
+
+impl From<u8> for Level {
+    fn from(value: u8) -> Self {
+        if value > 127 {
+            Self::High
+        } else {
+            Self::Low
+        }
+    }
+}
+
+impl From<Level> for u8 {
+    fn from(level: Level) -> Self {
+        match level {
+            Level::Low => 0,
+            Level::High => 255,
+        }
+    }
+}
+
+#[test]
+fn enum_from_into() {
+    for direction in [Direction::Both, Direction::Serialize, Direction::Deserialize] {
+        let cfg = Config::from_env().with_direction(direction);
+        assert_eq!(Level::decl(&cfg), "type Level = number;");
+    }
+}
+
+// Serialized as a number, but deserialized from its variants
+#[derive(Clone, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "serde_from_into/")]
+#[serde(into = "u8")]
+enum Priority {
+    Low,
+    High,
+}
+
+impl From<Priority> for u8 {
+    fn from(priority: Priority) -> Self {
+        priority as u8
+    }
+}
+
+#[test]
+fn enum_into() {
+    for direction in [Direction::Both, Direction::Serialize] {
+        let cfg = Config::from_env().with_direction(direction);
+        assert_eq!(Priority::decl(&cfg), "type Priority = number;");
+    }
+
+    let cfg = Config::from_env().with_direction(Direction::Deserialize);
+    assert_eq!(Priority::decl(&cfg), r#"type Priority = "Low" | "High";"#);
+}
+
+// `#[ts(as = "..")]` takes precedence over serde's `into` and `from`
+#[derive(Clone, Serialize, Deserialize, TS)]
+#[ts(export, export_to = "serde_from_into/", as = "String")]
+#[serde(from = "UserRequest", into = "UserResponse")]
+struct Overridden {
+    id: Id,
+    name: String,
+}
+
+impl From<UserRequest> for Overridden {
+    fn from(request: UserRequest) -> Self {
+        Self {
+            id: Id(0),
+            name: request.name,
+        }
+    }
+}
+
+impl From<Overridden> for UserResponse {
+    fn from(overridden: Overridden) -> Self {
+        Self {
+            id: overridden.id,
+            name: overridden.name,
+        }
+    }
+}
+
+#[test]
+fn as_takes_precedence() {
+    for direction in [Direction::Both, Direction::Serialize, Direction::Deserialize] {
+        let cfg = Config::from_env().with_direction(direction);
+        assert_eq!(Overridden::inline(&cfg), "string");
+    }
+}
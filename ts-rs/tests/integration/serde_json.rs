@@ -1,4 +1,4 @@
-#![cfg(feature = "serde_json")]
+#![cfg(feature = "serde-json-impl")]
 #![allow(unused)]
 
 use ts_rs::{Config, TS};
@@ -0,0 +1,113 @@
+#![allow(dead_code)]
+
+use std::{collections::HashSet, future::Future};
+
+use ts_rs::{Config, TS};
+
+#[derive(TS)]
+#[ts(export_to = "service/")]
+struct UserId(u32);
+
+#[derive(TS)]
+#[ts(export_to = "service/")]
+struct User {
+    name: String,
+}
+
+/// Manages users
+#[ts_rs::service(export, export_to = "service/", map)]
+trait UserService {
+    async fn get_user(&self, id: UserId) -> Result<User, String>;
+    fn list_users(
+        &self,
+        page_size: u32,
+        name_filter: Option<&str>,
+    ) -> impl Future<Output = Vec<User>>;
+    #[ts(rename = "remove")]
+    fn delete_user(&mut self, id: UserId);
+    #[ts(skip)]
+    fn new() -> Self;
+}
+
+#[ts_rs::service(
+    export_to = "service/Api.ts",
+    rename = "Api",
+    rename_all = "snake_case",
+    map = "ApiEndpoints"
+)]
+trait ApiService {
+    fn health_check(&self) -> bool;
+}
+
+#[test]
+fn interface() {
+    let cfg = Config::new();
+    assert_eq!(UserServiceClient::ident(&cfg), "UserService");
+    assert_eq!(
+        UserServiceClient::decl(&cfg),
+        "interface UserService { \
+            getUser(id: UserId): Promise<User>, \
+            listUsers(pageSize: number, nameFilter: string | null): Promise<Array<User>>, \
+            remove(id: UserId): Promise<void>, \
+        }"
+    );
+    assert_eq!(
+        ApiServiceClient::decl(&cfg),
+        "interface Api { health_check(): Promise<boolean>, }"
+    );
+}
+
+#[test]
+fn map() {
+    let cfg = Config::new();
+    assert_eq!(
+        UserServiceMap::decl(&cfg),
+        "type UserServiceMap = { \
+            getUser: { req: { id: UserId, }, res: User }, \
+            listUsers: { req: { pageSize: number, nameFilter: string | null, }, res: Array<User> }, \
+            remove: { req: { id: UserId, }, res: void }, \
+        };"
+    );
+    assert_eq!(
+        ApiServiceMap::decl(&cfg),
+        "type ApiEndpoints = { health_check: { req: {  }, res: boolean }, };"
+    );
+    assert_eq!(
+        UserServiceMap::output_path(),
+        UserServiceClient::output_path()
+    );
+    assert_eq!(
+        ApiServiceMap::output_path(),
+        ApiServiceClient::output_path()
+    );
+}
+
+#[test]
+fn dependencies() {
+    let cfg = Config::new();
+    let deps = UserServiceClient::dependencies(&cfg)
+        .into_iter()
+        .map(|dep| dep.ts_name)
+        .collect::<HashSet<_>>();
+    assert_eq!(
+        deps,
+        HashSet::from(["UserId".to_owned(), "User".to_owned()])
+    );
+}
+
+#[test]
+#[cfg(not(feature = "format"))]
+fn export() {
+    let cfg = Config::from_env();
+    let content = UserServiceClient::export_to_string(&cfg).unwrap();
+    let ext = cfg
+        .import_extension()
+        .map(|ext| format!(".{ext}"))
+        .unwrap_or_default();
+
+    assert!(content.contains(&format!(r#"import type {{ User }} from "./User{ext}";"#)));
+    assert!(content.contains(&format!(
+        r#"import type {{ UserId }} from "./UserId{ext}";"#
+    )));
+    assert!(content.contains("/**\n * Manages users\n */\nexport interface UserService {\n"));
+}
@@ -0,0 +1,82 @@
+#![allow(dead_code)]
+
+use ts_rs::{Config, TS};
+
+#[derive(TS)]
+#[ts(export_to = "single_file/users/")]
+struct User {
+    id: u32,
+    role: Role,
+    address: Address,
+}
+
+#[derive(TS)]
+#[ts(export_to = "single_file/roles.ts")]
+enum Role {
+    Admin,
+    Guest,
+}
+
+#[derive(TS)]
+#[ts(export_to = "single_file/")]
+struct Address {
+    street: String,
+}
+
+#[derive(TS)]
+#[ts(export_to = "single_file/")]
+struct Session {
+    user: User,
+    token: String,
+}
+
+#[test]
+fn single_file() {
+    let cfg = Config::from_env().with_single_file("single_file/index.ts");
+    User::export_all(&cfg).unwrap();
+    Session::export_all(&cfg).unwrap();
+
+    let bundle = std::fs::read_to_string(cfg.out_dir().join("single_file/index.ts")).unwrap();
+    let expected = if cfg!(feature = "format") {
+        concat!(
+            "// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.\n",
+            "\n",
+            "export type Address = { street: string };\n",
+            "\n",
+            "export type Role = \"Admin\" | \"Guest\";\n",
+            "\n",
+            "export type Session = { user: User; token: string };\n",
+            "\n",
+            "export type User = { id: number; role: Role; address: Address };\n",
+        )
+    } else {
+        concat!(
+            "// This file was generated by [ts-rs](https://github.com/Aleph-Alpha/ts-rs). Do not edit this file manually.\n",
+            "\n",
+            "export type Address = {\n",
+            "  street: string,\n",
+            "};\n",
+            "\n",
+            "export type Role = \"Admin\" | \"Guest\";\n",
+            "\n",
+            "export type Session = {\n",
+            "  user: User,\n",
+            "  token: string,\n",
+            "};\n",
+            "\n",
+            "export type User = {\n",
+            "  id: number,\n",
+            "  role: Role,\n",
+            "  address: Address,\n",
+            "};\n",
+        )
+    };
+    assert_eq!(bundle, expected);
+}
+
+#[test]
+fn no_imports() {
+    let cfg = Config::from_env().with_single_file("single_file/index.ts");
+    let exported = Session::export_to_string(&cfg).unwrap();
+    assert!(!exported.contains("import"));
+}
@@ -0,0 +1,171 @@
+#![cfg(feature = "testing")]
+#![allow(dead_code)]
+
+use std::collections::HashMap;
+
+use serde::{Serialize, Serializer};
+use serde_json::json;
+use ts_rs::{
+    testing::{assert_conforms, conforms, Mismatch},
+    Config, TS,
+};
+
+#[derive(TS, Serialize)]
+#[ts(export_to = "testing/")]
+#[serde(rename_all = "camelCase")]
+struct User {
+    user_name: String,
+    age: u8,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    #[ts(optional)]
+    email: Option<String>,
+    nickname: Option<String>,
+    roles: Vec<Role>,
+    #[serde(flatten)]
+    address: Address,
+}
+
+#[derive(TS, Serialize)]
+#[ts(export_to = "testing/")]
+struct Address {
+    city: String,
+}
+
+#[derive(TS, Serialize)]
+#[ts(export_to = "testing/")]
+enum Role {
+    Admin,
+    Guest,
+}
+
+#[derive(TS, Serialize)]
+#[ts(export_to = "testing/")]
+#[serde(tag = "type")]
+enum Event {
+    Login { user: User },
+    Logout,
+}
+
+#[derive(TS, Serialize)]
+#[ts(export_to = "testing/")]
+struct Metadata {
+    id: u64,
+    #[serde(flatten)]
+    extra: HashMap<String, i32>,
+}
+
+// serialized as a string, while the bindings claim it is a number
+#[derive(TS)]
+#[ts(export_to = "testing/")]
+struct Timestamp(u32);
+
+impl Serialize for Timestamp {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&self.0.to_string())
+    }
+}
+
+#[derive(TS, Serialize)]
+#[ts(export_to = "testing/")]
+struct Session {
+    started: Timestamp,
+}
+
+fn user() -> User {
+    User {
+        user_name: "Alice".to_owned(),
+        age: 30,
+        email: None,
+        nickname: None,
+        roles: vec![Role::Admin],
+        address: Address {
+            city: "Berlin".to_owned(),
+        },
+    }
+}
+
+fn check<T: TS + 'static>(value: &serde_json::Value) -> Result<(), Mismatch> {
+    conforms::<T>(value, &Config::new())
+}
+
+#[test]
+fn conforming() {
+    assert_conforms::<User>(&serde_json::to_value(user()).unwrap());
+
+    let login = Event::Login { user: user() };
+    assert_conforms::<Event>(&serde_json::to_value(login).unwrap());
+    assert_conforms::<Event>(&serde_json::to_value(Event::Logout).unwrap());
+
+    let extra = HashMap::from([("a".to_owned(), 1)]);
+    let metadata = Metadata { id: 1, extra };
+    assert_conforms::<Metadata>(&serde_json::to_value(metadata).unwrap());
+}
+
+#[test]
+fn custom_serialize() {
+    let session = Session {
+        started: Timestamp(1),
+    };
+    assert_eq!(
+        check::<Session>(&serde_json::to_value(session).unwrap()),
+        Err(Mismatch {
+            path: "$.started".to_owned(),
+            expected: "Timestamp".to_owned(),
+            found: r#""1""#.to_owned(),
+        })
+    );
+}
+
+#[test]
+fn nested() {
+    let mut value = serde_json::to_value(user()).unwrap();
+    value["roles"][0] = json!("Owner");
+    assert_eq!(
+        check::<User>(&value),
+        Err(Mismatch {
+            path: "$.roles[0]".to_owned(),
+            expected: "Role".to_owned(),
+            found: r#""Owner""#.to_owned(),
+        })
+    );
+
+    let value = json!({ "type": "Login", "user": { "userName": "Bob" } });
+    assert_eq!(check::<Event>(&value).unwrap_err().path, "$.user.age");
+}
+
+#[test]
+fn properties() {
+    let mut value = serde_json::to_value(user()).unwrap();
+    value.as_object_mut().unwrap().remove("nickname");
+    assert_eq!(
+        check::<User>(&value),
+        Err(Mismatch {
+            path: "$.nickname".to_owned(),
+            expected: "string | null".to_owned(),
+            found: "nothing".to_owned(),
+        })
+    );
+
+    let mut value = serde_json::to_value(user()).unwrap();
+    value["user_name"] = json!("Alice");
+    assert_eq!(check::<User>(&value).unwrap_err().path, "$.user_name");
+
+    let value = json!({ "id": 1, "a": "b" });
+    assert_eq!(check::<Metadata>(&value).unwrap_err().path, "$.a");
+}
+
+#[test]
+fn large_integers() {
+    let value = json!({ "id": u64::MAX });
+    assert!(check::<Metadata>(&value).is_ok());
+
+    let cfg = Config::new().with_large_int("number");
+    assert_eq!(conforms::<Metadata>(&value, &cfg).unwrap_err().path, "$.id");
+    assert!(conforms::<Metadata>(&json!({ "id": 1 }), &cfg).is_ok());
+}
+
+#[test]
+#[should_panic(expected = "value at `$` does not conform to `Role`")]
+fn assertion() {
+    assert_conforms::<Role>(&json!(null));
+}
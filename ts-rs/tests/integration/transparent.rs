@@ -0,0 +1,99 @@
+#![allow(dead_code)]
+
+use std::marker::PhantomData;
+
+#[cfg(feature = "serde-compat")]
+use serde::Serialize;
+use ts_rs::{Config, TS};
+
+#[derive(TS)]
+#[cfg_attr(feature = "serde-compat", derive(Serialize))]
+#[ts(export, export_to = "transparent/")]
+struct Inner {
+    a: i32,
+    b: String,
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "transparent/", transparent)]
+struct Named {
+    inner: Inner,
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "transparent/", transparent)]
+struct NamedPrimitive {
+    value: u32,
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "transparent/", transparent)]
+struct WithSkipped {
+    value: Vec<String>,
+    #[ts(skip)]
+    marker: PhantomData<Inner>,
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "transparent/", transparent)]
+struct Tuple(Inner, #[ts(skip)] PhantomData<()>);
+
+#[derive(TS)]
+#[ts(export, export_to = "transparent/")]
+struct Outer {
+    x: i32,
+    #[ts(flatten)]
+    named: Named,
+}
+
+#[test]
+fn named() {
+    let cfg = Config::from_env();
+    assert_eq!(Named::inline(&cfg), "Inner");
+    assert_eq!(Named::decl(&cfg), "type Named = Inner;");
+    assert_eq!(NamedPrimitive::decl(&cfg), "type NamedPrimitive = number;");
+}
+
+#[test]
+fn skipped_fields() {
+    let cfg = Config::from_env();
+    assert_eq!(
+        WithSkipped::decl(&cfg),
+        "type WithSkipped = Array<string>;"
+    );
+    assert_eq!(Tuple::decl(&cfg), "type Tuple = Inner;");
+}
+
+#[test]
+fn dependencies() {
+    let cfg = Config::from_env();
+    let deps = Named::dependencies(&cfg);
+    assert_eq!(deps.len(), 1);
+    assert_eq!(deps[0].ts_name, "Inner");
+}
+
+#[test]
+fn flatten() {
+    let cfg = Config::from_env();
+    assert_eq!(Outer::inline(&cfg), "{ x: number, a: number, b: string, }");
+}
+
+#[derive(TS)]
+#[cfg_attr(feature = "serde-compat", derive(Serialize))]
+#[cfg_attr(feature = "serde-compat", serde(transparent))]
+#[cfg_attr(not(feature = "serde-compat"), ts(transparent))]
+#[ts(export, export_to = "transparent/")]
+struct SerdeTransparent {
+    #[cfg_attr(feature = "serde-compat", serde(rename = "ignored"))]
+    #[cfg_attr(not(feature = "serde-compat"), ts(rename = "ignored"))]
+    inner: Option<Inner>,
+}
+
+#[test]
+fn serde_transparent() {
+    let cfg = Config::from_env();
+    assert_eq!(
+        SerdeTransparent::decl(&cfg),
+        "type SerdeTransparent = Inner | null;"
+    );
+}
@@ -0,0 +1,171 @@
+#![allow(dead_code)]
+
+use std::collections::HashMap;
+
+use ts_rs::{Config, TS};
+
+#[derive(TS)]
+#[ts(export, export_to = "type_guards/")]
+struct Point {
+    x: i32,
+    y: Option<f64>,
+    #[ts(optional)]
+    label: Option<String>,
+}
+
+#[test]
+fn object() {
+    let cfg = Config::from_env();
+    assert_eq!(
+        Point::guard(&cfg),
+        "function isPoint(value: unknown): value is Point { return (\
+            typeof value === \"object\" && \
+            value !== null && \
+            typeof (value as Record<string, unknown>)[\"x\"] === \"number\" && \
+            (typeof (value as Record<string, unknown>)[\"y\"] === \"number\" || (value as Record<string, unknown>)[\"y\"] === null) && \
+            ((value as Record<string, unknown>)[\"label\"] === undefined || typeof (value as Record<string, unknown>)[\"label\"] === \"string\")\
+        ); }"
+    );
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "type_guards/")]
+enum Shape {
+    Empty,
+    Circle(f64),
+    Polygon { points: Vec<Point> },
+}
+
+#[test]
+fn externally_tagged() {
+    let cfg = Config::from_env();
+    let guard = Shape::guard(&cfg);
+
+    assert!(guard.starts_with("function isShape(value: unknown): value is Shape { return ("));
+    assert!(guard.contains(r#"value === "Empty""#));
+    assert!(guard.contains(r#"typeof (value as Record<string, unknown>)["Circle"] === "number""#));
+    assert!(guard.contains(r#".every((v1: unknown): v1 is Point => isPoint(v1))"#));
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "type_guards/", tag = "kind")]
+enum Event {
+    Click { x: i32, y: i32 },
+    Key { code: u32 },
+}
+
+#[test]
+fn internally_tagged() {
+    let cfg = Config::from_env();
+    assert_eq!(
+        Event::guard(&cfg),
+        "function isEvent(value: unknown): value is Event { return (\
+            (typeof value === \"object\" && \
+            value !== null && \
+            (value as Record<string, unknown>)[\"kind\"] === \"Click\" && \
+            typeof (value as Record<string, unknown>)[\"x\"] === \"number\" && \
+            typeof (value as Record<string, unknown>)[\"y\"] === \"number\") || \
+            (typeof value === \"object\" && \
+            value !== null && \
+            (value as Record<string, unknown>)[\"kind\"] === \"Key\" && \
+            typeof (value as Record<string, unknown>)[\"code\"] === \"number\")\
+        ); }"
+    );
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "type_guards/")]
+struct Page<T> {
+    items: Vec<T>,
+    total: u32,
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "type_guards/")]
+struct Points {
+    page: Page<Point>,
+}
+
+#[test]
+fn generics() {
+    let cfg = Config::from_env();
+    assert_eq!(
+        Page::<Point>::guard(&cfg),
+        "function isPage<T>(value: unknown, isT: (value: unknown) => value is T): value is Page<T> { return (\
+            typeof value === \"object\" && \
+            value !== null && \
+            (Array.isArray((value as Record<string, unknown>)[\"items\"]) && \
+            ((value as Record<string, unknown>)[\"items\"] as unknown[]).every((v1: unknown): v1 is T => isT(v1))) && \
+            typeof (value as Record<string, unknown>)[\"total\"] === \"number\"\
+        ); }"
+    );
+    assert!(Points::guard(&cfg).contains(
+        r#"isPage((value as Record<string, unknown>)["page"], (v1: unknown): v1 is Point => isPoint(v1))"#
+    ));
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "type_guards/")]
+struct Tree {
+    children: HashMap<String, Tree>,
+    #[ts(type = "Date")]
+    created: String,
+}
+
+#[test]
+fn recursive() {
+    let cfg = Config::from_env();
+    assert_eq!(
+        Tree::guard(&cfg),
+        "function isTree(value: unknown): value is Tree { return (\
+            typeof value === \"object\" && \
+            value !== null && \
+            (typeof (value as Record<string, unknown>)[\"children\"] === \"object\" && \
+            (value as Record<string, unknown>)[\"children\"] !== null && \
+            Object.values(((value as Record<string, unknown>)[\"children\"] as Record<string, unknown>)).every((v1: unknown) => isTree(v1)))\
+        ); }"
+    );
+}
+
+#[derive(TS)]
+#[ts(export, export_to = "type_guards/", repr(enum))]
+enum Color {
+    Red = 1,
+    Green,
+}
+
+#[test]
+fn repr_enum() {
+    let cfg = Config::from_env();
+    assert_eq!(
+        Color::guard(&cfg),
+        "function isColor(value: unknown): value is Color { return (value === 1 || value === 2); }"
+    );
+}
+
+#[test]
+fn export() {
+    let cfg = Config::from_env().with_type_guards();
+    let exported = Points::export_to_string(&cfg).unwrap();
+    let ext = cfg
+        .import_extension()
+        .map(|ext| format!(".{ext}"))
+        .unwrap_or_default();
+
+    assert!(exported.contains(&format!(
+        "import type {{ Page }} from \"./Page{ext}\";\nimport {{ isPage }} from \"./Page{ext}\";\n"
+    )));
+    assert!(exported.contains(&format!(
+        "import type {{ Point }} from \"./Point{ext}\";\nimport {{ isPoint }} from \"./Point{ext}\";\n"
+    )));
+    let decl = match cfg!(feature = "format") {
+        true => "export type Points = { page: Page<Point>, };",
+        false => "export type Points = {\n  page: Page<Point>,\n};",
+    };
+    assert!(exported.contains(&format!("{decl}\nexport function isPoints(")));
+
+    // guards are not exported by default
+    let exported = Points::export_to_string(&Config::new()).unwrap();
+    assert!(!exported.contains("isPoints"));
+    assert!(!exported.contains("import {"));
+}
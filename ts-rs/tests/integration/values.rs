@@ -0,0 +1,91 @@
+#![cfg(feature = "values")]
+#![allow(dead_code)]
+
+use std::fs;
+
+use serde::Serialize;
+use ts_rs::{export_value, Config, TS};
+
+#[derive(TS, Serialize)]
+#[ts(export_to = "values/")]
+#[serde(rename_all = "camelCase")]
+struct Settings {
+    theme: Theme,
+    max_upload: u64,
+    tags: Vec<String>,
+    limits: Option<Limits<i64>>,
+}
+
+#[derive(TS, Serialize)]
+#[ts(export_to = "values/")]
+enum Theme {
+    Light,
+    Dark,
+}
+
+#[derive(TS, Serialize)]
+#[ts(export_to = "values/")]
+struct Limits<T> {
+    max: T,
+}
+
+export_value!(MAX_UPLOAD: u64 = 1024, export_to = "values/limits.ts");
+export_value!(pub(crate) static APP_NAME: &str = "ts-rs", export_to = "values/limits.ts");
+
+fn cfg() -> Config {
+    Config::from_env().with_large_int("bigint")
+}
+
+fn contents(path: &str) -> String {
+    let contents = fs::read_to_string(cfg().out_dir().join(path)).unwrap();
+    contents.split_whitespace().collect::<Vec<_>>().join(" ")
+}
+
+#[test]
+fn export_value() {
+    let settings = Settings {
+        theme: Theme::Dark,
+        max_upload: 1024,
+        tags: vec!["a\"b".to_owned()],
+        limits: Some(Limits { max: -5 }),
+    };
+    Settings::export_value(&settings, "DEFAULT_SETTINGS", &cfg()).unwrap();
+
+    let contents = contents("values/Settings.ts");
+    assert!(contents.contains("export type Settings = "));
+    let expected = if cfg!(feature = "format") {
+        r#"export const DEFAULT_SETTINGS: Settings = { theme: "Dark", maxUpload: 1024n, tags: ['a"b'], limits: { max: -5n }, };"#
+    } else {
+        r#"export const DEFAULT_SETTINGS: Settings = { theme: "Dark", maxUpload: 1024n, tags: ["a\"b"], limits: { max: -5n } };"#
+    };
+    assert!(contents.contains(expected));
+}
+
+#[test]
+fn export_value_to() {
+    let themes = vec![Theme::Light, Theme::Dark];
+    Vec::<Theme>::export_value_to(&themes, "THEMES", "values/themes.ts", &cfg()).unwrap();
+    Option::<u32>::export_value_to(&None, "NOTHING", "values/themes.ts", &cfg()).unwrap();
+
+    let contents = contents("values/themes.ts");
+    assert!(contents.contains(r#"import type { Theme } from "./Theme"#));
+    assert!(contents.contains(r#"export const THEMES: Array<Theme> = ["Light", "Dark"];"#));
+    assert!(contents.contains("export const NOTHING: number | null = null;"));
+    // the types used by the value are exported as well
+    assert!(cfg().out_dir().join("values/Theme.ts").exists());
+}
+
+#[test]
+fn as_const() {
+    u64::export_value_to(&MAX_UPLOAD, "MAX_UPLOAD", "values/limits.ts", &cfg()).unwrap();
+    <&str>::export_value_to(&APP_NAME, "APP_NAME", "values/limits.ts", &cfg()).unwrap();
+
+    let contents = contents("values/limits.ts");
+    assert!(contents.contains("export const MAX_UPLOAD: bigint = 1024n as const;"));
+    assert!(contents.contains(r#"export const APP_NAME: string = "ts-rs" as const;"#));
+
+    let cfg = cfg().with_readonly();
+    Vec::<u8>::export_value_to(&vec![1, 2], "BYTES", "values/readonly.ts", &cfg).unwrap();
+    let contents = fs::read_to_string(cfg.out_dir().join("values/readonly.ts")).unwrap();
+    assert!(contents.contains("export const BYTES: ReadonlyArray<number> = [1, 2] as const;"));
+}
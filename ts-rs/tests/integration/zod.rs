@@ -0,0 +1,204 @@
+#![cfg(feature = "zod")]
+#![allow(dead_code)]
+
+use std::collections::HashMap;
+
+use ts_rs::{Config, TS};
+
+/// A registered user
+#[derive(TS)]
+#[ts(export_to = "zod/", rename_all = "camelCase")]
+struct User {
+    user_id: u32,
+    /// The name of the user
+    display_name: String,
+    #[ts(optional)]
+    email: Option<String>,
+    role: Role,
+    tags: HashMap<Role, Vec<String>>,
+    scores: HashMap<String, f64>,
+    page: Page<Role>,
+    pair: (i32, Option<bool>),
+    #[ts(type = "Date")]
+    created: String,
+}
+
+#[derive(TS, Hash, PartialEq, Eq)]
+#[ts(export_to = "zod/")]
+enum Role {
+    Admin,
+    Guest,
+}
+
+#[derive(TS)]
+#[ts(export_to = "zod/")]
+struct Page<T> {
+    items: Vec<T>,
+    total: u32,
+}
+
+#[derive(TS)]
+#[ts(export_to = "zod/")]
+struct Tree {
+    value: i32,
+    children: Vec<Tree>,
+}
+
+#[derive(TS)]
+#[ts(export_to = "zod/")]
+enum External {
+    Unit,
+    Newtype(Box<User>),
+    Tuple(i32, String),
+    Struct { a: i32 },
+}
+
+#[derive(TS)]
+#[ts(export_to = "zod/", tag = "type")]
+enum Internal {
+    Unit,
+    Newtype(Box<User>),
+    Struct { a: i32 },
+}
+
+#[derive(TS)]
+#[ts(export_to = "zod/", tag = "t", content = "c")]
+enum Adjacent {
+    Unit,
+    Newtype(Box<User>),
+    Struct { a: i32 },
+}
+
+#[derive(TS)]
+#[ts(export_to = "zod/", untagged)]
+enum Untagged {
+    Int(i32),
+    Text(String),
+}
+
+#[derive(TS)]
+#[ts(export_to = "zod/", repr(enum))]
+enum Color {
+    Red = 1,
+    Green,
+}
+
+#[derive(TS)]
+#[ts(export_to = "zod/", brand)]
+struct Email(String);
+
+fn cfg() -> Config {
+    Config::from_env().with_zod()
+}
+
+#[test]
+fn object() {
+    assert_eq!(
+        User::zod(&cfg()),
+        "const UserSchema = z.object({ \
+            userId: z.number(), \
+            \n/**\n * The name of the user\n */\ndisplayName: z.string(), \
+            email: z.string().optional(), \
+            role: z.lazy(() => RoleSchema), \
+            tags: z.record(z.lazy(() => RoleSchema), z.array(z.string()).optional()), \
+            scores: z.record(z.string(), z.number()), \
+            page: z.lazy(() => PageSchema(z.lazy(() => RoleSchema))), \
+            pair: z.tuple([z.number(), z.boolean().nullable()]), \
+            created: z.custom<Date>(), \
+        });"
+    );
+
+    let exported = User::export_to_string(&cfg()).unwrap();
+    let ext = cfg()
+        .import_extension()
+        .map(|ext| format!(".{ext}"))
+        .unwrap_or_default();
+    assert!(exported.contains(&format!(
+        "import {{ PageSchema }} from \"./Page{ext}\";\n\
+         import {{ RoleSchema }} from \"./Role{ext}\";\n\
+         import {{ z }} from \"zod\";\n\n"
+    )));
+    assert!(exported.contains(
+        "});\n/**\n * A registered user\n */\nexport type User = z.infer<typeof UserSchema>;\n"
+    ));
+    // the types of dependencies are not used
+    assert!(!exported.contains("import type"));
+}
+
+#[test]
+fn generics() {
+    assert_eq!(
+        Page::<Role>::export_to_string(&cfg()).unwrap().split_once("\n\n").unwrap().1,
+        "export type Page<T> = { items: Array<T>, total: number, };\n\
+         export const PageSchema = <T extends z.ZodTypeAny>(TSchema: T): z.ZodType<Page<z.infer<T>>, z.ZodTypeDef, unknown> => \
+         z.object({ items: z.array(TSchema), total: z.number(), });\n"
+    );
+}
+
+#[test]
+fn recursive() {
+    assert_eq!(
+        Tree::export_to_string(&cfg())
+            .unwrap()
+            .split_once("\n\n")
+            .unwrap()
+            .1,
+        "export type Tree = { value: number, children: Array<Tree>, };\n\
+         export const TreeSchema: z.ZodType<Tree> = \
+         z.object({ value: z.number(), children: z.array(z.lazy(() => TreeSchema)), });\n"
+    );
+}
+
+#[test]
+fn enums() {
+    assert_eq!(
+        Role::zod(&cfg()),
+        r#"const RoleSchema = z.enum(["Admin", "Guest"]);"#
+    );
+    assert_eq!(
+        External::zod(&cfg()),
+        r#"const ExternalSchema = z.union([z.literal("Unit"), z.object({ Newtype: z.lazy(() => UserSchema), }), z.object({ Tuple: z.tuple([z.number(), z.string()]), }), z.object({ Struct: z.object({ a: z.number(), }), })]);"#
+    );
+    assert_eq!(
+        Internal::zod(&cfg()),
+        r#"const InternalSchema = z.union([z.object({ type: z.literal("Unit"), }), z.object({ type: z.literal("Newtype"), }).and(z.lazy(() => UserSchema)), z.object({ type: z.literal("Struct"), a: z.number(), })]);"#
+    );
+    assert_eq!(
+        Adjacent::zod(&cfg()),
+        r#"const AdjacentSchema = z.union([z.object({ t: z.literal("Unit"), }), z.object({ t: z.literal("Newtype"), c: z.lazy(() => UserSchema), }), z.object({ t: z.literal("Struct"), c: z.object({ a: z.number(), }), })]);"#
+    );
+    assert_eq!(
+        Untagged::zod(&cfg()),
+        "const UntaggedSchema = z.union([z.number(), z.string()]);"
+    );
+}
+
+#[test]
+fn repr_enum() {
+    assert_eq!(
+        Color::export_to_string(&cfg())
+            .unwrap()
+            .split_once("\n\n")
+            .unwrap()
+            .1,
+        "export enum Color { \"Red\" = 1, \"Green\" }\n\
+         export const ColorSchema = z.nativeEnum(Color);\n"
+    );
+}
+
+#[test]
+fn brand() {
+    assert_eq!(
+        Email::zod(&cfg()),
+        r#"const EmailSchema = z.string().brand<"Email">();"#
+    );
+}
+
+#[test]
+fn readonly() {
+    assert_eq!(
+        Page::<Role>::zod(&cfg().with_readonly()),
+        "const PageSchema = <T extends z.ZodTypeAny>(TSchema: T): z.ZodType<Page<z.infer<T>>, z.ZodTypeDef, unknown> => \
+         z.object({ items: z.array(TSchema).readonly(), total: z.number(), }).readonly();"
+    );
+}